@@ -4,15 +4,19 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{format_ident, quote};
+use std::collections::BTreeMap;
 use syn::punctuated::Punctuated;
+use syn::Attribute;
 use syn::Data;
 use syn::DataStruct;
 use syn::DeriveInput;
 use syn::GenericArgument;
+use syn::Generics;
 use syn::Ident;
 use syn::PathArguments;
 use syn::Token;
 use syn::Type;
+use syn::TypeArray;
 use syn::{Field, GenericParam};
 
 fn extend_decoding_params(
@@ -35,8 +39,8 @@ fn extend_decoding_params(
 fn is_type_container(name: &str, ty: &Type) -> bool {
     if let Type::Path(type_path) = &ty {
         let option = Ident::new(name, Span::call_site());
-        let first_segment = type_path.path.segments.first().unwrap();
-        return first_segment.ident == option;
+        let last_segment = type_path.path.segments.last().unwrap();
+        return last_segment.ident == option;
     }
     false
 }
@@ -45,8 +49,53 @@ fn is_type_option(ty: &Type) -> bool {
     is_type_container("Option", ty)
 }
 
-fn is_type_vec(ty: &Type) -> bool {
+fn is_type_obs_text(ty: &Type) -> bool {
+    is_type_container("ObsText", ty)
+}
+
+fn is_type_bump_vec(ty: &Type) -> bool {
+    if is_type_container("BumpVec", ty) {
+        return true;
+    }
+    // unlike std's Vec, bumpalo's Vec carries the lifetime of its arena
+    match ty {
+        Type::Path(type_path) if is_type_container("Vec", ty) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            match &last_segment.arguments {
+                PathArguments::AngleBracketed(generics) => generics
+                    .args
+                    .iter()
+                    .any(|g| matches!(g, GenericArgument::Lifetime(_))),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn is_type_repeated(ty: &Type) -> bool {
     is_type_container("Vec", ty)
+        || is_type_container("SmallVec", ty)
+        || is_type_container("HashSet", ty)
+        || is_type_container("BTreeSet", ty)
+        || is_type_bump_vec(ty)
+}
+
+fn is_type_pointer(ty: &Type) -> bool {
+    is_type_container("Box", ty) || is_type_container("Rc", ty) || is_type_container("Arc", ty)
+}
+
+/// Looks through a `Box`, `Rc` or `Arc` wrapping a repeated container, so
+/// `Arc<Vec<T>>` is collected like `Vec<T>` and only wrapped at the end.
+fn get_repeated_type(ty: &Type) -> Option<&Type> {
+    if is_type_repeated(ty) {
+        return Some(ty);
+    }
+    if is_type_pointer(ty) {
+        let pointee = get_first_generic_type(ty);
+        return is_type_repeated(pointee).then_some(pointee);
+    }
+    None
 }
 
 fn get_field_ident(field: &Field) -> &Ident {
@@ -73,37 +122,209 @@ fn get_first_generic_type(ty: &Type) -> &Type {
     generic_type
 }
 
+/// Options set on the struct with `#[noggin(...)]`.
+#[derive(Default)]
+struct ContainerOptions {
+    allow_control_characters: bool,
+    obs_text: bool,
+    partial: bool,
+    builder: bool,
+    merge: bool,
+}
+
+impl ContainerOptions {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = ContainerOptions::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("noggin")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("allow_control_characters") {
+                    options.allow_control_characters = true;
+                    Ok(())
+                } else if meta.path.is_ident("obs_text") {
+                    options.obs_text = true;
+                    Ok(())
+                } else if meta.path.is_ident("partial") {
+                    options.partial = true;
+                    Ok(())
+                } else if meta.path.is_ident("builder") {
+                    options.builder = true;
+                    Ok(())
+                } else if meta.path.is_ident("merge") {
+                    options.merge = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported noggin attribute"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+
+    fn make_header_lines(&self) -> proc_macro2::TokenStream {
+        let lines = if self.obs_text {
+            quote! { noggin::ObsTextLines::new(head) }
+        } else {
+            quote! { noggin::HeaderLines::new(head) }
+        };
+        if self.allow_control_characters {
+            quote! { #lines.allow_control_characters() }
+        } else {
+            lines
+        }
+    }
+}
+
 enum HeaderField<'a> {
     RequiredSingle(&'a Ident, &'a Type),
     RequiredRepeated(&'a Ident, &'a Type),
     OptionalSingle(&'a Ident, &'a Type),
     OptionalRepeated(&'a Ident, &'a Type),
+    RequiredArray(&'a Ident, &'a TypeArray),
+    OptionalArray(&'a Ident, &'a TypeArray),
+    /// A `#[noggin(order)]` field capturing the position and original name
+    /// of every matched header, rather than a header itself.
+    Order(&'a Ident, &'a Type),
+}
+
+/// The options set with `#[noggin(...)]` on a field.
+#[derive(Default)]
+struct FieldOptions {
+    order: bool,
+    no_split: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &Field) -> syn::Result<FieldOptions> {
+        let mut options = FieldOptions::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("noggin"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    options.order = true;
+                    Ok(())
+                } else if meta.path.is_ident("no_split") {
+                    options.no_split = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported noggin field attribute"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
 }
 
 impl<'a> HeaderField<'a> {
-    pub(crate) fn parse_all(data: &DataStruct) -> Vec<HeaderField> {
+    /// Classifies every field of the struct, along with its `no_split`
+    /// option. Reports invalid field attributes, a second `#[noggin(order)]`
+    /// field and `no_split` on a field that isn't repeated as spanned
+    /// errors.
+    pub(crate) fn parse_all(data: &DataStruct) -> syn::Result<Vec<(HeaderField<'_>, bool)>> {
+        let mut has_order = false;
         data.fields
             .iter()
             .map(|field| {
                 let ident = get_field_ident(field);
-                if is_type_option(&field.ty) {
+                let options = FieldOptions::parse(field)?;
+                if options.order && has_order {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "Noggin derive macro only supports one #[noggin(order)] field",
+                    ));
+                }
+                has_order |= options.order;
+                let header_field = if options.order {
+                    HeaderField::Order(ident, &field.ty)
+                } else if is_type_option(&field.ty) {
                     let optional_type = get_first_generic_type(&field.ty);
-                    if is_type_vec(optional_type) {
-                        let repeated_type = get_first_generic_type(optional_type);
+                    if let Type::Array(array_type) = optional_type {
+                        HeaderField::OptionalArray(ident, array_type)
+                    } else if let Some(repeated_type) = get_repeated_type(optional_type) {
                         HeaderField::OptionalRepeated(ident, repeated_type)
                     } else {
                         HeaderField::OptionalSingle(ident, optional_type)
                     }
-                } else if is_type_vec(&field.ty) {
-                    let repeated_type = get_first_generic_type(&field.ty);
+                } else if let Type::Array(array_type) = &field.ty {
+                    HeaderField::RequiredArray(ident, array_type)
+                } else if let Some(repeated_type) = get_repeated_type(&field.ty) {
                     HeaderField::RequiredRepeated(ident, repeated_type)
                 } else {
                     HeaderField::RequiredSingle(ident, &field.ty)
+                };
+                let repeated = !matches!(
+                    header_field,
+                    HeaderField::RequiredSingle(..)
+                        | HeaderField::OptionalSingle(..)
+                        | HeaderField::Order(..)
+                );
+                if options.no_split && !repeated {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "#[noggin(no_split)] is only supported on repeated fields",
+                    ));
                 }
+                Ok((header_field, options.no_split))
             })
             .collect()
     }
 
+    pub(crate) fn ident(&self) -> &Ident {
+        match self {
+            HeaderField::RequiredSingle(ident, _)
+            | HeaderField::RequiredRepeated(ident, _)
+            | HeaderField::OptionalSingle(ident, _)
+            | HeaderField::OptionalRepeated(ident, _)
+            | HeaderField::RequiredArray(ident, _)
+            | HeaderField::OptionalArray(ident, _)
+            | HeaderField::Order(ident, _) => ident,
+        }
+    }
+
+    /// Returns the field as it appears in the partial struct, where every
+    /// header is optional.
+    pub(crate) fn to_optional(&self) -> HeaderField<'a> {
+        match *self {
+            HeaderField::RequiredSingle(ident, ty) => HeaderField::OptionalSingle(ident, ty),
+            HeaderField::RequiredRepeated(ident, ty) => HeaderField::OptionalRepeated(ident, ty),
+            HeaderField::RequiredArray(ident, ty) => HeaderField::OptionalArray(ident, ty),
+            HeaderField::OptionalSingle(ident, ty) => HeaderField::OptionalSingle(ident, ty),
+            HeaderField::OptionalRepeated(ident, ty) => HeaderField::OptionalRepeated(ident, ty),
+            HeaderField::OptionalArray(ident, ty) => HeaderField::OptionalArray(ident, ty),
+            HeaderField::Order(ident, ty) => HeaderField::Order(ident, ty),
+        }
+    }
+
+    pub(crate) fn is_header(&self) -> bool {
+        !matches!(self, HeaderField::Order(..))
+    }
+
+    pub(crate) fn is_bump_allocated(&self) -> bool {
+        match self {
+            HeaderField::RequiredRepeated(_, ty) | HeaderField::OptionalRepeated(_, ty) => {
+                is_type_bump_vec(ty)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for the single `ObsText` fields that take values
+    /// containing obs-text in `#[noggin(obs_text)]` structs.
+    pub(crate) fn is_obs_text(&self) -> bool {
+        match self {
+            HeaderField::RequiredSingle(_, ty) | HeaderField::OptionalSingle(_, ty) => {
+                is_type_obs_text(ty)
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn header_key(&self) -> String {
+        self.ident().to_string().to_lowercase().replace('_', "-")
+    }
+
     pub(crate) fn make_declaration(&self) -> proc_macro2::TokenStream {
         match self {
             HeaderField::RequiredSingle(ident, ty) | HeaderField::OptionalSingle(ident, ty) => {
@@ -113,134 +334,711 @@ impl<'a> HeaderField<'a> {
                 }
             }
             HeaderField::RequiredRepeated(ident, ty) | HeaderField::OptionalRepeated(ident, ty) => {
+                let maybe_ident = format_ident!("maybe_{ident}");
+                if is_type_bump_vec(ty) {
+                    quote! {
+                        let mut #maybe_ident: #ty = noggin::BumpVec::new_in(bump);
+                    }
+                } else {
+                    quote! {
+                        let mut #maybe_ident: #ty = Default::default();
+                    }
+                }
+            }
+            HeaderField::RequiredArray(ident, ty) | HeaderField::OptionalArray(ident, ty) => {
+                let maybe_ident = format_ident!("maybe_{ident}");
+                let element = &ty.elem;
+                let len = &ty.len;
+                quote! {
+                    let mut #maybe_ident: noggin::runtime::ArrayCollector<#element, { #len }> =
+                        Default::default();
+                }
+            }
+            HeaderField::Order(ident, ty) => {
                 let maybe_ident = format_ident!("maybe_{ident}");
                 quote! {
-                    let mut #maybe_ident: Vec<#ty> = vec![];
+                    let mut #maybe_ident: #ty = Default::default();
                 }
             }
         }
     }
 
-    pub(crate) fn make_extractor(&self, key: &Ident, value: &Ident) -> proc_macro2::TokenStream {
+    /// Emits the call storing one header line, `no_split` keeps the whole
+    /// line as a single element of a repeated field.
+    pub(crate) fn make_extractor(&self, value: &Ident, no_split: bool) -> proc_macro2::TokenStream {
+        let maybe_ident = format_ident!("maybe_{}", self.ident());
         match self {
-            HeaderField::RequiredSingle(ident, ty) | HeaderField::OptionalSingle(ident, ty) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
-                let header_key = ident.to_string().replace('_', "-");
+            HeaderField::RequiredSingle(..) | HeaderField::OptionalSingle(..) => {
+                debug_assert!(!no_split, "no_split is checked by parse_all");
+                quote! { noggin::runtime::single(&mut #maybe_ident, #value) }
+            }
+            HeaderField::RequiredRepeated(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::RequiredArray(..)
+            | HeaderField::OptionalArray(..)
+                if no_split =>
+            {
+                quote! { noggin::runtime::repeated_unsplit(&mut #maybe_ident, #value) }
+            }
+            HeaderField::RequiredRepeated(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::RequiredArray(..)
+            | HeaderField::OptionalArray(..) => {
+                quote! { noggin::runtime::repeated(&mut #maybe_ident, #value) }
+            }
+            HeaderField::Order(..) => unreachable!("order fields are not headers"),
+        }
+    }
+
+    pub(crate) fn make_builders(&self) -> proc_macro2::TokenStream {
+        let ident = self.ident();
+        let maybe_ident = format_ident!("maybe_{ident}");
+        let header_key = self.header_key();
+        match self {
+            HeaderField::RequiredSingle(..) => {
                 quote! {
-                    if #maybe_ident.is_none() && #key.eq_ignore_ascii_case(#header_key) {
-                        let #ident: #ty = noggin::FromHeaderValue::parse_header_value(#value)
-                            .ok_or(noggin::Error::InvalidHeaderValue(#header_key))?;
-                        #maybe_ident = Some(#ident);
-                    }
+                    #ident: noggin::runtime::required(#maybe_ident, #header_key)?
                 }
             }
-            HeaderField::RequiredRepeated(ident, ty) | HeaderField::OptionalRepeated(ident, ty) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
-                let header_key = ident.to_string().replace('_', "-");
+            HeaderField::RequiredRepeated(..) => {
                 quote! {
-                    if #key.eq_ignore_ascii_case(#header_key) {
-                        let #ident: Vec<#ty> = noggin::FromHeaderValue::parse_header_value(#value)
-                            .ok_or(noggin::Error::InvalidHeaderValue(#header_key))?;
-                        #maybe_ident.extend(#ident);
-                    }
+                    #ident: noggin::runtime::required_repeated(#maybe_ident, #header_key)?.into()
+                }
+            }
+            HeaderField::OptionalSingle(..) | HeaderField::Order(..) => {
+                quote! {
+                    #ident: #maybe_ident
+                }
+            }
+            HeaderField::OptionalRepeated(..) => {
+                quote! {
+                    #ident: noggin::runtime::optional_repeated(#maybe_ident).map(Into::into)
+                }
+            }
+            HeaderField::RequiredArray(..) => {
+                quote! {
+                    #ident: noggin::runtime::required_array(#maybe_ident, #header_key, head.as_ref())?
+                }
+            }
+            HeaderField::OptionalArray(..) => {
+                quote! {
+                    #ident: noggin::runtime::optional_array(#maybe_ident, #header_key, head.as_ref())?
                 }
             }
         }
     }
 
-    pub(crate) fn make_validator(&self) -> proc_macro2::TokenStream {
+    /// Moves the field out of the partial struct, checking that required
+    /// headers are present.
+    pub(crate) fn make_finisher(&self) -> proc_macro2::TokenStream {
+        let ident = self.ident();
+        let header_key = self.header_key();
         match self {
-            HeaderField::RequiredSingle(ident, _) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
-                let header_key = ident.to_string().replace('_', "-");
+            HeaderField::RequiredSingle(..)
+            | HeaderField::RequiredRepeated(..)
+            | HeaderField::RequiredArray(..) => {
                 quote! {
-                    if #maybe_ident.is_none() {
-                        return Err(noggin::Error::MissingHeader(#header_key));
-                    }
+                    #ident: noggin::runtime::required(self.#ident, #header_key)?
                 }
             }
-            HeaderField::RequiredRepeated(ident, _) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
-                let header_key = ident.to_string().replace('_', "-");
+            HeaderField::OptionalSingle(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::OptionalArray(..)
+            | HeaderField::Order(..) => {
                 quote! {
-                    if #maybe_ident.is_empty() {
-                        return Err(noggin::Error::MissingHeader(#header_key));
-                    }
+                    #ident: self.#ident
                 }
             }
-            _ => quote! {},
         }
     }
 
-    pub(crate) fn make_builders(&self) -> proc_macro2::TokenStream {
+    /// Converts the collected value into an `Option` that is `None` if the
+    /// header was absent, used by the generated `parse_into`. This runs for
+    /// every field before any of them is assigned so a failure leaves the
+    /// struct untouched.
+    pub(crate) fn make_update_check(&self) -> proc_macro2::TokenStream {
+        let maybe_ident = format_ident!("maybe_{}", self.ident());
+        let header_key = self.header_key();
         match self {
-            HeaderField::RequiredSingle(ident, _) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
+            HeaderField::RequiredSingle(..)
+            | HeaderField::OptionalSingle(..)
+            | HeaderField::Order(..) => quote! {},
+            HeaderField::RequiredRepeated(..) | HeaderField::OptionalRepeated(..) => {
                 quote! {
-                    #ident: #maybe_ident.unwrap()
+                    let #maybe_ident = noggin::runtime::optional_repeated(#maybe_ident).map(Into::into);
                 }
             }
-            HeaderField::RequiredRepeated(ident, _) | HeaderField::OptionalSingle(ident, _) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
+            HeaderField::RequiredArray(..) | HeaderField::OptionalArray(..) => {
                 quote! {
-                    #ident: #maybe_ident
+                    let #maybe_ident = noggin::runtime::optional_array(#maybe_ident, #header_key, head.as_ref())?;
                 }
             }
-            HeaderField::OptionalRepeated(ident, _) => {
-                let maybe_ident = format_ident!("maybe_{ident}");
+        }
+    }
+
+    /// Assigns the field of `self` only if its header was present.
+    pub(crate) fn make_updater(&self) -> proc_macro2::TokenStream {
+        let ident = self.ident();
+        let maybe_ident = format_ident!("maybe_{ident}");
+        match self {
+            HeaderField::RequiredSingle(..)
+            | HeaderField::RequiredRepeated(..)
+            | HeaderField::RequiredArray(..) => {
+                quote! {
+                    if let Some(value) = #maybe_ident {
+                        self.#ident = value;
+                    }
+                }
+            }
+            HeaderField::OptionalSingle(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::OptionalArray(..) => {
                 quote! {
-                    #ident: (!#maybe_ident.is_empty()).then_some(#maybe_ident)
+                    if let Some(value) = #maybe_ident {
+                        self.#ident = Some(value);
+                    }
+                }
+            }
+            HeaderField::Order(..) => {
+                quote! {
+                    self.#ident.extend(#maybe_ident);
+                }
+            }
+        }
+    }
+}
+
+/// Builds the closure returning the index of the header named `key`, which
+/// first branches on the name's length so each header line is only compared
+/// against the header names that could possibly match it.
+fn make_dispatch(header_keys: &[String]) -> proc_macro2::TokenStream {
+    let mut by_length: BTreeMap<usize, Vec<(usize, &String)>> = BTreeMap::new();
+    for (index, header_key) in header_keys.iter().enumerate() {
+        by_length
+            .entry(header_key.len())
+            .or_default()
+            .push((index, header_key));
+    }
+    let arms = by_length.iter().map(|(length, keys)| {
+        let branches = keys.iter().map(|(index, header_key)| {
+            quote! {
+                if key.eq_ignore_ascii_case(#header_key) {
+                    Some(#index)
                 }
             }
+        });
+        quote! {
+            #length => #(#branches)else* else { None },
+        }
+    });
+    quote! {
+        |key: &str| match key.len() {
+            #(#arms)*
+            _ => None,
         }
     }
 }
 
-#[proc_macro_derive(Noggin)]
+fn expand_struct(
+    name: &Ident,
+    generics: &Generics,
+    fields: &[(HeaderField<'_>, bool)],
+    options: &ContainerOptions,
+) -> proc_macro2::TokenStream {
+    let params = &generics.params;
+    let extended_params = extend_decoding_params(params);
+    let value = Ident::new("value", Span::call_site());
+    let extractors: Vec<_> = fields
+        .iter()
+        .filter(|(f, _)| f.is_header())
+        .map(|(f, no_split)| {
+            if !options.obs_text {
+                return f.make_extractor(&value, *no_split);
+            }
+            if f.is_obs_text() {
+                let maybe_ident = format_ident!("maybe_{}", f.ident());
+                return quote! { noggin::runtime::single_obs_text(&mut #maybe_ident, #value) };
+            }
+            // every other field only takes ASCII values
+            let extractor = f.make_extractor(&value, *no_split);
+            quote! { #value.as_str().and_then(|#value| #extractor) }
+        })
+        .collect();
+    let fields: Vec<_> = fields.iter().map(|(f, _)| f).collect();
+    let declarations: Vec<_> = fields.iter().map(|f| f.make_declaration()).collect();
+    let headers: Vec<_> = fields.iter().filter(|f| f.is_header()).collect();
+    let header_keys: Vec<_> = headers.iter().map(|f| f.header_key()).collect();
+    let indices = 0..headers.len();
+    let builders: Vec<_> = fields.iter().map(|f| f.make_builders()).collect();
+    let update_checks: Vec<_> = fields.iter().map(|f| f.make_update_check()).collect();
+    let updaters: Vec<_> = fields.iter().map(|f| f.make_updater()).collect();
+    let lines = options.make_header_lines();
+    let find = make_dispatch(&header_keys);
+    let on_header = quote! {
+        |index, #value| {
+            match index {
+                #(
+                    #indices => #extractors,
+                )*
+                _ => Some(()),
+            }
+        }
+    };
+    let (parse_head_fn, parse_head_ordered_fn) = if options.obs_text {
+        (
+            quote! { parse_obs_text_head },
+            quote! { parse_obs_text_head_ordered },
+        )
+    } else {
+        (quote! { parse_head }, quote! { parse_head_ordered })
+    };
+    let parse_head = match fields.iter().find(|f| !f.is_header()) {
+        None => quote! {
+            noggin::runtime::#parse_head_fn(#lines, &[#(#header_keys),*], #find, #on_header)?;
+        },
+        Some(order) => {
+            let maybe_ident = format_ident!("maybe_{}", order.ident());
+            quote! {
+                noggin::runtime::#parse_head_ordered_fn(
+                    #lines,
+                    &[#(#header_keys),*],
+                    #find,
+                    &mut #maybe_ident,
+                    #on_header,
+                )?;
+            }
+        }
+    };
+    let parse = quote! {
+        #(
+            #declarations
+        )*
+        #parse_head
+    };
+    // fields have no contiguous head, so a wrong array count can't be
+    // located and is reported without its value
+    let empty_head = fields
+        .iter()
+        .any(|f| {
+            matches!(
+                f,
+                HeaderField::RequiredArray(..) | HeaderField::OptionalArray(..)
+            )
+        })
+        .then(|| quote! { let head: &[u8] = &[]; });
+    let parse_fields_fn = if options.obs_text {
+        quote! { parse_obs_text_fields }
+    } else {
+        quote! { parse_fields }
+    };
+    let order = match fields.iter().find(|f| !f.is_header()) {
+        None => quote! { &mut noggin::runtime::Discard },
+        Some(order) => {
+            let maybe_ident = format_ident!("maybe_{}", order.ident());
+            quote! { &mut #maybe_ident }
+        }
+    };
+    let allow_control_characters = options.allow_control_characters;
+    let field_parser = quote! {
+        impl<#extended_params> noggin::FieldParser<'de> for #name<#params> {
+            fn parse_fields<I>(fields: I) -> Result<Self, noggin::Error<'de>>
+            where
+                I: IntoIterator<Item = (&'de str, &'de [u8])>,
+            {
+                #empty_head
+                #(
+                    #declarations
+                )*
+                noggin::runtime::#parse_fields_fn(
+                    fields,
+                    &[#(#header_keys),*],
+                    #find,
+                    #allow_control_characters,
+                    #order,
+                    #on_header,
+                )?;
+                let result = #name {
+                    #(
+                        #builders
+                    ),*
+                };
+                Ok(result)
+            }
+        }
+    };
+    let update = quote! {
+        #parse
+        #(
+            #update_checks
+        )*
+        #(
+            #updaters
+        )*
+        Ok(())
+    };
+    let body = quote! {
+        #parse
+        let result = #name {
+            #(
+                #builders
+            ),*
+        };
+        Ok(result)
+    };
+    // obs-text structs parse the head as bytes, so they override the
+    // parsers that would otherwise require an ASCII head
+    let update = if options.obs_text {
+        quote! {
+            let head = head.as_bytes();
+            #update
+        }
+    } else {
+        update
+    };
+    let bump_parsers = if options.obs_text {
+        quote! {
+            fn parse_head_section_in(
+                head: &'de str,
+                bump: &'de noggin::bumpalo::Bump,
+            ) -> Result<Self, noggin::Error<'de>> {
+                Self::parse_head_bytes_in(head.as_bytes(), bump)
+            }
+
+            fn parse_headers_in(
+                head_and_body: &'de [u8],
+                bump: &'de noggin::bumpalo::Bump,
+            ) -> Result<(Self, &'de [u8]), noggin::Error<'de>> {
+                let (head, body) = noggin::runtime::split_head_bytes(head_and_body)?;
+                Ok((Self::parse_head_bytes_in(head, bump)?, body))
+            }
+
+            fn parse_head_bytes_in(
+                head: &'de [u8],
+                bump: &'de noggin::bumpalo::Bump,
+            ) -> Result<Self, noggin::Error<'de>> {
+                let head = noggin::runtime::strip_head_terminator(head);
+                #body
+            }
+        }
+    } else {
+        quote! {
+            fn parse_head_section_in(
+                head: &'de str,
+                bump: &'de noggin::bumpalo::Bump,
+            ) -> Result<Self, noggin::Error<'de>> {
+                #body
+            }
+        }
+    };
+    let parsers = if options.obs_text {
+        quote! {
+            fn parse_head_section(head: &'de str) -> Result<Self, noggin::Error<'de>> {
+                Self::parse_head_bytes(head.as_bytes())
+            }
+
+            fn parse_headers(
+                head_and_body: &'de [u8],
+            ) -> Result<(Self, &'de [u8]), noggin::Error<'de>> {
+                let (head, body) = noggin::runtime::split_head_bytes(head_and_body)?;
+                Ok((Self::parse_head_bytes(head)?, body))
+            }
+
+            fn parse_head_bytes(head: &'de [u8]) -> Result<Self, noggin::Error<'de>> {
+                let head = noggin::runtime::strip_head_terminator(head);
+                #body
+            }
+        }
+    } else {
+        quote! {
+            fn parse_head_section(head: &'de str) -> Result<Self, noggin::Error<'de>> {
+                #body
+            }
+        }
+    };
+    if fields.iter().any(|f| f.is_bump_allocated()) {
+        quote! {
+            impl<#extended_params> noggin::BumpHeadParser<'de> for #name<#params> {
+                #bump_parsers
+            }
+
+            impl<#extended_params> #name<#params> {
+                /// Parses `head` and overwrites the fields whose headers it
+                /// contains, keeping the current value of every other field.
+                /// Nothing is modified if parsing fails.
+                pub fn parse_into_in(
+                    &mut self,
+                    head: &'de str,
+                    bump: &'de noggin::bumpalo::Bump,
+                ) -> Result<(), noggin::Error<'de>> {
+                    #update
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl<#extended_params> noggin::HeadParser<'de> for #name<#params> {
+                #parsers
+            }
+
+            #field_parser
+
+            impl<#extended_params> #name<#params> {
+                /// Parses `head` and overwrites the fields whose headers it
+                /// contains, keeping the current value of every other field.
+                /// Nothing is modified if parsing fails.
+                pub fn parse_into(&mut self, head: &'de str) -> Result<(), noggin::Error<'de>> {
+                    #update
+                }
+            }
+        }
+    }
+}
+
+/// Emits the `<Name>Partial` companion of a struct, where every field is
+/// optional so it can be parsed from an incomplete head, along with the
+/// `finish` method that checks the required fields.
+fn expand_partial(
+    derive_input: &DeriveInput,
+    data: &DataStruct,
+    fields: &[(HeaderField<'_>, bool)],
+    options: &ContainerOptions,
+) -> proc_macro2::TokenStream {
+    let name = &derive_input.ident;
+    let vis = &derive_input.vis;
+    let generics = &derive_input.generics;
+    let params = &generics.params;
+    let where_clause = &generics.where_clause;
+    let partial_name = format_ident!("{name}Partial");
+    // the partial struct doesn't derive `Noggin`, so only the doc comments
+    // of the fields are kept, their options are applied through `fields`
+    let mut partial_data = data.clone();
+    for (field, (header_field, _)) in partial_data.fields.iter_mut().zip(fields) {
+        field.attrs.retain(|attr| attr.path().is_ident("doc"));
+        if !is_type_option(&field.ty) && header_field.is_header() {
+            let ty = &field.ty;
+            field.ty = syn::parse_quote!(Option<#ty>);
+        }
+    }
+    let partial_fields = partial_data.fields.iter();
+    let finishers: Vec<_> = fields.iter().map(|(f, _)| f.make_finisher()).collect();
+    let partial_header_fields: Vec<_> = fields
+        .iter()
+        .map(|(f, no_split)| (f.to_optional(), *no_split))
+        .collect();
+    let partial_impl = expand_struct(&partial_name, generics, &partial_header_fields, options);
+    let doc = format!(
+        "The partial counterpart of [`{name}`] where every header is optional, \
+         see [`finish`]({partial_name}::finish)."
+    );
+    quote! {
+        #[doc = #doc]
+        #[derive(Default)]
+        #vis struct #partial_name<#params> #where_clause {
+            #(
+                #partial_fields
+            ),*
+        }
+
+        #partial_impl
+
+        impl<#params> #partial_name<#params> #where_clause {
+            /// Converts the partial headers into the complete struct, or
+            /// reports the first required header that is still missing.
+            pub fn finish(self) -> Result<#name<#params>, noggin::Error<'static>> {
+                Ok(#name {
+                    #(
+                        #finishers
+                    ),*
+                })
+            }
+        }
+    }
+}
+
+/// Emits the `<Name>Builder` used to construct a struct by hand, whose
+/// `build` method enforces the same required headers as parsing.
+fn expand_builder(
+    derive_input: &DeriveInput,
+    data: &DataStruct,
+    fields: &[(HeaderField<'_>, bool)],
+) -> proc_macro2::TokenStream {
+    let name = &derive_input.ident;
+    let vis = &derive_input.vis;
+    let generics = &derive_input.generics;
+    let params = &generics.params;
+    let where_clause = &generics.where_clause;
+    let builder_name = format_ident!("{name}Builder");
+    let mut builder_fields = vec![];
+    let mut setters = vec![];
+    let mut finishers = vec![];
+    for (field, (header_field, _)) in data.fields.iter().zip(fields) {
+        let ident = header_field.ident();
+        let header_key = header_field.header_key();
+        let ty = if is_type_option(&field.ty) {
+            get_first_generic_type(&field.ty)
+        } else {
+            &field.ty
+        };
+        let doc = if header_field.is_header() {
+            format!("Sets the `{header_key}` header.")
+        } else {
+            format!("Sets the `{ident}` header order.")
+        };
+        builder_fields.push(quote! { #ident: Option<#ty> });
+        setters.push(quote! {
+            #[doc = #doc]
+            pub fn #ident(mut self, value: #ty) -> Self {
+                self.#ident = Some(value);
+                self
+            }
+        });
+        finishers.push(match header_field {
+            HeaderField::RequiredSingle(..) | HeaderField::RequiredArray(..) => quote! {
+                #ident: noggin::runtime::required(self.#ident, #header_key)?
+            },
+            HeaderField::RequiredRepeated(..) => quote! {
+                #ident: match self.#ident {
+                    Some(value) if !value.is_empty() => value,
+                    _ => noggin::runtime::required(None, #header_key)?,
+                }
+            },
+            HeaderField::OptionalSingle(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::OptionalArray(..) => quote! {
+                #ident: self.#ident
+            },
+            HeaderField::Order(..) => quote! {
+                #ident: self.#ident.unwrap_or_default()
+            },
+        });
+    }
+    let doc = format!("A builder for [`{name}`], see [`{name}::builder`].");
+    quote! {
+        #[doc = #doc]
+        #[derive(Default)]
+        #vis struct #builder_name<#params> #where_clause {
+            #(
+                #builder_fields
+            ),*
+        }
+
+        impl<#params> #builder_name<#params> #where_clause {
+            #(
+                #setters
+            )*
+
+            /// Builds the struct, or reports the first required header that
+            /// wasn't set. Required lists must not be empty, just like when
+            /// parsing.
+            pub fn build(self) -> Result<#name<#params>, noggin::Error<'static>> {
+                Ok(#name {
+                    #(
+                        #finishers
+                    ),*
+                })
+            }
+        }
+
+        impl<#params> #name<#params> #where_clause {
+            /// Returns a builder to construct the headers by hand.
+            pub fn builder() -> #builder_name<#params> {
+                Default::default()
+            }
+        }
+    }
+}
+
+/// Emits `merge` and `diff`, which combine and compare two instances of a
+/// struct header by header.
+fn expand_merge(
+    derive_input: &DeriveInput,
+    fields: &[(HeaderField<'_>, bool)],
+) -> proc_macro2::TokenStream {
+    let name = &derive_input.ident;
+    let generics = &derive_input.generics;
+    let params = &generics.params;
+    let where_clause = &generics.where_clause;
+    let mergers = fields.iter().map(|(f, _)| {
+        let ident = f.ident();
+        match f {
+            HeaderField::RequiredSingle(..)
+            | HeaderField::RequiredRepeated(..)
+            | HeaderField::RequiredArray(..)
+            | HeaderField::Order(..) => quote! { #ident: other.#ident },
+            HeaderField::OptionalSingle(..)
+            | HeaderField::OptionalRepeated(..)
+            | HeaderField::OptionalArray(..) => quote! { #ident: other.#ident.or(self.#ident) },
+        }
+    });
+    let differs = fields.iter().filter(|(f, _)| f.is_header()).map(|(f, _)| {
+        let ident = f.ident();
+        let header_key = f.header_key();
+        quote! {
+            if self.#ident != other.#ident {
+                names.push(#header_key);
+            }
+        }
+    });
+    quote! {
+        impl<#params> #name<#params> #where_clause {
+            /// Combines two sets of headers, the headers present in `other`
+            /// replace the ones in `self`.
+            pub fn merge(self, other: Self) -> Self {
+                #name {
+                    #(
+                        #mergers
+                    ),*
+                }
+            }
+
+            /// Returns the names of the headers whose values differ between
+            /// `self` and `other`.
+            pub fn diff(&self, other: &Self) -> noggin::alloc::vec::Vec<&'static str> {
+                let mut names = noggin::alloc::vec::Vec::new();
+                #(
+                    #differs
+                )*
+                names
+            }
+        }
+    }
+}
+
+#[proc_macro_derive(Noggin, attributes(noggin))]
 pub fn noggin_derive(input: TokenStream) -> TokenStream {
     let derive_input = syn::parse_macro_input!(input as DeriveInput);
-    match &derive_input.data {
-        Data::Struct(data) => {
-            let name = &derive_input.ident;
-            let params = &derive_input.generics.params;
-            let extended_params = extend_decoding_params(params);
-            let fields = HeaderField::parse_all(data);
-            let key = Ident::new("key", Span::call_site());
-            let value = Ident::new("value", Span::call_site());
-            let declarations: Vec<_> = fields.iter().map(|f| f.make_declaration()).collect();
-            let extractors: Vec<_> = fields
-                .iter()
-                .map(|f| f.make_extractor(&key, &value))
-                .collect();
-            let validators: Vec<_> = fields.iter().map(|f| f.make_validator()).collect();
-            let builders: Vec<_> = fields.iter().map(|f| f.make_builders()).collect();
-            let result = quote! {
-                impl<#extended_params> noggin::HeadParser<'de> for #name<#params> {
-                    fn parse_head_section(head: &'de str) -> Result<Self, noggin::Error> {
-                        #(
-                            #declarations
-                        )*
-                        for header in head.split("\r\n") {
-                            let (key, value) = header.split_once(':')
-                                .ok_or(noggin::Error::MalformedHeader)?;
-                            #(
-                                #extractors
-                            )*
-                        }
-                        #(
-                            #validators
-                        )*
-                        let result = #name {
-                            #(
-                                #builders
-                            ),*
-                        };
-                        Ok(result)
-                    }
-                }
-            };
-            result.into()
+    let options = match ContainerOptions::parse(&derive_input.attrs) {
+        Ok(options) => options,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let data = match &derive_input.data {
+        Data::Struct(data) => data,
+        _ => {
+            let error = syn::Error::new_spanned(
+                &derive_input.ident,
+                "Noggin derive macro only works on struct types",
+            );
+            return error.to_compile_error().into();
         }
-        _ => panic!("Noggin derive macro only works on struct types"),
+    };
+    let fields = match HeaderField::parse_all(data) {
+        Ok(fields) => fields,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let mut result = expand_struct(
+        &derive_input.ident,
+        &derive_input.generics,
+        &fields,
+        &options,
+    );
+    if options.partial {
+        result.extend(expand_partial(&derive_input, data, &fields, &options));
+    }
+    if options.builder {
+        result.extend(expand_builder(&derive_input, data, &fields));
+    }
+    if options.merge {
+        result.extend(expand_merge(&derive_input, &fields));
     }
+    result.into()
 }
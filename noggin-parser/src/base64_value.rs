@@ -0,0 +1,93 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use core::ops::Deref;
+
+/// The `Base64` type is a base64 encoded header value, decoded into `T`,
+/// for headers like `Sec-WebSocket-Key`, digests and signatures.
+///
+/// Both the standard and the URL-safe alphabets are accepted, with or
+/// without padding. `Base64<[u8; N]>` requires the value to decode to
+/// exactly `N` bytes and doesn't allocate, [`Base64Bytes`] accepts any
+/// length.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Base64<T>(pub T);
+
+/// A base64 encoded header value of any length, decoded into a `Vec<u8>`.
+#[cfg(feature = "alloc")]
+pub type Base64Bytes = Base64<Vec<u8>>;
+
+impl<T> Deref for Base64<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+const PAD_INDIFFERENT: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, PAD_INDIFFERENT);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, PAD_INDIFFERENT);
+
+fn engine(value: &str) -> &'static GeneralPurpose {
+    if value.contains(['-', '_']) {
+        &URL_SAFE
+    } else {
+        &STANDARD
+    }
+}
+
+impl<'de, const N: usize> FromHeaderValue<'de> for Base64<[u8; N]> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let mut bytes = [0; N];
+        match engine(value).decode_slice(value, &mut bytes) {
+            Ok(len) if len == N => Some(Base64(bytes)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> FromHeaderValue<'de> for Base64<Vec<u8>> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        engine(value).decode(value).ok().map(Base64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case("aGVsbG8=", Some(b"hello".to_vec()))]
+    #[case(" aGVsbG8 ", Some(b"hello".to_vec()))]
+    #[case("+/8=", Some(vec![0xfb, 0xff]))]
+    #[case("-_8", Some(vec![0xfb, 0xff]))]
+    #[case("", Some(vec![]))]
+    #[case("aGVsbG8*", None)]
+    #[case("+_8=", None)]
+    fn base64_bytes_test(#[case] input: &str, #[case] expected: Option<Vec<u8>>) {
+        assert_eq!(
+            expected,
+            Base64Bytes::parse_header_value(input).map(|b| b.0)
+        );
+    }
+
+    #[rstest]
+    #[case("dGhlIHNhbXBsZSBub25jZQ==", true)]
+    #[case("dGhlIHNhbXBsZSBub25jZSE=", false)]
+    #[case("aGVsbG8=", false)]
+    fn base64_array_test(#[case] input: &str, #[case] valid: bool) {
+        let parsed = Base64::<[u8; 16]>::parse_header_value(input);
+        assert_eq!(valid.then_some(*b"the sample nonce"), parsed.map(|b| b.0));
+    }
+}
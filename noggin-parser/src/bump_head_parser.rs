@@ -0,0 +1,125 @@
+use crate::header_parser::{head_from_bytes, split_head};
+use crate::Error;
+use bumpalo::Bump;
+
+/// A `bumpalo::collections::Vec`, the container used for repeated header
+/// fields that allocate from an arena.
+pub type BumpVec<'bump, T> = bumpalo::collections::Vec<'bump, T>;
+
+/// The `BumpHeadParser` trait is the arena allocating counterpart of
+/// [`HeadParser`](crate::HeadParser).
+///
+/// Instead of allocating repeated header fields on the heap, every
+/// [`BumpVec`] field of the parsed struct is allocated from the provided
+/// `Bump`. A server can then release all the allocations of a request at
+/// once by resetting the arena.
+///
+/// This trait is implemented by the `noggin::Noggin` procedural macro for
+/// structs that contain at least one `BumpVec` field.
+pub trait BumpHeadParser<'de>: Sized {
+    /// Parse the HTTP headers from a string slice representing the head section
+    /// of an HTTP message, allocating list data in `bump`.
+    ///
+    /// # Parameters
+    ///
+    /// * `head`: A string slice containing the head section of an HTTP message.
+    /// * `bump`: The arena that repeated header fields are allocated in.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, Error>`: Returns the parsed headers if successful, or
+    ///   an error if parsing fails.
+    fn parse_head_section_in(head: &'de str, bump: &'de Bump) -> Result<Self, Error<'de>>;
+
+    /// Parse the HTTP headers and returns both the parsed headers and the
+    /// remaining body from a byte slice containing both head and body sections
+    /// of an HTTP message, allocating list data in `bump`.
+    ///
+    /// # Parameters
+    ///
+    /// * `head_and_body`: A byte slice containing both the head and body sections
+    ///   of an HTTP message.
+    /// * `bump`: The arena that repeated header fields are allocated in.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(Self, &'de [u8]), Error>`: Returns a tuple containing the parsed
+    ///   headers and the remaining body if successful, or an error if parsing fails.
+    fn parse_headers_in(
+        head_and_body: &'de [u8],
+        bump: &'de Bump,
+    ) -> Result<(Self, &'de [u8]), Error<'de>> {
+        let (head, body) = split_head(head_and_body)?;
+        let headers = Self::parse_head_section_in(head, bump)?;
+        Ok((headers, body))
+    }
+
+    /// Parse the HTTP headers from a byte slice that contains only the head
+    /// section of an HTTP message, allocating list data in `bump`. A trailing
+    /// `\r\n\r\n` or `\r\n` is optional.
+    ///
+    /// # Parameters
+    ///
+    /// * `head`: A byte slice containing only the head section of an HTTP
+    ///   message.
+    /// * `bump`: The arena that repeated header fields are allocated in.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, Error>`: Returns the parsed headers if successful, or
+    ///   an error if parsing fails.
+    fn parse_head_bytes_in(head: &'de [u8], bump: &'de Bump) -> Result<Self, Error<'de>> {
+        Self::parse_head_section_in(head_from_bytes(head)?, bump)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{runtime, HeaderLines};
+
+    #[derive(Debug)]
+    pub struct ListHeaders<'a> {
+        pub accept: BumpVec<'a, &'a str>,
+    }
+
+    impl<'de> BumpHeadParser<'de> for ListHeaders<'de> {
+        fn parse_head_section_in(head: &'de str, bump: &'de Bump) -> Result<Self, Error<'de>> {
+            let mut accept = BumpVec::new_in(bump);
+            runtime::parse_head(
+                HeaderLines::new(head),
+                &["accept"],
+                |key| key.eq_ignore_ascii_case("accept").then_some(0),
+                |_, value| runtime::repeated(&mut accept, value),
+            )?;
+            Ok(ListHeaders { accept })
+        }
+    }
+
+    #[test]
+    fn parse_headers_in_bump() {
+        let bump = Bump::new();
+        let input = b"Accept: a, b\r\nAccept: c\r\n\r\nbody";
+        let (headers, body) = ListHeaders::parse_headers_in(input, &bump).unwrap();
+
+        assert_eq!(headers.accept.as_slice(), ["a", "b", "c"]);
+        assert_eq!(body, b"body");
+    }
+
+    #[test]
+    fn parse_head_bytes_in_bump() {
+        let bump = Bump::new();
+        let headers =
+            ListHeaders::parse_head_bytes_in(b"Accept: a\r\nAccept: b\r\n", &bump).unwrap();
+
+        assert_eq!(headers.accept.as_slice(), ["a", "b"]);
+    }
+
+    #[test]
+    fn error_on_incomplete_head_in_bump() {
+        let bump = Bump::new();
+        let result = ListHeaders::parse_headers_in(b"Accept: a\r\n", &bump);
+
+        assert_eq!(result.unwrap_err(), Error::IncompleteHead);
+    }
+}
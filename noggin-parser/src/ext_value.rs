@@ -0,0 +1,122 @@
+use crate::from_header_value::trim;
+use crate::{FromHeaderValue, LanguageTag};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The `ExtValue` type is an RFC 8187 extended parameter value such as
+/// `UTF-8'en'%E2%82%AC%20rates`, used by the `filename*` and `title*`
+/// parameters to carry non-ASCII text.
+///
+/// The `UTF-8` and `ISO-8859-1` charsets are supported, compared
+/// case-insensitively. The percent-encoded value is decoded into a
+/// `String`, the optional language is borrowed from the header.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExtValue<'a> {
+    charset: &'a str,
+    language: Option<LanguageTag<'a>>,
+    value: String,
+}
+
+impl<'a> ExtValue<'a> {
+    /// Returns the charset the value was encoded with.
+    pub fn charset(&self) -> &'a str {
+        self.charset
+    }
+
+    /// Returns the language of the value, if one was given.
+    pub fn language(&self) -> Option<LanguageTag<'a>> {
+        self.language
+    }
+
+    /// Returns the decoded value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the decoded value, consuming the `ExtValue`.
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+fn is_attr_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~'
+        )
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|digit| digit as u8)
+}
+
+fn percent_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut bytes = encoded.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'%' => {
+                let high = hex_digit(bytes.next()?)?;
+                let low = hex_digit(bytes.next()?)?;
+                decoded.push(high << 4 | low);
+            }
+            b if is_attr_char(b) => decoded.push(b),
+            _ => return None,
+        }
+    }
+    Some(decoded)
+}
+
+impl<'de> FromHeaderValue<'de> for ExtValue<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut parts = trim(slice).splitn(3, '\'');
+        let charset = parts.next()?;
+        let language = match parts.next()? {
+            "" => None,
+            language => Some(LanguageTag::parse_header_value(language)?),
+        };
+        let decoded = percent_decode(parts.next()?)?;
+        let value = if charset.eq_ignore_ascii_case("UTF-8") {
+            String::from_utf8(decoded).ok()?
+        } else if charset.eq_ignore_ascii_case("ISO-8859-1") {
+            decoded.into_iter().map(char::from).collect()
+        } else {
+            return None;
+        };
+        Some(ExtValue {
+            charset,
+            language,
+            value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("UTF-8''%e2%82%ac%20rates", Some(("UTF-8", None, "€ rates")))]
+    #[case("utf-8'en'na%C3%AFve.txt", Some(("utf-8", Some("en"), "naïve.txt")))]
+    #[case("iso-8859-1'en'%A3%20rates", Some(("iso-8859-1", Some("en"), "£ rates")))]
+    #[case(" UTF-8''plain ", Some(("UTF-8", None, "plain")))]
+    #[case("UTF-8''%ff", None)]
+    #[case("UTF-8''%e2%8", None)]
+    #[case("UTF-8''has space", None)]
+    #[case("UTF-16''abc", None)]
+    #[case("UTF-8'en_US'abc", None)]
+    #[case("UTF-8'abc", None)]
+    fn ext_value_test(#[case] input: &str, #[case] expected: Option<(&str, Option<&str>, &str)>) {
+        let parsed = ExtValue::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.as_ref().map(|ext| (
+                ext.charset(),
+                ext.language().map(|language| language.as_str()),
+                ext.value()
+            ))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Error;
+
+/// The `FieldParser` trait parses headers that are already split into
+/// fields, such as the entries of an `http::HeaderMap`, without copying
+/// them into a contiguous head first.
+///
+/// Fields are given as `(name, value)` pairs of the raw name and value
+/// bytes. Fields the struct doesn't declare are skipped without looking at
+/// their value, while a declared field whose value isn't ASCII is reported
+/// as `Error::NonAscii`, unless the struct is marked `#[noggin(obs_text)]`.
+/// Errors report the 1-based position of the field as its line, with
+/// offsets relative to the field's value.
+///
+/// This trait is implemented by the `noggin::Noggin` procedural macro for
+/// structs without `BumpVec` fields.
+pub trait FieldParser<'de>: Sized {
+    /// Parse the HTTP headers from their fields.
+    ///
+    /// # Parameters
+    ///
+    /// * `fields`: The `(name, value)` pairs of the header fields, in the
+    ///   order they were received.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, Error>`: Returns the parsed headers if successful, or
+    ///   an error if parsing fails.
+    fn parse_fields<I>(fields: I) -> Result<Self, Error<'de>>
+    where
+        I: IntoIterator<Item = (&'de str, &'de [u8])>;
+}
@@ -1,3 +1,22 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, borrow::ToOwned, boxed::Box, rc::Rc, string::String, vec::Vec};
+#[cfg(any(feature = "indexmap", feature = "std"))]
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
 /// The `FromHeaderValue` trait provides a mechanism for parsing individual
 /// HTTP header values from string slices.
 ///
@@ -22,7 +41,79 @@ pub trait FromHeaderValue<'de>: Sized {
     fn parse_header_value(slice: &'de str) -> Option<Self>;
 }
 
-fn trim(string: &str) -> &str {
+/// Splits a comma separated header list into its raw elements.
+///
+/// Commas inside quoted-strings, `(comments)` and `<URI-references>` don't
+/// separate elements, so `<a>; title="a, b"` stays a single element. An
+/// unterminated quote, comment or reference extends to the end of the list.
+pub(crate) fn split_list(slice: &str) -> SplitList<'_> {
+    SplitList {
+        rest: Some(slice),
+        separator: b',',
+    }
+}
+
+/// Splits a semicolon separated parameter list into its raw elements, with
+/// the same quoting rules as [`split_list`].
+pub(crate) fn split_params(slice: &str) -> SplitList<'_> {
+    SplitList {
+        rest: Some(slice),
+        separator: b';',
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct SplitList<'a> {
+    rest: Option<&'a str>,
+    separator: u8,
+}
+
+impl<'a> Iterator for SplitList<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.rest?;
+        let mut quoted = false;
+        let mut escaped = false;
+        let mut comments = 0usize;
+        let mut bracketed = false;
+        for (i, b) in rest.bytes().enumerate() {
+            if escaped {
+                escaped = false;
+            } else if quoted {
+                match b {
+                    b'\\' => escaped = true,
+                    b'"' => quoted = false,
+                    _ => {}
+                }
+            } else if comments > 0 {
+                match b {
+                    b'\\' => escaped = true,
+                    b'(' => comments += 1,
+                    b')' => comments -= 1,
+                    _ => {}
+                }
+            } else if bracketed {
+                bracketed = b != b'>';
+            } else {
+                match b {
+                    b'"' => quoted = true,
+                    b'(' => comments = 1,
+                    b'<' => bracketed = true,
+                    b if b == self.separator => {
+                        self.rest = Some(&rest[i + 1..]);
+                        return Some(&rest[..i]);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.rest = None;
+        Some(rest)
+    }
+}
+
+pub(crate) fn trim(string: &str) -> &str {
     string.trim_matches(' ').trim_end_matches(' ')
 }
 
@@ -108,6 +199,95 @@ impl<'de> FromHeaderValue<'de> for isize {
     }
 }
 
+impl<'de> FromHeaderValue<'de> for NonZeroU8 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroU16 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroU32 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroU64 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroU128 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroUsize {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroI8 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroI16 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroI32 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroI64 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroI128 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for NonZeroIsize {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+/// Parses delta-seconds, a non-negative integer number of seconds as used by
+/// `Age`, `Retry-After` or `Access-Control-Max-Age`.
+impl<'de> FromHeaderValue<'de> for Duration {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        parse_digits(trim(slice)).map(Duration::from_secs)
+    }
+}
+
+/// Parses a non-empty string of ASCII digits, rejecting the sign that
+/// `u64::from_str` would accept.
+pub(crate) fn parse_digits(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
 impl<'de> FromHeaderValue<'de> for f32 {
     fn parse_header_value(slice: &'de str) -> Option<Self> {
         trim(slice).parse().ok()
@@ -120,6 +300,42 @@ impl<'de> FromHeaderValue<'de> for f64 {
     }
 }
 
+impl<'de> FromHeaderValue<'de> for IpAddr {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Ipv4Addr {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Ipv6Addr {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SocketAddr {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SocketAddrV4 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SocketAddrV6 {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
 impl<'de> FromHeaderValue<'de> for &'de [u8] {
     fn parse_header_value(slice: &'de str) -> Option<Self> {
         Some(slice.as_bytes())
@@ -132,16 +348,178 @@ impl<'de> FromHeaderValue<'de> for &'de str {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'de> FromHeaderValue<'de> for String {
     fn parse_header_value(slice: &'de str) -> Option<Self> {
         Some(trim(slice).to_owned())
     }
 }
 
+/// Unquotes a quoted-string, borrowing its contents unless they contain
+/// escapes. Values that aren't quoted are borrowed as they are.
+#[cfg(feature = "alloc")]
+pub(crate) fn unquote(value: &str) -> Option<Cow<'_, str>> {
+    let quoted = match value.strip_prefix('"') {
+        Some(rest) => rest.strip_suffix('"')?,
+        None => return Some(Cow::Borrowed(value)),
+    };
+    if !quoted.contains(['\\', '"']) {
+        return Some(Cow::Borrowed(quoted));
+    }
+    let mut unquoted = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.push(chars.next()?),
+            '"' => return None,
+            c => unquoted.push(c),
+        }
+    }
+    Some(Cow::Owned(unquoted))
+}
+
+/// Quoted-strings are unquoted, allocating only when the value contains
+/// escaped characters. Any other value is borrowed as it is.
+#[cfg(feature = "alloc")]
+impl<'de> FromHeaderValue<'de> for Cow<'de, str> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        unquote(trim(slice))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> FromHeaderValue<'de> for Box<str> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Some(trim(slice).into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> FromHeaderValue<'de> for Rc<str> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Some(trim(slice).into())
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<'de> FromHeaderValue<'de> for Arc<str> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Some(trim(slice).into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Box<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        T::parse_header_value(slice).map(Box::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Rc<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        T::parse_header_value(slice).map(Rc::new)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Arc<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        T::parse_header_value(slice).map(Arc::new)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'de, const N: usize> FromHeaderValue<'de> for heapless::String<N> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut string = heapless::String::new();
+        string.push_str(trim(slice)).ok()?;
+        Some(string)
+    }
+}
+
+#[cfg(feature = "mime")]
+impl<'de> FromHeaderValue<'de> for mime::Mime {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        trim(slice).parse().ok()
+    }
+}
+
+#[cfg(feature = "semver")]
+impl<'de> FromHeaderValue<'de> for semver::Version {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        semver::Version::parse(trim(slice)).ok()
+    }
+}
+
+/// Accepts a comma separated list of comparators such as `>=1.2, <2`, the
+/// whole value is parsed as a single requirement.
+#[cfg(feature = "semver")]
+impl<'de> FromHeaderValue<'de> for semver::VersionReq {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        semver::VersionReq::parse(trim(slice)).ok()
+    }
+}
+
+/// Accepts the hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`) and
+/// simple (`67e5504410b1426f9247bb680e5fe0c8`) formats.
+#[cfg(feature = "uuid")]
+impl<'de> FromHeaderValue<'de> for uuid::Uuid {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        if value.len() != 32 && value.len() != 36 {
+            return None;
+        }
+        uuid::Uuid::try_parse(value).ok()
+    }
+}
+
+/// Splits a `key=value` pair on the first `=`, both sides are trimmed and
+/// the key can't be empty.
+impl<'de> FromHeaderValue<'de> for (&'de str, &'de str) {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (key, value) = slice.split_once('=')?;
+        let key = trim(key);
+        if key.is_empty() {
+            return None;
+        }
+        Some((key, trim(value)))
+    }
+}
+
+/// Collects `;` separated `key=value` pairs like those of `Cookie`, empty
+/// elements are skipped and a repeated key keeps its last value.
+#[cfg(feature = "std")]
+impl<'de, S: BuildHasher + Default> FromHeaderValue<'de> for HashMap<&'de str, &'de str, S> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut map = HashMap::default();
+        for param in split_params(slice).filter(|param| !trim(param).is_empty()) {
+            let (key, value) = <(&str, &str)>::parse_header_value(param)?;
+            map.insert(key, value);
+        }
+        Some(map)
+    }
+}
+
+/// Collects `;` separated `key=value` pairs like those of `Cookie`, empty
+/// elements are skipped and a repeated key keeps its last value.
+#[cfg(feature = "alloc")]
+impl<'de> FromHeaderValue<'de> for BTreeMap<&'de str, &'de str> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut map = BTreeMap::new();
+        for param in split_params(slice).filter(|param| !trim(param).is_empty()) {
+            let (key, value) = <(&str, &str)>::parse_header_value(param)?;
+            map.insert(key, value);
+        }
+        Some(map)
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Vec<T> {
     fn parse_header_value(slice: &'de str) -> Option<Self> {
-        let mut values = vec![];
-        for value in slice.split(',') {
+        let mut values = Vec::new();
+        for value in split_list(slice) {
             let parsed = T::parse_header_value(value)?;
             values.push(parsed);
         }
@@ -149,11 +527,44 @@ impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Vec<T> {
     }
 }
 
+/// Collects `;` separated `key=value` pairs in the order they appeared, a
+/// repeated key keeps its first position and its last value.
+#[cfg(feature = "indexmap")]
+impl<'de, S: BuildHasher + Default> FromHeaderValue<'de>
+    for indexmap::IndexMap<&'de str, &'de str, S>
+{
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut map = indexmap::IndexMap::default();
+        for param in split_params(slice).filter(|param| !trim(param).is_empty()) {
+            let (key, value) = <(&str, &str)>::parse_header_value(param)?;
+            map.insert(key, value);
+        }
+        Some(map)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, T, S> FromHeaderValue<'de> for HashSet<T, S>
+where
+    T: FromHeaderValue<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        split_list(slice).map(T::parse_header_value).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: FromHeaderValue<'de> + Ord> FromHeaderValue<'de> for BTreeSet<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        split_list(slice).map(T::parse_header_value).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
-    use std::usize;
 
     #[rstest]
     #[case("true", Some(true))]
@@ -255,6 +666,34 @@ mod tests {
         assert_eq!(expected, isize::parse_header_value(input));
     }
 
+    #[rstest]
+    #[case("42", NonZeroU32::new(42))]
+    #[case(" 1 ", NonZeroU32::new(1))]
+    #[case("0", None)]
+    #[case("-1", None)]
+    fn non_zero_u32_test(#[case] input: &str, #[case] expected: Option<NonZeroU32>) {
+        assert_eq!(expected, NonZeroU32::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("-42", NonZeroI64::new(-42))]
+    #[case("0", None)]
+    #[case("idk", None)]
+    fn non_zero_i64_test(#[case] input: &str, #[case] expected: Option<NonZeroI64>) {
+        assert_eq!(expected, NonZeroI64::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("120", Some(Duration::from_secs(120)))]
+    #[case(" 0 ", Some(Duration::ZERO))]
+    #[case("+1", None)]
+    #[case("-1", None)]
+    #[case("1.5", None)]
+    #[case("", None)]
+    fn duration_test(#[case] input: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(expected, Duration::parse_header_value(input));
+    }
+
     #[rstest]
     #[case("42.7", Some(42.7))]
     #[case("-42.7", Some(-42.7))]
@@ -271,6 +710,31 @@ mod tests {
         assert_eq!(expected, f64::parse_header_value(input));
     }
 
+    #[rstest]
+    #[case("192.0.2.1", Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))))]
+    #[case(" ::1 ", Some(IpAddr::V6(Ipv6Addr::LOCALHOST)))]
+    #[case("example.com", None)]
+    fn ip_addr_test(#[case] input: &str, #[case] expected: Option<IpAddr>) {
+        assert_eq!(expected, IpAddr::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("192.0.2.1:8080", Some(SocketAddr::from(([192, 0, 2, 1], 8080))))]
+    #[case(" [::1]:443 ", Some(SocketAddr::from((Ipv6Addr::LOCALHOST, 443))))]
+    #[case("192.0.2.1", None)]
+    #[case("192.0.2.1:70000", None)]
+    fn socket_addr_test(#[case] input: &str, #[case] expected: Option<SocketAddr>) {
+        assert_eq!(expected, SocketAddr::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("192.0.2.1:80", true, false)]
+    #[case("[::1]:80", false, true)]
+    fn socket_addr_family_test(#[case] input: &str, #[case] v4: bool, #[case] v6: bool) {
+        assert_eq!(v4, SocketAddrV4::parse_header_value(input).is_some());
+        assert_eq!(v6, SocketAddrV6::parse_header_value(input).is_some());
+    }
+
     #[rstest]
     #[case("hello", Some(b"hello".as_slice()))]
     #[case(" hello ", Some(b" hello ".as_slice()))]
@@ -292,6 +756,124 @@ mod tests {
         assert_eq!(expected, String::parse_header_value(input));
     }
 
+    #[rstest]
+    #[case("hello", Some(Cow::Borrowed("hello")))]
+    #[case(" hello ", Some(Cow::Borrowed("hello")))]
+    fn cow_str_test(#[case] input: &str, #[case] expected: Option<Cow<str>>) {
+        let parsed = Cow::<str>::parse_header_value(input);
+        assert!(matches!(parsed, Some(Cow::Borrowed(_))));
+        assert_eq!(expected, parsed);
+    }
+
+    #[rstest]
+    #[case(r#""hello world""#, Some("hello world"), true)]
+    #[case(r#" "" "#, Some(""), true)]
+    #[case(r#""say \"hi\"""#, Some(r#"say "hi""#), false)]
+    #[case(r#""back\\slash""#, Some(r"back\slash"), false)]
+    #[case(r#""unterminated"#, None, false)]
+    #[case(r#""trailing\""#, None, false)]
+    #[case(r#""in"side""#, None, false)]
+    fn cow_str_quoted_test(
+        #[case] input: &str,
+        #[case] expected: Option<&str>,
+        #[case] borrowed: bool,
+    ) {
+        let parsed = Cow::<str>::parse_header_value(input);
+        assert_eq!(expected, parsed.as_deref());
+        assert_eq!(borrowed, matches!(parsed, Some(Cow::Borrowed(_))));
+    }
+
+    #[rstest]
+    #[case(" hello ", Some("hello"))]
+    fn shared_str_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected, Box::<str>::parse_header_value(input).as_deref());
+        assert_eq!(expected, Rc::<str>::parse_header_value(input).as_deref());
+        assert_eq!(expected, Arc::<str>::parse_header_value(input).as_deref());
+    }
+
+    #[rstest]
+    #[case("42", Some(42))]
+    #[case("idk", None)]
+    fn shared_value_test(#[case] input: &str, #[case] expected: Option<u8>) {
+        assert_eq!(expected, Box::<u8>::parse_header_value(input).map(|v| *v));
+        assert_eq!(expected, Rc::<u8>::parse_header_value(input).map(|v| *v));
+        assert_eq!(expected, Arc::<u8>::parse_header_value(input).map(|v| *v));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[rstest]
+    #[case("hello", Some("hello"))]
+    #[case(" hello ", Some("hello"))]
+    #[case("hello world", None)]
+    fn heapless_string_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        let parsed = heapless::String::<8>::parse_header_value(input);
+        assert_eq!(expected, parsed.as_deref());
+    }
+
+    #[cfg(feature = "mime")]
+    #[rstest]
+    #[case("text/html", Some(("text", "html", None)))]
+    #[case(" text/plain; charset=utf-8 ", Some(("text", "plain", Some("utf-8"))))]
+    #[case("multipart/form-data; boundary=\"a b\"", Some(("multipart", "form-data", None)))]
+    #[case("text", None)]
+    fn mime_test(#[case] input: &str, #[case] expected: Option<(&str, &str, Option<&str>)>) {
+        let parsed = mime::Mime::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.as_ref().map(|mime| (
+                mime.type_().as_str(),
+                mime.subtype().as_str(),
+                mime.get_param(mime::CHARSET)
+                    .map(|charset| charset.as_str())
+            ))
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[rstest]
+    #[case("1.2.3", Some((1, 2, 3)))]
+    #[case(" 2.0.0-beta.1+build.5 ", Some((2, 0, 0)))]
+    #[case("1.2", None)]
+    #[case("v1.2.3", None)]
+    fn semver_version_test(#[case] input: &str, #[case] expected: Option<(u64, u64, u64)>) {
+        let parsed = semver::Version::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|version| (version.major, version.minor, version.patch))
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[rstest]
+    #[case(">=1.2, <2", "1.4.0", true)]
+    #[case(">=1.2, <2", "2.0.0", false)]
+    #[case(" ^0.3 ", "0.3.9", true)]
+    fn semver_version_req_test(#[case] input: &str, #[case] version: &str, #[case] matches: bool) {
+        let req = semver::VersionReq::parse_header_value(input).unwrap();
+        assert_eq!(
+            matches,
+            req.matches(&semver::Version::parse(version).unwrap())
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_version_req_invalid() {
+        assert_eq!(None, semver::VersionReq::parse_header_value(">=1.2,"));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[rstest]
+    #[case("67e55044-10b1-426f-9247-bb680e5fe0c8", true)]
+    #[case(" 67E5504410B1426F9247BB680E5FE0C8 ", true)]
+    #[case("{67e55044-10b1-426f-9247-bb680e5fe0c8}", false)]
+    #[case("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8", false)]
+    #[case("67e55044-10b1-426f-9247-bb680e5fe0cg", false)]
+    fn uuid_test(#[case] input: &str, #[case] valid: bool) {
+        let expected = valid.then(|| uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8));
+        assert_eq!(expected, uuid::Uuid::parse_header_value(input));
+    }
+
     #[rstest]
     #[case("1", Some(vec![1]))]
     #[case("1, 2", Some(vec![1, 2]))]
@@ -300,4 +882,78 @@ mod tests {
     fn vec_test(#[case] input: &str, #[case] expected: Option<Vec<u8>>) {
         assert_eq!(expected, Vec::<_>::parse_header_value(input));
     }
+
+    #[rstest]
+    #[case("a,b", vec!["a", "b"])]
+    #[case("", vec![""])]
+    #[case("a,", vec!["a", ""])]
+    #[case(r#"<https://a.example>; title="a, b", <b>"#, vec![r#"<https://a.example>; title="a, b""#, " <b>"])]
+    #[case(r#""say \"hi, there\"", x"#, vec![r#""say \"hi, there\"""#, " x"])]
+    #[case("<https://a.example/?q=1,2>, <b>", vec!["<https://a.example/?q=1,2>", " <b>"])]
+    #[case("1.1 proxy (comment, (nested, more)), 1.0 fred", vec!["1.1 proxy (comment, (nested, more))", " 1.0 fred"])]
+    #[case(r#""open, ended"#, vec![r#""open, ended"#])]
+    fn split_list_test(#[case] input: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, split_list(input).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case("a=b", Some(("a", "b")))]
+    #[case(" a = b=c ", Some(("a", "b=c")))]
+    #[case("a=", Some(("a", "")))]
+    #[case("=b", None)]
+    #[case("ab", None)]
+    fn pair_test(#[case] input: &str, #[case] expected: Option<(&str, &str)>) {
+        assert_eq!(expected, <(&str, &str)>::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("a=1; b=2", Some(vec![("a", "1"), ("b", "2")]))]
+    #[case("a=1;; b=2;", Some(vec![("a", "1"), ("b", "2")]))]
+    #[case(r#"a="x; y"; a=3"#, Some(vec![("a", "3")]))]
+    #[case(r#"q="x; y""#, Some(vec![("q", r#""x; y""#)]))]
+    #[case("", Some(vec![]))]
+    #[case("a=1; b", None)]
+    fn map_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let btree = BTreeMap::parse_header_value(input);
+        assert_eq!(
+            expected,
+            btree.map(|map| map.into_iter().collect::<Vec<_>>())
+        );
+        let hash = HashMap::<_, _>::parse_header_value(input);
+        let expected = expected.map(|pairs| pairs.into_iter().collect::<HashMap<_, _>>());
+        assert_eq!(expected, hash);
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[rstest]
+    #[case("b=1; a=2", Some(vec![("b", "1"), ("a", "2")]))]
+    #[case("b=1; a=2; b=3", Some(vec![("b", "3"), ("a", "2")]))]
+    #[case("b=1; a", None)]
+    fn index_map_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let parsed = indexmap::IndexMap::<_, _>::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|map| map.into_iter().collect::<Vec<_>>())
+        );
+    }
+
+    #[rstest]
+    #[case("b, a, b", Some(vec!["a", "b"]))]
+    #[case("a", Some(vec!["a"]))]
+    fn set_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let btree = BTreeSet::parse_header_value(input);
+        assert_eq!(
+            expected,
+            btree.map(|set| set.into_iter().collect::<Vec<_>>())
+        );
+        let hash = HashSet::<_>::parse_header_value(input);
+        let expected = expected.map(|values| values.into_iter().collect::<HashSet<_>>());
+        assert_eq!(expected, hash);
+    }
+
+    #[test]
+    fn vec_quoted_test() {
+        let parsed = Vec::<&str>::parse_header_value(r#""a, b", c"#);
+        assert_eq!(Some(vec![r#""a, b""#, "c"]), parsed);
+    }
 }
@@ -0,0 +1,314 @@
+use crate::header_parser::split_head_bytes;
+use crate::{Error, ObsText};
+use memchr::{memchr, memchr_iter};
+
+/// The `HeaderLines` iterator walks the header lines of an HTTP head section
+/// and yields each header as a `(name, value)` pair of string slices borrowed
+/// from the head.
+///
+/// Lines are only validated as they are reached, so a malformed line late in
+/// the head does not cost anything until the iterator gets to it. No
+/// allocations are performed. The yielded value is the raw text after the
+/// colon, including any surrounding whitespace.
+///
+/// Values containing control characters other than horizontal tab, such as
+/// NUL or a stray CR or LF, are rejected with `Error::ControlCharacter` since
+/// they allow header injection when the value is later re-emitted. Heads from
+/// trusted sources can skip this check with
+/// [`allow_control_characters`](HeaderLines::allow_control_characters).
+///
+/// This is the primitive that both the `noggin::Noggin` derive and
+/// [`RawHeaders`](crate::RawHeaders) are built on.
+#[derive(Clone, Debug)]
+pub struct HeaderLines<'a> {
+    lines: Lines<'a>,
+}
+
+impl<'a> HeaderLines<'a> {
+    /// Creates an iterator over the header lines of `head`, which must not
+    /// contain the terminating empty line (`\r\n\r\n`).
+    pub fn new(head: &'a str) -> Self {
+        HeaderLines {
+            lines: Lines::new(head.as_bytes()),
+        }
+    }
+
+    /// Accepts control characters in header values instead of reporting them
+    /// as errors. Only use this for heads from trusted sources.
+    pub fn allow_control_characters(mut self) -> Self {
+        self.lines.allow_control_characters = true;
+        self
+    }
+
+    /// Returns the 1-based line number of the most recently yielded header,
+    /// or `0` before the first call to `next`.
+    pub fn line(&self) -> usize {
+        self.lines.line
+    }
+
+    /// Returns the byte offset within the head at which the most recently
+    /// yielded header line starts.
+    pub fn offset(&self) -> usize {
+        self.lines.offset
+    }
+}
+
+impl<'a> Iterator for HeaderLines<'a> {
+    type Item = Result<(&'a str, &'a str), Error<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.lines.next()?;
+        // this is safe because the head was a string slice and it was only
+        // split on ascii bytes, which never occur inside a multi-byte char
+        Some(header.map(|(name, value)| (name, unsafe { core::str::from_utf8_unchecked(value) })))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for HeaderLines<'a> {}
+
+/// The `ObsTextLines` iterator is the counterpart of [`HeaderLines`] for
+/// heads that may carry obs-text (RFC 9110), bytes above `0x7f` that legacy
+/// clients send as ISO-8859-1 in header values.
+///
+/// Header names must still be ASCII tokens, a name containing other bytes
+/// is reported as a malformed header. Values are yielded as [`ObsText`]
+/// and can be read as bytes or decoded. Structs deriving `noggin::Noggin`
+/// parse their heads with it when marked `#[noggin(obs_text)]`.
+#[derive(Clone, Debug)]
+pub struct ObsTextLines<'a> {
+    lines: Lines<'a>,
+}
+
+impl<'a> ObsTextLines<'a> {
+    /// Creates an iterator over the header lines of `head`, which must not
+    /// contain the terminating empty line (`\r\n\r\n`).
+    pub fn new(head: &'a [u8]) -> Self {
+        ObsTextLines {
+            lines: Lines::new(head),
+        }
+    }
+
+    /// Splits a message at the end of its head section and returns an
+    /// iterator over the header lines of the head along with the remaining
+    /// body. Unlike [`HeadParser::parse_headers`](crate::HeadParser::parse_headers)
+    /// the head is not required to be ASCII.
+    pub fn parse_headers(head_and_body: &'a [u8]) -> Result<(Self, &'a [u8]), Error<'a>> {
+        let (head, body) = split_head_bytes(head_and_body)?;
+        Ok((ObsTextLines::new(head), body))
+    }
+
+    /// Accepts control characters in header values instead of reporting them
+    /// as errors. Only use this for heads from trusted sources.
+    pub fn allow_control_characters(mut self) -> Self {
+        self.lines.allow_control_characters = true;
+        self
+    }
+
+    /// Returns the 1-based line number of the most recently yielded header,
+    /// or `0` before the first call to `next`.
+    pub fn line(&self) -> usize {
+        self.lines.line
+    }
+
+    /// Returns the byte offset within the head at which the most recently
+    /// yielded header line starts.
+    pub fn offset(&self) -> usize {
+        self.lines.offset
+    }
+}
+
+impl<'a> Iterator for ObsTextLines<'a> {
+    type Item = Result<(&'a str, ObsText<'a>), Error<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.lines.next()?;
+        Some(header.map(|(name, value)| (name, ObsText::new(value))))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for ObsTextLines<'a> {}
+
+/// The line splitting shared by [`HeaderLines`] and [`ObsTextLines`]. Names
+/// are validated to be ASCII, values are left as bytes.
+#[derive(Clone, Debug)]
+struct Lines<'a> {
+    head: &'a [u8],
+    next: Option<usize>,
+    line: usize,
+    offset: usize,
+    allow_control_characters: bool,
+}
+
+impl<'a> Lines<'a> {
+    fn new(head: &'a [u8]) -> Self {
+        let next = (!head.is_empty()).then_some(0);
+        Lines {
+            head,
+            next,
+            line: 0,
+            offset: 0,
+            allow_control_characters: false,
+        }
+    }
+}
+
+fn split_header(line: &[u8]) -> Option<(&str, &[u8])> {
+    let colon = memchr(b':', line)?;
+    let name = &line[..colon];
+    if name.is_empty()
+        || name
+            .iter()
+            .any(|&b| b == b' ' || !b.is_ascii() || b.is_ascii_control())
+    {
+        return None;
+    }
+    // this is safe because we just checked that the name is ascii
+    let name = unsafe { core::str::from_utf8_unchecked(name) };
+    Some((name, &line[colon + 1..]))
+}
+
+fn find_control_character(value: &[u8]) -> Option<usize> {
+    value
+        .iter()
+        .position(|&b| b.is_ascii_control() && b != b'\t')
+}
+
+fn find_line_end(bytes: &[u8]) -> Option<usize> {
+    memchr_iter(b'\r', bytes).find(|&i| bytes.get(i + 1) == Some(&b'\n'))
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Result<(&'a str, &'a [u8]), Error<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next?;
+        let remaining = &self.head[start..];
+        let line = match find_line_end(remaining) {
+            Some(end) => {
+                self.next = Some(start + end + 2);
+                &remaining[..end]
+            }
+            None => {
+                self.next = None;
+                remaining
+            }
+        };
+        self.line += 1;
+        self.offset = start;
+        let header = match split_header(line) {
+            Some((name, value)) if !self.allow_control_characters => {
+                match find_control_character(value) {
+                    Some(position) => Err(Error::ControlCharacter {
+                        line: self.line,
+                        offset: start + name.len() + 1 + position,
+                    }),
+                    None => Ok((name, value)),
+                }
+            }
+            Some(header) => Ok(header),
+            None => Err(Error::MalformedHeader {
+                line: self.line,
+                offset: self.offset,
+            }),
+        };
+        if header.is_err() {
+            self.next = None;
+        }
+        Some(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", vec![])]
+    #[case("Host: example.com", vec![Ok(("Host", " example.com"))])]
+    #[case(
+        "Host: example.com\r\nAccept:*/*",
+        vec![Ok(("Host", " example.com")), Ok(("Accept", "*/*"))]
+    )]
+    #[case("Empty:", vec![Ok(("Empty", ""))])]
+    #[case("Time: 12:30", vec![Ok(("Time", " 12:30"))])]
+    #[case(
+        "A: 1\r\r\nB: 2",
+        vec![Err(Error::ControlCharacter { line: 1, offset: 4 })]
+    )]
+    #[case(
+        "A: 1\r\nB: x\ny",
+        vec![Ok(("A", " 1")), Err(Error::ControlCharacter { line: 2, offset: 10 })]
+    )]
+    #[case("A: \0", vec![Err(Error::ControlCharacter { line: 1, offset: 3 })])]
+    #[case("A: \x7f", vec![Err(Error::ControlCharacter { line: 1, offset: 3 })])]
+    #[case("A:\tx\t", vec![Ok(("A", "\tx\t"))])]
+    #[case("A\0: x", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    #[case("Ä: x", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    #[case(
+        "A: 1\r\n",
+        vec![Ok(("A", " 1")), Err(Error::MalformedHeader { line: 2, offset: 6 })]
+    )]
+    #[case(
+        "Host: example.com\r\nBroken\r\nAccept: */*",
+        vec![
+            Ok(("Host", " example.com")),
+            Err(Error::MalformedHeader { line: 2, offset: 19 })
+        ]
+    )]
+    #[case(": no-name", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    #[case("Bad Name: value", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    #[case("Host : example.com", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    fn header_lines_test(#[case] head: &str, #[case] expected: Vec<Result<(&str, &str), Error>>) {
+        let lines: Vec<_> = HeaderLines::new(head).collect();
+        assert_eq!(expected, lines);
+    }
+
+    #[test]
+    fn header_lines_allowing_control_characters() {
+        let head = "A: 1\r\r\nB: \0";
+        let lines: Vec<_> = HeaderLines::new(head).allow_control_characters().collect();
+        assert_eq!(lines, vec![Ok(("A", " 1\r")), Ok(("B", " \0"))]);
+    }
+
+    #[rstest]
+    #[case(b"Name: caf\xe9", vec![Ok(("Name", &b" caf\xe9"[..]))])]
+    #[case(
+        b"A: 1\r\nB: \xa3\x01",
+        vec![Ok(("A", &b" 1"[..])), Err(Error::ControlCharacter { line: 2, offset: 10 })]
+    )]
+    #[case(b"N\xe4me: x", vec![Err(Error::MalformedHeader { line: 1, offset: 0 })])]
+    fn obs_text_lines_test(
+        #[case] head: &[u8],
+        #[case] expected: Vec<Result<(&str, &[u8]), Error>>,
+    ) {
+        let lines: Vec<_> = ObsTextLines::new(head)
+            .map(|header| header.map(|(name, value)| (name, value.as_bytes())))
+            .collect();
+        assert_eq!(expected, lines);
+    }
+
+    #[test]
+    fn obs_text_lines_with_body() {
+        let (mut lines, body) =
+            ObsTextLines::parse_headers(b"Title: Fran\xe7ais\r\n\r\nbody").unwrap();
+        let (name, value) = lines.next().unwrap().unwrap();
+
+        assert_eq!(name, "Title");
+        assert_eq!(value.trim().decode_latin1(), "Français");
+        assert_eq!(lines.next(), None);
+        assert_eq!(body, b"body");
+    }
+
+    #[test]
+    fn header_lines_positions() {
+        let mut lines = HeaderLines::new("Host: a\r\nAccept: b\r\nX: c");
+        assert_eq!((lines.line(), lines.offset()), (0, 0));
+        lines.next();
+        assert_eq!((lines.line(), lines.offset()), (1, 0));
+        lines.next();
+        assert_eq!((lines.line(), lines.offset()), (2, 9));
+        lines.next();
+        assert_eq!((lines.line(), lines.offset()), (3, 20));
+    }
+}
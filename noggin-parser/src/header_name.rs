@@ -0,0 +1,113 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// The `HeaderName` type is the name of a header as reported by [`Error`](crate::Error).
+///
+/// Names known at compile time, like the ones generated by the `noggin::Noggin`
+/// derive, are stored as `&'static str` without allocating. With the `alloc`
+/// feature, names computed at runtime can be stored as an owned `String`.
+/// Both forms compare equal when they contain the same text.
+#[derive(Clone)]
+pub struct HeaderName(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    #[cfg(feature = "alloc")]
+    Owned(String),
+}
+
+impl HeaderName {
+    /// Creates a header name from a string known at compile time.
+    pub const fn from_static(name: &'static str) -> Self {
+        HeaderName(Repr::Static(name))
+    }
+
+    /// Returns the header name as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(name) => name,
+            #[cfg(feature = "alloc")]
+            Repr::Owned(name) => name,
+        }
+    }
+}
+
+impl Deref for HeaderName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&'static str> for HeaderName {
+    fn from(name: &'static str) -> Self {
+        HeaderName::from_static(name)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for HeaderName {
+    fn from(name: String) -> Self {
+        HeaderName(Repr::Owned(name))
+    }
+}
+
+impl PartialEq for HeaderName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for HeaderName {}
+
+impl PartialEq<str> for HeaderName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for HeaderName {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for HeaderName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for HeaderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for HeaderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_and_owned_names_are_equal() {
+        let from_static = HeaderName::from_static("x-request-id");
+        let owned = HeaderName::from(String::from("x-request-id"));
+
+        assert_eq!(from_static, owned);
+        assert_eq!(owned, "x-request-id");
+        assert_eq!(from_static.len(), 12);
+        assert_eq!(owned.to_string(), "x-request-id");
+        assert_eq!(format!("{from_static:?}"), "\"x-request-id\"");
+    }
+}
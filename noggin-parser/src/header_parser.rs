@@ -1,17 +1,52 @@
+use crate::{HeaderName, RejectedValue};
 use memchr::memmem;
 
 #[derive(thiserror::Error, PartialEq, Debug)]
-pub enum Error {
+pub enum Error<'a> {
     #[error("the http head was not complete")]
     IncompleteHead,
     #[error("the http head contained non-ascii characters")]
     NonAscii,
     #[error("missing http header: {0}")]
-    MissingHeader(&'static str),
-    #[error("malformed http header")]
-    MalformedHeader,
-    #[error("invalid http header value: {0}")]
-    InvalidHeaderValue(&'static str),
+    MissingHeader(HeaderName),
+    #[error("malformed http header on line {line} (byte {offset})")]
+    MalformedHeader { line: usize, offset: usize },
+    #[error("control character in http header value on line {line} (byte {offset})")]
+    ControlCharacter { line: usize, offset: usize },
+    #[error("invalid http header value: {name}: {value:?} on line {line} (byte {offset})")]
+    InvalidHeaderValue {
+        name: HeaderName,
+        value: RejectedValue<'a>,
+        line: usize,
+        offset: usize,
+    },
+}
+
+impl<'a> Error<'a> {
+    /// Detaches the error from the parsed head by copying the rejected value
+    /// of an `InvalidHeaderValue`, for example to return it from a function
+    /// that doesn't own the buffer. Without the `alloc` feature the value
+    /// can't be copied and is emptied instead.
+    pub fn into_static(self) -> Error<'static> {
+        match self {
+            Error::IncompleteHead => Error::IncompleteHead,
+            Error::NonAscii => Error::NonAscii,
+            Error::MissingHeader(name) => Error::MissingHeader(name),
+            Error::MalformedHeader { line, offset } => Error::MalformedHeader { line, offset },
+            Error::ControlCharacter { line, offset } => Error::ControlCharacter { line, offset },
+            Error::InvalidHeaderValue {
+                name,
+                value,
+                line,
+                offset,
+            } => Error::InvalidHeaderValue {
+                name,
+                value: value.into_owned(),
+                line,
+                offset,
+            },
+        }
+    }
 }
 
 /// The `HeadParser` trait provides a way to parse HTTP headers and potentially
@@ -32,7 +67,7 @@ pub trait HeadParser<'de>: Sized {
     ///
     /// * `Result<Self, Error>`: Returns the parsed headers if successful, or
     ///   an error if parsing fails.
-    fn parse_head_section(head: &'de str) -> Result<Self, Error>;
+    fn parse_head_section(head: &'de str) -> Result<Self, Error<'de>>;
 
     /// Parse the HTTP headers and returns both the parsed headers and the
     /// remaining body from a byte slice containing both head and body sections
@@ -51,24 +86,79 @@ pub trait HeadParser<'de>: Sized {
     ///
     /// * `Result<(Self, &'de [u8]), Error>`: Returns a tuple containing the parsed
     ///   headers and the remaining body if successful, or an error if parsing fails.
-    fn parse_headers(head_and_body: &'de [u8]) -> Result<(Self, &'de [u8]), Error> {
-        let head_end = memmem::find(head_and_body, b"\r\n\r\n").ok_or(Error::IncompleteHead)?;
-        let head_bytes = &head_and_body[..head_end];
-        if !head_bytes.is_ascii() {
-            return Err(Error::NonAscii);
-        }
-        // this is safe because we just checked if the bytes contained valid
-        // ascii and ascii is strict subset of utf-8
-        let head = unsafe { std::str::from_utf8_unchecked(head_bytes) };
+    fn parse_headers(head_and_body: &'de [u8]) -> Result<(Self, &'de [u8]), Error<'de>> {
+        let (head, body) = split_head(head_and_body)?;
         let headers = Self::parse_head_section(head)?;
-        let body = &head_and_body[head_end + 4..];
         Ok((headers, body))
     }
+
+    /// Parse the HTTP headers from a byte slice that contains only the head
+    /// section of an HTTP message, such as a head that was already framed by
+    /// the caller or replayed from a log.
+    ///
+    /// Unlike `parse_headers` the head doesn't have to end with `\r\n\r\n`,
+    /// a single trailing `\r\n` or none at all is accepted too.
+    ///
+    /// # Parameters
+    ///
+    /// * `head`: A byte slice containing only the head section of an HTTP
+    ///   message.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, Error>`: Returns the parsed headers if successful, or
+    ///   an error if parsing fails.
+    fn parse_head_bytes(head: &'de [u8]) -> Result<Self, Error<'de>> {
+        Self::parse_head_section(head_from_bytes(head)?)
+    }
+}
+
+/// Splits a message at the end of its head section and validates that the
+/// head is ASCII, returning the head (without the terminating `\r\n\r\n`)
+/// and the remaining body.
+pub(crate) fn split_head(head_and_body: &[u8]) -> Result<(&str, &[u8]), Error<'static>> {
+    let (head_bytes, body) = split_head_bytes(head_and_body)?;
+    if !head_bytes.is_ascii() {
+        return Err(Error::NonAscii);
+    }
+    // this is safe because we just checked if the bytes contained valid
+    // ascii and ascii is strict subset of utf-8
+    let head = unsafe { core::str::from_utf8_unchecked(head_bytes) };
+    Ok((head, body))
+}
+
+/// Validates that a head-only buffer is ASCII and strips its optional
+/// terminating `\r\n\r\n` or `\r\n`.
+pub(crate) fn head_from_bytes(head: &[u8]) -> Result<&str, Error<'static>> {
+    let head = strip_head_terminator(head);
+    if !head.is_ascii() {
+        return Err(Error::NonAscii);
+    }
+    // this is safe because we just checked if the bytes contained valid
+    // ascii and ascii is strict subset of utf-8
+    Ok(unsafe { core::str::from_utf8_unchecked(head) })
+}
+
+/// Strips the optional terminating `\r\n\r\n` or `\r\n` of a head-only
+/// buffer without validating it.
+pub fn strip_head_terminator(head: &[u8]) -> &[u8] {
+    head.strip_suffix(b"\r\n\r\n")
+        .or_else(|| head.strip_suffix(b"\r\n"))
+        .unwrap_or(head)
+}
+
+/// Splits a message at the end of its head section without validating the
+/// head, returning the head (without the terminating `\r\n\r\n`) and the
+/// remaining body.
+pub fn split_head_bytes(head_and_body: &[u8]) -> Result<(&[u8], &[u8]), Error<'static>> {
+    let head_end = memmem::find(head_and_body, b"\r\n\r\n").ok_or(Error::IncompleteHead)?;
+    Ok((&head_and_body[..head_end], &head_and_body[head_end + 4..]))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[derive(Debug, PartialEq)]
     pub struct SimpleHeaders {
@@ -77,20 +167,32 @@ mod tests {
     }
 
     impl<'de> HeadParser<'de> for SimpleHeaders {
-        fn parse_head_section(head: &'de str) -> Result<Self, Error> {
+        fn parse_head_section(head: &'de str) -> Result<Self, Error<'de>> {
             // A simple parsing implementation, for illustration.
-            let content_length_str = head
+            let (index, line) = head
                 .split("\r\n")
-                .find(|line| line.starts_with("Content-Length:"))
-                .ok_or(Error::MissingHeader("Content-Length"))?
+                .enumerate()
+                .find(|(_, line)| line.starts_with("Content-Length:"))
+                .ok_or(Error::MissingHeader("Content-Length".into()))?;
+            let offset = line.as_ptr() as usize - head.as_ptr() as usize;
+            let content_length_str = line
                 .split(':')
                 .nth(1)
-                .ok_or(Error::MalformedHeader)?
+                .ok_or(Error::MalformedHeader {
+                    line: index + 1,
+                    offset,
+                })?
                 .trim();
 
-            let content_length = content_length_str
-                .parse::<usize>()
-                .map_err(|_| Error::InvalidHeaderValue("Content-Length"))?;
+            let content_length =
+                content_length_str
+                    .parse::<usize>()
+                    .map_err(|_| Error::InvalidHeaderValue {
+                        name: "Content-Length".into(),
+                        value: content_length_str.into(),
+                        line: index + 1,
+                        offset,
+                    })?;
 
             Ok(SimpleHeaders {
                 content_length,
@@ -99,6 +201,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn error_into_static() {
+        let value = String::from("abc");
+        let error = Error::InvalidHeaderValue {
+            name: "Content-Length".into(),
+            value: value.as_str().into(),
+            line: 2,
+            offset: 10,
+        };
+        assert_eq!(
+            error.to_string(),
+            "invalid http header value: Content-Length: \"abc\" on line 2 (byte 10)"
+        );
+        let detached = error.into_static();
+        drop(value);
+        assert_eq!(
+            detached,
+            Error::InvalidHeaderValue {
+                name: "Content-Length".into(),
+                value: "abc".into(),
+                line: 2,
+                offset: 10,
+            }
+        );
+    }
+
     #[test]
     fn parse_valid_head() {
         let input_head = b"Content-Length: 5\r\nAnother-Header: value\r\n\r\nBodyHere";
@@ -108,6 +236,15 @@ mod tests {
         assert_eq!(body, b"BodyHere");
     }
 
+    #[rstest]
+    #[case(b"Content-Length: 5", Ok(SimpleHeaders { content_length: 5 }))]
+    #[case(b"Content-Length: 5\r\n", Ok(SimpleHeaders { content_length: 5 }))]
+    #[case(b"Content-Length: 5\r\n\r\n", Ok(SimpleHeaders { content_length: 5 }))]
+    #[case(b"Content-Length: \xff", Err(Error::NonAscii))]
+    fn parse_head_bytes(#[case] head: &[u8], #[case] expected: Result<SimpleHeaders, Error>) {
+        assert_eq!(SimpleHeaders::parse_head_bytes(head), expected);
+    }
+
     #[test]
     fn error_on_non_ascii_head() {
         let input_head = b"Content-Length: 5\r\nNon-Ascii: \x80\x81\x82\r\n\r\nBodyHere";
@@ -129,7 +266,7 @@ mod tests {
         let input_head = b"Wrong-Header: 5\r\nAnother-Header: value\r\n\r\nBodyHere";
         let result = SimpleHeaders::parse_headers(input_head);
 
-        assert_eq!(result, Err(Error::MissingHeader("Content-Length")));
+        assert_eq!(result, Err(Error::MissingHeader("Content-Length".into())));
     }
 
     #[test]
@@ -137,6 +274,14 @@ mod tests {
         let input_head = b"Content-Length: invalid_value\r\nAnother-Header: value\r\n\r\nBodyHere";
         let result = SimpleHeaders::parse_headers(input_head);
 
-        assert_eq!(result, Err(Error::InvalidHeaderValue("Content-Length")));
+        assert_eq!(
+            result,
+            Err(Error::InvalidHeaderValue {
+                name: "Content-Length".into(),
+                value: "invalid_value".into(),
+                line: 1,
+                offset: 0
+            })
+        );
     }
 }
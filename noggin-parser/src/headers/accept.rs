@@ -0,0 +1,146 @@
+use super::ContentType;
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, Weighted};
+
+/// The `Accept` type is the value of an `Accept` header, a list of media
+/// ranges such as `text/html, application/*;q=0.8, */*;q=0.1`.
+///
+/// Every media range is validated when the header is parsed, the ranges
+/// themselves are borrowed from the header and parsed again on iteration,
+/// so `Accept` never allocates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Accept<'a>(&'a str);
+
+impl<'a> Accept<'a> {
+    /// Returns an iterator over the media ranges and their quality, in the
+    /// order they were sent in. A media range is a [`ContentType`] whose
+    /// type or subtype may be `*`.
+    pub fn ranges(&self) -> impl Iterator<Item = Weighted<ContentType<'a>>> {
+        split_list(self.0)
+            .filter(|range| !trim(range).is_empty())
+            .filter_map(Weighted::parse_header_value)
+    }
+
+    /// Returns the quality of `content_type`, taken from the most specific
+    /// media range matching it, or `0.0` if none does.
+    pub fn quality(&self, content_type: &ContentType<'_>) -> f32 {
+        let mut best: Option<(usize, f32)> = None;
+        for range in self.ranges() {
+            let Some(specificity) = specificity(&range.item, content_type) else {
+                continue;
+            };
+            if best.is_none_or(|(best, _)| specificity > best) {
+                best = Some((specificity, range.quality));
+            }
+        }
+        best.map_or(0.0, |(_, quality)| quality)
+    }
+
+    /// Picks the best of the `available` media types following RFC 9110:
+    /// each one gets the quality of its most specific matching range, the
+    /// highest non-zero quality wins and ties go to the earlier entry of
+    /// `available`. An `Accept` without any range accepts everything.
+    pub fn negotiate<'s>(&self, available: &[&'s str]) -> Option<&'s str> {
+        if self.ranges().next().is_none() {
+            return available.first().copied();
+        }
+        let mut best: Option<(&'s str, f32)> = None;
+        for &candidate in available {
+            let Some(content_type) = ContentType::parse_header_value(candidate) else {
+                continue;
+            };
+            let quality = self.quality(&content_type);
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((candidate, quality));
+            }
+        }
+        best.map(|(candidate, _)| candidate)
+    }
+}
+
+/// Returns how specific `range` is when it matches `content_type`, from
+/// `*/*` at 0 up to a range with parameters at 3.
+fn specificity(range: &ContentType<'_>, content_type: &ContentType<'_>) -> Option<usize> {
+    if range.essence() == "*/*" {
+        return Some(0);
+    }
+    if !range
+        .media_type()
+        .eq_ignore_ascii_case(content_type.media_type())
+    {
+        return None;
+    }
+    if range.subtype() == "*" {
+        return Some(1);
+    }
+    if !range.subtype().eq_ignore_ascii_case(content_type.subtype()) {
+        return None;
+    }
+    let mut params = range.params().peekable();
+    if params.peek().is_none() {
+        return Some(2);
+    }
+    params
+        .all(|(name, value)| content_type.param(name) == Some(value))
+        .then_some(3)
+}
+
+impl<'de> FromHeaderValue<'de> for Accept<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let valid = split_list(slice)
+            .filter(|range| !trim(range).is_empty())
+            .all(|range| Weighted::<ContentType>::parse_header_value(range).is_some());
+        valid.then_some(Accept(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("text/html, application/json;q=0.9", true)]
+    #[case("*/*", true)]
+    #[case("text/*;q=0.5,", true)]
+    #[case("", true)]
+    #[case("text/html;q=2", false)]
+    #[case("html", false)]
+    fn accept_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, Accept::parse_header_value(input).is_some());
+    }
+
+    #[rstest]
+    #[case("text/html, application/json;q=0.9", &["application/json", "text/html"], Some("text/html"))]
+    #[case("text/*;q=0.3, text/html;q=0.7, */*;q=0.5", &["text/plain", "image/png"], Some("image/png"))]
+    #[case("text/*;q=0.3, text/html;q=0.7, */*;q=0.5", &["text/html", "image/png"], Some("text/html"))]
+    #[case("text/html;level=1, text/html;q=0.2", &["text/html", "text/html;level=1"], Some("text/html;level=1"))]
+    #[case("application/json, text/html", &["text/html", "application/json"], Some("text/html"))]
+    #[case("image/*, */*;q=0", &["text/html"], None)]
+    #[case("application/json", &["text/html"], None)]
+    #[case("", &["text/html", "application/json"], Some("text/html"))]
+    fn accept_negotiate(
+        #[case] input: &str,
+        #[case] available: &[&str],
+        #[case] expected: Option<&str>,
+    ) {
+        let accept = Accept::parse_header_value(input).unwrap();
+        assert_eq!(expected, accept.negotiate(available));
+    }
+
+    #[test]
+    fn accept_ranges() {
+        let accept = Accept::parse_header_value("text/html;level=1;q=0.5, */*").unwrap();
+        let ranges: Vec<_> = accept
+            .ranges()
+            .map(|range| {
+                (
+                    range.item.essence(),
+                    range.item.param("level"),
+                    range.quality,
+                )
+            })
+            .collect();
+        assert_eq!(ranges, [("text/html", Some("1"), 0.5), ("*/*", None, 1.0)]);
+    }
+}
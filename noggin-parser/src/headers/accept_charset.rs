@@ -0,0 +1,109 @@
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::{FromHeaderValue, Weighted};
+
+/// The `AcceptCharset` type is the value of an `Accept-Charset` header, a
+/// list of charsets with their quality such as `utf-8, iso-8859-1;q=0.5`.
+///
+/// The charsets are validated when the header is parsed and borrowed from
+/// the header on iteration. They are compared case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AcceptCharset<'a>(&'a str);
+
+impl<'a> AcceptCharset<'a> {
+    /// Returns an iterator over the charsets and their quality, in the
+    /// order they were sent in. The `*` wildcard is yielded as `None`.
+    pub fn charsets(&self) -> impl Iterator<Item = Weighted<Option<&'a str>>> {
+        split_list(self.0)
+            .filter(|charset| !trim(charset).is_empty())
+            .filter_map(Weighted::<&str>::parse_header_value)
+            .map(|charset| Weighted {
+                item: (charset.item != "*").then_some(charset.item),
+                quality: charset.quality,
+            })
+    }
+
+    /// Returns the quality of `charset`. A charset that isn't listed gets
+    /// the quality of `*`, or `0.0` without it.
+    pub fn quality(&self, charset: &str) -> f32 {
+        let mut wildcard = 0.0;
+        for entry in self.charsets() {
+            match entry.item {
+                Some(listed) if listed.eq_ignore_ascii_case(charset) => return entry.quality,
+                None => wildcard = entry.quality,
+                Some(_) => {}
+            }
+        }
+        wildcard
+    }
+
+    /// Picks the charset of `available` with the highest non-zero quality,
+    /// ties go to the earlier entry of `available`.
+    pub fn preferred<'s>(&self, available: &[&'s str]) -> Option<&'s str> {
+        let mut best: Option<(&'s str, f32)> = None;
+        for &charset in available {
+            let quality = self.quality(charset);
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((charset, quality));
+            }
+        }
+        best.map(|(charset, _)| charset)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AcceptCharset<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut count = 0;
+        let valid = split_list(slice)
+            .filter(|charset| !trim(charset).is_empty())
+            .all(|charset| {
+                count += 1;
+                Weighted::<&str>::parse_header_value(charset).is_some_and(|c| is_token(c.item))
+            });
+        (valid && count > 0).then_some(AcceptCharset(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("utf-8", Some(vec![(Some("utf-8"), 1.0)]))]
+    #[case(
+        "iso-8859-5, unicode-1-1;q=0.8, *;q=0.1",
+        Some(vec![(Some("iso-8859-5"), 1.0), (Some("unicode-1-1"), 0.8), (None, 0.1)])
+    )]
+    #[case("", None)]
+    #[case("utf-8;q=2", None)]
+    #[case("utf 8", None)]
+    fn accept_charset_test(
+        #[case] input: &str,
+        #[case] expected: Option<Vec<(Option<&str>, f32)>>,
+    ) {
+        let parsed = AcceptCharset::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|accept| accept
+                .charsets()
+                .map(|charset| (charset.item, charset.quality))
+                .collect())
+        );
+    }
+
+    #[rstest]
+    #[case("utf-8, iso-8859-1;q=0.5", &["iso-8859-1", "UTF-8"], Some("UTF-8"))]
+    #[case("utf-8;q=0.5, iso-8859-1", &["utf-8", "iso-8859-1"], Some("iso-8859-1"))]
+    #[case("utf-8", &["iso-8859-1"], None)]
+    #[case("*;q=0.1, utf-8;q=0", &["utf-8", "shift_jis"], Some("shift_jis"))]
+    #[case("*", &["utf-8", "shift_jis"], Some("utf-8"))]
+    fn accept_charset_preferred(
+        #[case] input: &str,
+        #[case] available: &[&str],
+        #[case] expected: Option<&str>,
+    ) {
+        let accept = AcceptCharset::parse_header_value(input).unwrap();
+        assert_eq!(expected, accept.preferred(available));
+    }
+}
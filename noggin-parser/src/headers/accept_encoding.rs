@@ -0,0 +1,207 @@
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::{FromHeaderValue, Weighted};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// The `ContentCoding` type is a content coding as found in
+/// `Content-Encoding` and `Accept-Encoding`, compared case-insensitively.
+///
+/// `x-gzip` is accepted as an alias of `gzip`. Unknown codings are kept as
+/// [`Other`](ContentCoding::Other), borrowed from the header.
+#[derive(Clone, Copy, Eq, Debug)]
+pub enum ContentCoding<'a> {
+    /// `gzip` or `x-gzip`
+    Gzip,
+    /// `br`, Brotli
+    Br,
+    /// `zstd`, Zstandard
+    Zstd,
+    /// `deflate`, zlib
+    Deflate,
+    /// `compress` or `x-compress`
+    Compress,
+    /// `identity`, no encoding
+    Identity,
+    /// Any other coding
+    Other(&'a str),
+}
+
+impl<'a> ContentCoding<'a> {
+    /// Returns the registered name of the coding.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            ContentCoding::Gzip => "gzip",
+            ContentCoding::Br => "br",
+            ContentCoding::Zstd => "zstd",
+            ContentCoding::Deflate => "deflate",
+            ContentCoding::Compress => "compress",
+            ContentCoding::Identity => "identity",
+            ContentCoding::Other(name) => name,
+        }
+    }
+
+    fn from_token(token: &'a str) -> Self {
+        const KNOWN: [ContentCoding<'static>; 6] = [
+            ContentCoding::Gzip,
+            ContentCoding::Br,
+            ContentCoding::Zstd,
+            ContentCoding::Deflate,
+            ContentCoding::Compress,
+            ContentCoding::Identity,
+        ];
+        if token.eq_ignore_ascii_case("x-gzip") {
+            return ContentCoding::Gzip;
+        }
+        if token.eq_ignore_ascii_case("x-compress") {
+            return ContentCoding::Compress;
+        }
+        KNOWN
+            .into_iter()
+            .find(|coding| coding.as_str().eq_ignore_ascii_case(token))
+            .unwrap_or(ContentCoding::Other(token))
+    }
+}
+
+impl<'a, 'b> PartialEq<ContentCoding<'b>> for ContentCoding<'a> {
+    fn eq(&self, other: &ContentCoding<'b>) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl<'a> Hash for ContentCoding<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.as_str().bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentCoding<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        (is_token(value) && value != "*").then(|| ContentCoding::from_token(value))
+    }
+}
+
+impl<'a> fmt::Display for ContentCoding<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The `AcceptEncoding` type is the value of an `Accept-Encoding` header,
+/// a list of content codings with their quality such as
+/// `gzip, br;q=0.9, *;q=0.1`.
+///
+/// The codings are validated when the header is parsed and borrowed from
+/// the header on iteration.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AcceptEncoding<'a>(&'a str);
+
+impl<'a> AcceptEncoding<'a> {
+    /// Returns an iterator over the codings and their quality, in the order
+    /// they were sent in. The `*` wildcard is yielded as `None`.
+    pub fn codings(&self) -> impl Iterator<Item = Weighted<Option<ContentCoding<'a>>>> {
+        split_list(self.0)
+            .filter(|coding| !trim(coding).is_empty())
+            .filter_map(Weighted::<&str>::parse_header_value)
+            .map(|coding| Weighted {
+                item: (coding.item != "*").then(|| ContentCoding::from_token(coding.item)),
+                quality: coding.quality,
+            })
+    }
+
+    /// Returns the quality of `coding`. A coding that isn't listed gets the
+    /// quality of `*`, except `identity`, which is acceptable unless it or
+    /// `*` is explicitly given a quality of `0`.
+    pub fn quality(&self, coding: &ContentCoding<'_>) -> f32 {
+        let mut wildcard = None;
+        for entry in self.codings() {
+            match entry.item {
+                Some(listed) if listed == *coding => return entry.quality,
+                None => wildcard = Some(entry.quality),
+                Some(_) => {}
+            }
+        }
+        match (wildcard, coding) {
+            (Some(quality), _) => quality,
+            (None, ContentCoding::Identity) => 1.0,
+            (None, _) => 0.0,
+        }
+    }
+
+    /// Picks the coding of `available` with the highest non-zero quality,
+    /// ties go to the earlier entry of `available`.
+    pub fn preferred<'s>(&self, available: &[ContentCoding<'s>]) -> Option<ContentCoding<'s>> {
+        let mut best: Option<(ContentCoding<'s>, f32)> = None;
+        for &coding in available {
+            let quality = self.quality(&coding);
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((coding, quality));
+            }
+        }
+        best.map(|(coding, _)| coding)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AcceptEncoding<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let valid = split_list(slice)
+            .filter(|coding| !trim(coding).is_empty())
+            .all(|coding| {
+                Weighted::<&str>::parse_header_value(coding).is_some_and(|c| is_token(c.item))
+            });
+        valid.then_some(AcceptEncoding(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use ContentCoding::*;
+
+    #[rstest]
+    #[case("gzip", Some(Gzip))]
+    #[case(" X-GZIP ", Some(Gzip))]
+    #[case("br", Some(Br))]
+    #[case("zstd", Some(Zstd))]
+    #[case("Identity", Some(Identity))]
+    #[case("exi", Some(Other("exi")))]
+    #[case("*", None)]
+    #[case("a b", None)]
+    fn content_coding_test(#[case] input: &str, #[case] expected: Option<ContentCoding>) {
+        assert_eq!(expected, ContentCoding::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("gzip, br", true)]
+    #[case("gzip;q=1.0, identity; q=0.5, *;q=0", true)]
+    #[case("", true)]
+    #[case("gzip;q=2", false)]
+    #[case("g zip", false)]
+    fn accept_encoding_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, AcceptEncoding::parse_header_value(input).is_some());
+    }
+
+    #[rstest]
+    #[case("gzip, br;q=0.9", &[Br, Gzip], Some(Gzip))]
+    #[case("gzip;q=0.5, br", &[Gzip, Br, Identity], Some(Br))]
+    #[case("gzip, br", &[Br, Gzip], Some(Br))]
+    #[case("zstd", &[Gzip, Identity], Some(Identity))]
+    #[case("", &[Gzip, Identity], Some(Identity))]
+    #[case("zstd, identity;q=0", &[Gzip, Identity], None)]
+    #[case("*;q=0", &[Gzip, Identity], None)]
+    #[case("*;q=0.1, gzip;q=0", &[Gzip, Br], Some(Br))]
+    #[case("EXI", &[Gzip, Other("exi")], Some(Other("exi")))]
+    fn accept_encoding_preferred(
+        #[case] input: &str,
+        #[case] available: &[ContentCoding],
+        #[case] expected: Option<ContentCoding>,
+    ) {
+        let accept = AcceptEncoding::parse_header_value(input).unwrap();
+        assert_eq!(expected, accept.preferred(available));
+    }
+}
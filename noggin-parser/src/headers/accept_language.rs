@@ -0,0 +1,160 @@
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, LanguageTag, Weighted};
+
+/// The `AcceptLanguage` type is the value of an `Accept-Language` header, a
+/// list of language ranges with their quality such as
+/// `fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5`.
+///
+/// The ranges are validated when the header is parsed and borrowed from the
+/// header on iteration. Both matching schemes of RFC 4647 are provided,
+/// [`negotiate`](AcceptLanguage::negotiate) for basic filtering and
+/// [`lookup`](AcceptLanguage::lookup) for lookup.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AcceptLanguage<'a>(&'a str);
+
+impl<'a> AcceptLanguage<'a> {
+    /// Returns an iterator over the language ranges and their quality, in
+    /// the order they were sent in.
+    pub fn ranges(&self) -> impl Iterator<Item = Weighted<LanguageTag<'a>>> {
+        split_list(self.0)
+            .filter(|range| !trim(range).is_empty())
+            .filter_map(Weighted::parse_header_value)
+    }
+
+    /// Returns the quality of `tag`, taken from the longest range it is
+    /// within, or `0.0` if there is none.
+    pub fn quality(&self, tag: &LanguageTag<'_>) -> f32 {
+        let mut best: Option<(usize, f32)> = None;
+        for range in self.ranges().filter(|range| tag.is_within(&range.item)) {
+            let specificity = match range.item.as_str() {
+                "*" => 0,
+                range => range.len(),
+            };
+            if best.is_none_or(|(best, _)| specificity > best) {
+                best = Some((specificity, range.quality));
+            }
+        }
+        best.map_or(0.0, |(_, quality)| quality)
+    }
+
+    /// Picks the best of the `available` languages by basic filtering: each
+    /// one gets the quality of the longest range it is within, the highest
+    /// non-zero quality wins and ties go to the earlier entry of
+    /// `available`. An `Accept-Language` without any range accepts every
+    /// language.
+    pub fn negotiate<'s>(&self, available: &[&'s str]) -> Option<&'s str> {
+        if self.ranges().next().is_none() {
+            return available.first().copied();
+        }
+        let mut best: Option<(&'s str, f32)> = None;
+        for &candidate in available {
+            let Some(tag) = LanguageTag::parse_header_value(candidate) else {
+                continue;
+            };
+            let quality = self.quality(&tag);
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((candidate, quality));
+            }
+        }
+        best.map(|(candidate, _)| candidate)
+    }
+
+    /// Picks one of the `available` languages by lookup: ranges are tried
+    /// from the highest quality down, each one is progressively truncated
+    /// (`zh-Hant-CN`, `zh-Hant`, `zh`) until it equals an available
+    /// language. The `*` range and ranges with a quality of `0` are skipped.
+    pub fn lookup<'s>(&self, available: &[&'s str]) -> Option<&'s str> {
+        let mut current = f32::INFINITY;
+        loop {
+            let quality = self
+                .ranges()
+                .map(|range| range.quality)
+                .filter(|&quality| quality > 0.0 && quality < current)
+                .max_by(f32::total_cmp)?;
+            for range in self.ranges().filter(|range| range.quality == quality) {
+                if let Some(found) = lookup_range(range.item.as_str(), available) {
+                    return Some(found);
+                }
+            }
+            current = quality;
+        }
+    }
+}
+
+fn lookup_range<'s>(mut range: &str, available: &[&'s str]) -> Option<&'s str> {
+    if range == "*" {
+        return None;
+    }
+    loop {
+        let found = available.iter().find(|tag| tag.eq_ignore_ascii_case(range));
+        if let Some(found) = found {
+            return Some(found);
+        }
+        let (truncated, _) = range.rsplit_once('-')?;
+        range = truncated;
+        // a singleton can't end a tag, drop it together with its subtag
+        if let Some((truncated, singleton)) = range.rsplit_once('-') {
+            if singleton.len() == 1 {
+                range = truncated;
+            }
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AcceptLanguage<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let valid = split_list(slice)
+            .filter(|range| !trim(range).is_empty())
+            .all(|range| Weighted::<LanguageTag>::parse_header_value(range).is_some());
+        valid.then_some(AcceptLanguage(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5", true)]
+    #[case("", true)]
+    #[case("en_US", false)]
+    #[case("en;q=1.5", false)]
+    fn accept_language_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, AcceptLanguage::parse_header_value(input).is_some());
+    }
+
+    #[rstest]
+    #[case("fr-CH, fr;q=0.9, en;q=0.8", &["en", "fr"], Some("fr"))]
+    #[case("fr-CH, fr;q=0.9, en;q=0.8", &["en-GB", "de"], Some("en-GB"))]
+    #[case("en, en-GB;q=0", &["en-GB", "en-US"], Some("en-US"))]
+    #[case("de, *;q=0.1", &["ja", "de-AT"], Some("de-AT"))]
+    #[case("de, *;q=0.1", &["ja"], Some("ja"))]
+    #[case("de", &["ja"], None)]
+    #[case("", &["ja", "de"], Some("ja"))]
+    fn accept_language_negotiate(
+        #[case] input: &str,
+        #[case] available: &[&str],
+        #[case] expected: Option<&str>,
+    ) {
+        let accept = AcceptLanguage::parse_header_value(input).unwrap();
+        assert_eq!(expected, accept.negotiate(available));
+    }
+
+    #[rstest]
+    #[case("zh-Hant-CN-x-private1, en;q=0.5", &["en", "zh-Hant", "zh"], Some("zh-Hant"))]
+    #[case("de-CH-1996", &["de"], Some("de"))]
+    #[case("en-a-bbb-ccc", &["en"], Some("en"))]
+    #[case("fr;q=0.5, en-US", &["fr", "en"], Some("en"))]
+    #[case("fr;q=0.5, EN-us", &["fr", "en-US"], Some("en-US"))]
+    #[case("de-CH, *", &["fr"], None)]
+    #[case("en;q=0", &["en"], None)]
+    fn accept_language_lookup(
+        #[case] input: &str,
+        #[case] available: &[&str],
+        #[case] expected: Option<&str>,
+    ) {
+        let accept = AcceptLanguage::parse_header_value(input).unwrap();
+        assert_eq!(expected, accept.lookup(available));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::FromHeaderValue;
+
+/// The `AcceptRanges` type is the value of an `Accept-Ranges` header, the
+/// range units a server supports such as `bytes`, or `none`.
+///
+/// Range units are compared case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AcceptRanges<'a>(&'a str);
+
+impl<'a> AcceptRanges<'a> {
+    /// Returns an iterator over the range units, in order.
+    pub fn units(&self) -> impl Iterator<Item = &'a str> {
+        split_list(self.0).map(trim).filter(|unit| !unit.is_empty())
+    }
+
+    /// Returns `true` if the server accepts `unit` ranges.
+    pub fn accepts(&self, unit: &str) -> bool {
+        !unit.eq_ignore_ascii_case("none")
+            && self.units().any(|known| known.eq_ignore_ascii_case(unit))
+    }
+
+    /// Returns `true` if the server accepts byte ranges.
+    pub fn accepts_bytes(&self) -> bool {
+        self.accepts("bytes")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AcceptRanges<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut units = split_list(slice).map(trim).filter(|unit| !unit.is_empty());
+        let mut count = 0;
+        let valid = units.all(|unit| {
+            count += 1;
+            is_token(unit)
+        });
+        (valid && count > 0).then_some(AcceptRanges(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("bytes", Some((vec!["bytes"], true)))]
+    #[case("Bytes, pages", Some((vec!["Bytes", "pages"], true)))]
+    #[case("none", Some((vec!["none"], false)))]
+    #[case("", None)]
+    #[case("by tes", None)]
+    fn accept_ranges_test(#[case] input: &str, #[case] expected: Option<(Vec<&str>, bool)>) {
+        let parsed = AcceptRanges::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|ranges| (ranges.units().collect(), ranges.accepts_bytes()))
+        );
+    }
+
+    #[test]
+    fn accept_ranges_none() {
+        let ranges = AcceptRanges::parse_header_value("none").unwrap();
+        assert!(!ranges.accepts("none"));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::FromHeaderValue;
+use core::time::Duration;
+
+/// The `Age` type is the value of an `Age` header, how long ago a cached
+/// response was generated or validated at the origin.
+///
+/// As the specification requires, a value too large to represent is read
+/// as 2147483648 seconds, the largest age a cache has to track.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Age(pub Duration);
+
+impl Age {
+    /// The age overflowing values are capped to.
+    pub const MAX: Age = Age(Duration::from_secs(1 << 31));
+}
+
+impl<'de> FromHeaderValue<'de> for Age {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let age = match parse_digits(value) {
+            Some(seconds) => Age(Duration::from_secs(seconds)),
+            None => Age::MAX,
+        };
+        Some(age.min(Age::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0", Some(0))]
+    #[case(" 3600 ", Some(3600))]
+    #[case("2147483649", Some(1 << 31))]
+    #[case("99999999999999999999999", Some(1 << 31))]
+    #[case("-1", None)]
+    #[case("1.5", None)]
+    #[case("", None)]
+    fn age_test(#[case] input: &str, #[case] expected: Option<u64>) {
+        let parsed = Age::parse_header_value(input);
+        assert_eq!(expected.map(Duration::from_secs), parsed.map(|age| age.0));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, Method};
+
+/// The `Allow` type is the value of an `Allow` header, the methods a
+/// resource supports such as `GET, HEAD, PUT`. It also parses
+/// `Access-Control-Allow-Methods`, where `*` is a wildcard.
+///
+/// An empty value is valid and means the resource allows no methods.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Allow<'a>(&'a str);
+
+impl<'a> Allow<'a> {
+    /// Returns an iterator over the methods, in order.
+    pub fn methods(&self) -> impl Iterator<Item = Method<'a>> {
+        split_list(self.0)
+            .filter(|method| !trim(method).is_empty())
+            .filter_map(Method::parse_header_value)
+    }
+
+    /// Returns `true` if `method` is listed.
+    pub fn contains(&self, method: Method<'_>) -> bool {
+        self.methods().any(|allowed| allowed == method)
+    }
+
+    /// Returns `true` if the list is the `*` wildcard of
+    /// `Access-Control-Allow-Methods`.
+    pub fn is_wildcard(&self) -> bool {
+        trim(self.0) == "*"
+    }
+
+    /// Returns `true` if the resource allows no methods.
+    pub fn is_empty(&self) -> bool {
+        self.methods().next().is_none()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Allow<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        split_list(slice)
+            .filter(|method| !trim(method).is_empty())
+            .all(|method| Method::parse_header_value(method).is_some())
+            .then_some(Allow(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("GET, HEAD, PUT", Some(vec![Method::Get, Method::Head, Method::Put]))]
+    #[case("OPTIONS,,PROPFIND", Some(vec![Method::Options, Method::Extension("PROPFIND")]))]
+    #[case("", Some(vec![]))]
+    #[case("GET, HE AD", None)]
+    fn allow_test(#[case] input: &str, #[case] expected: Option<Vec<Method>>) {
+        let parsed = Allow::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|allow| allow.methods().collect()));
+    }
+
+    #[test]
+    fn allow_contains() {
+        let allow = Allow::parse_header_value("GET, HEAD, MKCOL").unwrap();
+        assert!(allow.contains(Method::Head));
+        assert!(allow.contains(Method::Extension("MKCOL")));
+        assert!(!allow.contains(Method::Post));
+        assert!(!allow.is_wildcard());
+        assert!(Allow::parse_header_value(" * ").unwrap().is_wildcard());
+        assert!(Allow::parse_header_value("").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use super::split_param;
+use crate::from_header_value::{parse_digits, split_list, split_params, trim};
+use crate::FromHeaderValue;
+use core::time::Duration;
+
+/// The `AltService` type is an entry of an `Alt-Svc` header, such as
+/// `h3=":443"; ma=86400; persist=1`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AltService<'a> {
+    protocol_id: &'a str,
+    host: &'a str,
+    port: u16,
+    max_age: Option<Duration>,
+    persist: bool,
+}
+
+impl<'a> AltService<'a> {
+    /// Returns the ALPN protocol id, such as `h3` or `h2`, as it appeared.
+    pub fn protocol_id(&self) -> &'a str {
+        self.protocol_id
+    }
+
+    /// Returns the host of the alternative, `None` if it is the origin's
+    /// own host.
+    pub fn host(&self) -> Option<&'a str> {
+        (!self.host.is_empty()).then_some(self.host)
+    }
+
+    /// Returns the port of the alternative.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the `ma` parameter, how long the alternative may be used
+    /// for, or `None` if it was absent, in which case RFC 7838 defaults to
+    /// 24 hours.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Returns `true` if the alternative survives network changes, given by
+    /// `persist=1`.
+    pub fn persist(&self) -> bool {
+        self.persist
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AltService<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut params = split_params(slice);
+        let alternative = params.next()?;
+        let (protocol_id, _) = alternative.split_once('=')?;
+        let (_, authority) = split_param(alternative)?;
+        if !trim(alternative).ends_with('"') {
+            return None;
+        }
+        let (host, port) = authority.rsplit_once(':')?;
+        let mut service = AltService {
+            protocol_id: trim(protocol_id),
+            host,
+            port: parse_digits(port)?.try_into().ok()?,
+            max_age: None,
+            persist: false,
+        };
+        for param in params.filter(|param| !trim(param).is_empty()) {
+            let (name, value) = split_param(param)?;
+            if name.eq_ignore_ascii_case("ma") {
+                service.max_age = Some(Duration::from_secs(parse_digits(value)?));
+            } else if name.eq_ignore_ascii_case("persist") {
+                service.persist = value == "1";
+            }
+        }
+        Some(service)
+    }
+}
+
+/// The `AltSvc` type is the value of an `Alt-Svc` header, either `clear`,
+/// invalidating every alternative of the origin, or a list of alternative
+/// services in order of preference.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AltSvc<'a>(Option<&'a str>);
+
+impl<'a> AltSvc<'a> {
+    /// Returns `true` for the `clear` form.
+    pub fn is_clear(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns an iterator over the alternatives, which is empty for the
+    /// `clear` form.
+    pub fn services(&self) -> impl Iterator<Item = AltService<'a>> {
+        split_list(self.0.unwrap_or(""))
+            .filter(|service| !trim(service).is_empty())
+            .filter_map(AltService::parse_header_value)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AltSvc<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        if trim(slice) == "clear" {
+            return Some(AltSvc(None));
+        }
+        let mut services = split_list(slice).filter(|service| !trim(service).is_empty());
+        let mut count = 0;
+        let valid = services.all(|service| {
+            count += 1;
+            AltService::parse_header_value(service).is_some()
+        });
+        (valid && count > 0).then_some(AltSvc(Some(slice)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Service<'a> = (&'a str, Option<&'a str>, u16, Option<u64>, bool);
+
+    #[rstest]
+    #[case("h3=\":443\"; ma=86400; persist=1", Some(("h3", None, 443, Some(86400), true)))]
+    #[case("h2=\"alt.example.com:8000\"", Some(("h2", Some("alt.example.com"), 8000, None, false)))]
+    #[case("h3-29=\"[2001:db8::1]:443\";persist=0;x=\"y\"", Some(("h3-29", Some("[2001:db8::1]"), 443, None, false)))]
+    #[case("h3=:443", None)]
+    #[case("h3=\"443\"", None)]
+    #[case("h3=\":99999\"", None)]
+    #[case("h3=\":443\"; ma=soon", None)]
+    #[case("=\":443\"", None)]
+    fn alt_service_test(#[case] input: &str, #[case] expected: Option<Service>) {
+        let parsed = AltService::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|s| (
+                s.protocol_id(),
+                s.host(),
+                s.port(),
+                s.max_age().map(|ma| ma.as_secs()),
+                s.persist()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("clear", Some(true), vec![])]
+    #[case("h3=\":443\", h2=\":443\"; ma=60", Some(false), vec!["h3", "h2"])]
+    #[case("", None, vec![])]
+    #[case("h3=\":443\", clear", None, vec![])]
+    fn alt_svc_test(
+        #[case] input: &str,
+        #[case] clear: Option<bool>,
+        #[case] protocols: Vec<&str>,
+    ) {
+        let parsed = AltSvc::parse_header_value(input);
+        assert_eq!(clear, parsed.map(|alt_svc| alt_svc.is_clear()));
+        let parsed_protocols: Vec<_> = parsed
+            .iter()
+            .flat_map(|alt_svc| alt_svc.services())
+            .map(|service| service.protocol_id())
+            .collect();
+        assert_eq!(protocols, parsed_protocols);
+    }
+}
@@ -0,0 +1,231 @@
+use super::{split_param, Params};
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::FromHeaderValue;
+#[cfg(all(feature = "base64", feature = "alloc"))]
+use alloc::string::String;
+use core::fmt;
+
+/// The `AuthParams` type is a comma separated list of `name=value`
+/// authentication parameters, such as the credentials of the `Digest`
+/// scheme, borrowed from the header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AuthParams<'a>(pub(super) &'a str);
+
+impl<'a> AuthParams<'a> {
+    /// Returns an iterator over the parameters, with quoted values returned
+    /// without their DQUOTEs.
+    pub fn iter(&self) -> Params<'a> {
+        Params(split_list(self.0))
+    }
+
+    /// Returns the value of the parameter `name`, compared
+    /// case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.iter()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the parameters as they were sent.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    pub(crate) fn parse(params: &'a str) -> Option<Self> {
+        split_list(params)
+            .all(|param| trim(param).is_empty() || split_param(param).is_some())
+            .then_some(AuthParams(params))
+    }
+}
+
+pub(crate) fn is_token68(value: &str) -> bool {
+    let value = value.trim_end_matches('=');
+    !value.is_empty()
+        && value.bytes().all(|b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~' | b'+' | b'/')
+        })
+}
+
+/// Splits `Scheme rest` into the scheme and the trimmed rest.
+pub(crate) fn split_scheme(value: &str) -> Option<(&str, &str)> {
+    let value = trim(value);
+    // an empty rest still points into `value`, so offsets can be taken
+    let (scheme, rest) = value.split_once(' ').unwrap_or(value.split_at(value.len()));
+    is_token(scheme).then(|| (scheme, trim(rest)))
+}
+
+/// The `Authorization` type is the value of an `Authorization` header, the
+/// credentials of a request. Schemes are matched case-insensitively.
+///
+/// `Basic` credentials are only decoded with the `base64` and `alloc`
+/// features, without them they are kept as [`Other`](Authorization::Other).
+///
+/// The `Debug` output redacts the credentials, so only the scheme and the
+/// `Basic` user-id end up in logs.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Authorization<'a> {
+    /// The `Basic` scheme, with the user-id and password decoded.
+    #[cfg(all(feature = "base64", feature = "alloc"))]
+    Basic { user: String, pass: String },
+    /// The `Bearer` scheme of RFC 6750, with its token.
+    Bearer(&'a str),
+    /// The `Digest` scheme of RFC 7616, with its parameters.
+    Digest(AuthParams<'a>),
+    /// Any other scheme, with its token68 or parameters as they were sent.
+    Other { scheme: &'a str, params: &'a str },
+}
+
+impl<'a> Authorization<'a> {
+    /// Returns the authentication scheme, spelled the canonical way for
+    /// the known schemes.
+    pub fn scheme(&self) -> &'a str {
+        match self {
+            #[cfg(all(feature = "base64", feature = "alloc"))]
+            Authorization::Basic { .. } => "Basic",
+            Authorization::Bearer(_) => "Bearer",
+            Authorization::Digest(_) => "Digest",
+            Authorization::Other { scheme, .. } => scheme,
+        }
+    }
+}
+
+/// Stands in for a credential in the `Debug` output of [`Authorization`].
+struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<'a> fmt::Debug for Authorization<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(all(feature = "base64", feature = "alloc"))]
+            Authorization::Basic { user, .. } => f
+                .debug_struct("Basic")
+                .field("user", user)
+                .field("pass", &Redacted)
+                .finish(),
+            Authorization::Bearer(_) => f.debug_tuple("Bearer").field(&Redacted).finish(),
+            Authorization::Digest(_) => f.debug_tuple("Digest").field(&Redacted).finish(),
+            Authorization::Other { scheme, .. } => f
+                .debug_struct("Other")
+                .field("scheme", scheme)
+                .field("params", &Redacted)
+                .finish(),
+        }
+    }
+}
+
+#[cfg(all(feature = "base64", feature = "alloc"))]
+fn decode_basic(token: &str) -> Option<(String, String)> {
+    let decoded = crate::Base64Bytes::parse_header_value(token)?;
+    let decoded = String::from_utf8(decoded.0).ok()?;
+    let (user, pass) = decoded.split_once(':')?;
+    Some((user.into(), pass.into()))
+}
+
+impl<'de> FromHeaderValue<'de> for Authorization<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (scheme, params) = split_scheme(slice)?;
+        #[cfg(all(feature = "base64", feature = "alloc"))]
+        if scheme.eq_ignore_ascii_case("Basic") {
+            if !is_token68(params) {
+                return None;
+            }
+            let (user, pass) = decode_basic(params)?;
+            return Some(Authorization::Basic { user, pass });
+        }
+        if scheme.eq_ignore_ascii_case("Bearer") {
+            return is_token68(params).then_some(Authorization::Bearer(params));
+        }
+        if scheme.eq_ignore_ascii_case("Digest") {
+            return AuthParams::parse(params).map(Authorization::Digest);
+        }
+        if !params.is_empty() && !is_token68(params) && AuthParams::parse(params).is_none() {
+            return None;
+        }
+        Some(Authorization::Other { scheme, params })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "Bearer mF_9.B5f-4.1JqM",
+        Some(Authorization::Bearer("mF_9.B5f-4.1JqM"))
+    )]
+    #[case("bearer abc==", Some(Authorization::Bearer("abc==")))]
+    #[case("Bearer", None)]
+    #[case("Bearer a b", None)]
+    #[case(
+        "Negotiate YIIB==",
+        Some(Authorization::Other { scheme: "Negotiate", params: "YIIB==" })
+    )]
+    #[case("HOBA", Some(Authorization::Other { scheme: "HOBA", params: "" }))]
+    #[case("Custom a b", None)]
+    #[case("", None)]
+    fn authorization_test(#[case] input: &str, #[case] expected: Option<Authorization>) {
+        assert_eq!(expected, Authorization::parse_header_value(input));
+    }
+
+    #[test]
+    fn authorization_digest() {
+        let parsed = Authorization::parse_header_value(
+            r#"Digest username="Mufasa", realm="http-auth@example.org", nc=00000001"#,
+        )
+        .unwrap();
+        let Authorization::Digest(params) = parsed else {
+            panic!("expected digest credentials");
+        };
+        assert_eq!(params.get("USERNAME"), Some("Mufasa"));
+        assert_eq!(params.get("realm"), Some("http-auth@example.org"));
+        assert_eq!(params.get("nc"), Some("00000001"));
+        assert_eq!(params.iter().count(), 3);
+        assert_eq!(parsed.scheme(), "Digest");
+    }
+
+    #[rstest]
+    #[case("Bearer mF_9.B5f-4.1JqM", "Bearer(<redacted>)")]
+    #[case(
+        r#"Digest username="Mufasa", response="6629fae4""#,
+        "Digest(<redacted>)"
+    )]
+    #[case(
+        "Negotiate YIIB==",
+        r#"Other { scheme: "Negotiate", params: <redacted> }"#
+    )]
+    #[cfg_attr(
+        feature = "base64",
+        case(
+            "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==",
+            r#"Basic { user: "Aladdin", pass: <redacted> }"#
+        )
+    )]
+    fn authorization_debug_redacts_credentials(#[case] input: &str, #[case] expected: &str) {
+        let parsed = Authorization::parse_header_value(input).unwrap();
+        assert_eq!(expected, format!("{parsed:?}"));
+    }
+
+    #[cfg(feature = "base64")]
+    #[rstest]
+    #[case("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==", Some(("Aladdin", "open sesame")))]
+    #[case("BASIC dXNlcjo=", Some(("user", "")))]
+    #[case("Basic dXNlcg==", None)]
+    #[case("Basic !!", None)]
+    fn authorization_basic(#[case] input: &str, #[case] expected: Option<(&str, &str)>) {
+        let parsed = Authorization::parse_header_value(input);
+        let credentials = match &parsed {
+            Some(Authorization::Basic { user, pass }) => Some((user.as_str(), pass.as_str())),
+            _ => None,
+        };
+        assert_eq!(expected, credentials);
+    }
+}
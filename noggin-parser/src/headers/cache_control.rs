@@ -0,0 +1,282 @@
+use super::{split_directive, Age};
+use crate::from_header_value::{parse_digits, split_list, trim};
+use crate::FromHeaderValue;
+use core::time::Duration;
+
+/// The `CacheControl` type is the value of a `Cache-Control` header, such
+/// as `public, max-age=3600, stale-while-revalidate=60`.
+///
+/// The directives of RFC 9111 and RFC 5861 as well as `immutable` are
+/// parsed when the header is, directive names are case-insensitive and the
+/// first occurrence of a directive wins. Any other directive is available
+/// from [`extensions`](CacheControl::extensions).
+///
+/// Like [`Age`], delta-seconds larger than 2147483648 are read as
+/// 2147483648, as RFC 9111 requires.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CacheControl<'a> {
+    raw: &'a str,
+    max_age: Option<Duration>,
+    s_maxage: Option<Duration>,
+    min_fresh: Option<Duration>,
+    stale_while_revalidate: Option<Duration>,
+    stale_if_error: Option<Duration>,
+    no_cache: Option<&'a str>,
+    private: Option<&'a str>,
+    no_store: bool,
+    public: bool,
+    must_revalidate: bool,
+    proxy_revalidate: bool,
+    must_understand: bool,
+    no_transform: bool,
+    only_if_cached: bool,
+    immutable: bool,
+}
+
+const KNOWN: [&str; 15] = [
+    "max-age",
+    "s-maxage",
+    "min-fresh",
+    "stale-while-revalidate",
+    "stale-if-error",
+    "no-cache",
+    "private",
+    "no-store",
+    "public",
+    "must-revalidate",
+    "proxy-revalidate",
+    "must-understand",
+    "no-transform",
+    "only-if-cached",
+    "immutable",
+];
+
+impl<'a> CacheControl<'a> {
+    /// Returns the `max-age` directive.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Returns the `s-maxage` directive.
+    pub fn s_maxage(&self) -> Option<Duration> {
+        self.s_maxage
+    }
+
+    /// Returns the `min-fresh` directive.
+    pub fn min_fresh(&self) -> Option<Duration> {
+        self.min_fresh
+    }
+
+    /// Returns the `stale-while-revalidate` directive.
+    pub fn stale_while_revalidate(&self) -> Option<Duration> {
+        self.stale_while_revalidate
+    }
+
+    /// Returns the `stale-if-error` directive.
+    pub fn stale_if_error(&self) -> Option<Duration> {
+        self.stale_if_error
+    }
+
+    /// Returns `true` if the `no-cache` directive is present, with or
+    /// without field names.
+    pub fn no_cache(&self) -> bool {
+        self.no_cache.is_some()
+    }
+
+    /// Returns the field names of a qualified `no-cache="..."` directive.
+    pub fn no_cache_fields(&self) -> impl Iterator<Item = &'a str> {
+        field_names(self.no_cache)
+    }
+
+    /// Returns `true` if the `private` directive is present, with or
+    /// without field names.
+    pub fn private(&self) -> bool {
+        self.private.is_some()
+    }
+
+    /// Returns the field names of a qualified `private="..."` directive.
+    pub fn private_fields(&self) -> impl Iterator<Item = &'a str> {
+        field_names(self.private)
+    }
+
+    /// Returns `true` if the `no-store` directive is present.
+    pub fn no_store(&self) -> bool {
+        self.no_store
+    }
+
+    /// Returns `true` if the `public` directive is present.
+    pub fn public(&self) -> bool {
+        self.public
+    }
+
+    /// Returns `true` if the `must-revalidate` directive is present.
+    pub fn must_revalidate(&self) -> bool {
+        self.must_revalidate
+    }
+
+    /// Returns `true` if the `proxy-revalidate` directive is present.
+    pub fn proxy_revalidate(&self) -> bool {
+        self.proxy_revalidate
+    }
+
+    /// Returns `true` if the `must-understand` directive is present.
+    pub fn must_understand(&self) -> bool {
+        self.must_understand
+    }
+
+    /// Returns `true` if the `no-transform` directive is present.
+    pub fn no_transform(&self) -> bool {
+        self.no_transform
+    }
+
+    /// Returns `true` if the `only-if-cached` directive is present.
+    pub fn only_if_cached(&self) -> bool {
+        self.only_if_cached
+    }
+
+    /// Returns `true` if the `immutable` directive is present.
+    pub fn immutable(&self) -> bool {
+        self.immutable
+    }
+
+    /// Returns an iterator over the directives that aren't otherwise
+    /// exposed, such as `max-stale` or `community="UCI"`, with their
+    /// unquoted argument.
+    pub fn extensions(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> {
+        directives(self.raw)
+            .filter(|(name, _)| !KNOWN.iter().any(|known| known.eq_ignore_ascii_case(name)))
+    }
+}
+
+fn directives(raw: &str) -> impl Iterator<Item = (&str, Option<&str>)> {
+    split_list(raw)
+        .filter(|directive| !trim(directive).is_empty())
+        .filter_map(split_directive)
+}
+
+fn field_names(fields: Option<&str>) -> impl Iterator<Item = &str> {
+    split_list(fields.unwrap_or(""))
+        .map(trim)
+        .filter(|field| !field.is_empty())
+}
+
+/// Sets a delta-seconds directive unless it was already set, capping values
+/// too large to represent.
+fn set_seconds(slot: &mut Option<Duration>, value: Option<&str>) -> Option<()> {
+    let value = value?;
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let seconds = parse_digits(value).map_or(Age::MAX.0, Duration::from_secs);
+    slot.get_or_insert(seconds.min(Age::MAX.0));
+    Some(())
+}
+
+impl<'de> FromHeaderValue<'de> for CacheControl<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut cc = CacheControl {
+            raw: slice,
+            ..CacheControl::default()
+        };
+        for directive in split_list(slice).filter(|directive| !trim(directive).is_empty()) {
+            let (name, value) = split_directive(directive)?;
+            let name = KNOWN
+                .into_iter()
+                .find(|known| known.eq_ignore_ascii_case(name))
+                .unwrap_or("");
+            match name {
+                "max-age" => set_seconds(&mut cc.max_age, value)?,
+                "s-maxage" => set_seconds(&mut cc.s_maxage, value)?,
+                "min-fresh" => set_seconds(&mut cc.min_fresh, value)?,
+                "stale-while-revalidate" => set_seconds(&mut cc.stale_while_revalidate, value)?,
+                "stale-if-error" => set_seconds(&mut cc.stale_if_error, value)?,
+                "no-cache" => _ = cc.no_cache.get_or_insert(value.unwrap_or("")),
+                "private" => _ = cc.private.get_or_insert(value.unwrap_or("")),
+                "no-store" => cc.no_store = true,
+                "public" => cc.public = true,
+                "must-revalidate" => cc.must_revalidate = true,
+                "proxy-revalidate" => cc.proxy_revalidate = true,
+                "must-understand" => cc.must_understand = true,
+                "no-transform" => cc.no_transform = true,
+                "only-if-cached" => cc.only_if_cached = true,
+                "immutable" => cc.immutable = true,
+                _ => {}
+            }
+        }
+        Some(cc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn cache_control_response() {
+        let parsed = CacheControl::parse_header_value(
+            "public, Max-Age=3600, s-maxage=\"60\", stale-while-revalidate=30, immutable",
+        )
+        .unwrap();
+        assert!(parsed.public());
+        assert!(!parsed.private());
+        assert!(parsed.immutable());
+        assert_eq!(parsed.max_age(), Some(Duration::from_secs(3600)));
+        assert_eq!(parsed.s_maxage(), Some(Duration::from_secs(60)));
+        assert_eq!(
+            parsed.stale_while_revalidate(),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parsed.stale_if_error(), None);
+        assert_eq!(parsed.extensions().count(), 0);
+    }
+
+    #[test]
+    fn cache_control_field_names() {
+        let parsed = CacheControl::parse_header_value(
+            r#"no-cache="Set-Cookie, Authorization", private, no-store"#,
+        )
+        .unwrap();
+        assert!(parsed.no_cache());
+        assert_eq!(
+            parsed.no_cache_fields().collect::<Vec<_>>(),
+            ["Set-Cookie", "Authorization"]
+        );
+        assert!(parsed.private());
+        assert_eq!(parsed.private_fields().count(), 0);
+        assert!(parsed.no_store());
+    }
+
+    #[test]
+    fn cache_control_extensions() {
+        let parsed =
+            CacheControl::parse_header_value(r#"max-stale, community="UCI, x", max-age=5"#)
+                .unwrap();
+        assert_eq!(
+            parsed.extensions().collect::<Vec<_>>(),
+            [("max-stale", None), ("community", Some("UCI, x"))]
+        );
+        assert_eq!(parsed.max_age(), Some(Duration::from_secs(5)));
+    }
+
+    #[rstest]
+    #[case("max-age=60, max-age=10", Some(60))]
+    #[case("max-age=2147483649", Some(1 << 31))]
+    #[case("public, max-age=99999999999999999999999", Some(1 << 31))]
+    #[case("no-store,, ", None)]
+    #[case("", None)]
+    fn cache_control_max_age(#[case] input: &str, #[case] expected: Option<u64>) {
+        let parsed = CacheControl::parse_header_value(input).unwrap();
+        assert_eq!(expected.map(Duration::from_secs), parsed.max_age());
+    }
+
+    #[rstest]
+    #[case("max-age")]
+    #[case("max-age=-1")]
+    #[case("max-age=1.5")]
+    #[case("no cache")]
+    #[case("private=\"open")]
+    fn cache_control_invalid(#[case] input: &str) {
+        assert_eq!(None, CacheControl::parse_header_value(input));
+    }
+}
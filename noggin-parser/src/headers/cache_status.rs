@@ -0,0 +1,219 @@
+use crate::structured::{BareItem, List, ListMember, Parameters};
+use crate::FromHeaderValue;
+
+/// Why a cache forwarded a request, the `fwd` parameter of a
+/// [`CacheStatusEntry`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CacheForward<'a> {
+    /// `bypass`, the cache was configured not to handle the request
+    Bypass,
+    /// `method`, the request method isn't cacheable
+    Method,
+    /// `uri-miss`, nothing was stored for the URI
+    UriMiss,
+    /// `vary-miss`, a response was stored but its `Vary` didn't match
+    VaryMiss,
+    /// `miss`, no usable response was stored
+    Miss,
+    /// `request`, the request asked to skip the cache
+    Request,
+    /// `stale`, the stored response was stale
+    Stale,
+    /// `partial`, only part of the response was stored
+    Partial,
+    /// Any other reason
+    Other(&'a str),
+}
+
+impl<'a> CacheForward<'a> {
+    fn from_token(token: &'a str) -> Self {
+        match token {
+            "bypass" => CacheForward::Bypass,
+            "method" => CacheForward::Method,
+            "uri-miss" => CacheForward::UriMiss,
+            "vary-miss" => CacheForward::VaryMiss,
+            "miss" => CacheForward::Miss,
+            "request" => CacheForward::Request,
+            "stale" => CacheForward::Stale,
+            "partial" => CacheForward::Partial,
+            other => CacheForward::Other(other),
+        }
+    }
+}
+
+/// Returns the name of a cache or proxy, a token or a string item.
+pub(super) fn member_name(member: ListMember<'_>) -> Option<(&str, Parameters<'_>)> {
+    let item = member.as_item()?;
+    let name = match item.bare() {
+        BareItem::Token(name) | BareItem::String(name) => name,
+        _ => return None,
+    };
+    Some((name, item.params()))
+}
+
+/// Returns a parameter that is a token or a string.
+pub(super) fn text_param<'a>(params: &Parameters<'a>, key: &str) -> Option<&'a str> {
+    match params.get(key)? {
+        BareItem::Token(value) | BareItem::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// What a single cache did with a response, a member of a `Cache-Status`
+/// header such as `ExampleCache; fwd=uri-miss; stored`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CacheStatusEntry<'a> {
+    name: &'a str,
+    params: Parameters<'a>,
+}
+
+impl<'a> CacheStatusEntry<'a> {
+    /// Returns the name of the cache.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns all the parameters, including extension ones.
+    pub fn params(&self) -> Parameters<'a> {
+        self.params
+    }
+
+    /// Returns `true` if the response was served from the cache.
+    pub fn is_hit(&self) -> bool {
+        self.params.get("hit").and_then(|hit| hit.as_bool()) == Some(true)
+    }
+
+    /// Returns why the request was forwarded towards the origin.
+    pub fn fwd(&self) -> Option<CacheForward<'a>> {
+        self.params
+            .get("fwd")?
+            .as_token()
+            .map(CacheForward::from_token)
+    }
+
+    /// Returns the status of the response the next hop returned to the
+    /// forwarded request.
+    pub fn fwd_status(&self) -> Option<u16> {
+        self.params.get("fwd-status")?.as_integer()?.try_into().ok()
+    }
+
+    /// Returns the remaining freshness lifetime of the response, in seconds.
+    /// It is negative for a stale response.
+    pub fn ttl(&self) -> Option<i64> {
+        self.params.get("ttl")?.as_integer()
+    }
+
+    /// Returns `true` if the cache stored the forwarded response.
+    pub fn is_stored(&self) -> bool {
+        self.params
+            .get("stored")
+            .and_then(|stored| stored.as_bool())
+            == Some(true)
+    }
+
+    /// Returns `true` if the request was collapsed with another one.
+    pub fn is_collapsed(&self) -> bool {
+        self.params
+            .get("collapsed")
+            .and_then(|collapsed| collapsed.as_bool())
+            == Some(true)
+    }
+
+    /// Returns the implementation specific cache key, with its escapes kept.
+    pub fn key(&self) -> Option<&'a str> {
+        self.params.get("key")?.as_string()
+    }
+
+    /// Returns implementation specific details.
+    pub fn detail(&self) -> Option<&'a str> {
+        text_param(&self.params, "detail")
+    }
+}
+
+/// The `CacheStatus` type is the value of a `Cache-Status` header
+/// (RFC 9211), what each cache on the path did with a response such as
+/// `OriginCache; hit; ttl=1100, "CDN Company Here"; hit; ttl=545`.
+///
+/// Entries are ordered from the origin towards the user, so the last one
+/// was added by the cache closest to the user.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CacheStatus<'a>(List<'a>);
+
+impl<'a> CacheStatus<'a> {
+    /// Returns an iterator over the entries, from the origin side to the
+    /// user side.
+    pub fn entries(&self) -> impl Iterator<Item = CacheStatusEntry<'a>> {
+        self.0
+            .members()
+            .filter_map(member_name)
+            .map(|(name, params)| CacheStatusEntry { name, params })
+    }
+
+    /// Returns the entry of the cache called `name`.
+    pub fn get(&self, name: &str) -> Option<CacheStatusEntry<'a>> {
+        self.entries().find(|entry| entry.name == name)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for CacheStatus<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let list = List::parse_header_value(slice)?;
+        let mut count = 0;
+        let valid = list.members().all(|member| {
+            count += 1;
+            member_name(member).is_some()
+        });
+        (valid && count > 0).then_some(CacheStatus(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Entry<'a> = (&'a str, bool, Option<CacheForward<'a>>, Option<i64>, bool);
+
+    #[rstest]
+    #[case("ExampleCache; hit", Some(vec![("ExampleCache", true, None, None, false)]))]
+    #[case(
+        "OriginCache; hit; ttl=1100, \"CDN Company Here\"; hit; ttl=545",
+        Some(vec![("OriginCache", true, None, Some(1100), false), ("CDN Company Here", true, None, Some(545), false)])
+    )]
+    #[case(
+        "ExampleCache; fwd=uri-miss; stored",
+        Some(vec![("ExampleCache", false, Some(CacheForward::UriMiss), None, true)])
+    )]
+    #[case(
+        "ExampleCache; fwd=x-custom; ttl=-412; hit=?0",
+        Some(vec![("ExampleCache", false, Some(CacheForward::Other("x-custom")), Some(-412), false)])
+    )]
+    #[case("", None)]
+    #[case("42; hit", None)]
+    #[case("(a b); hit", None)]
+    fn cache_status_test(#[case] input: &str, #[case] expected: Option<Vec<Entry>>) {
+        let parsed = CacheStatus::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|status| status
+                .entries()
+                .map(|e| (e.name(), e.is_hit(), e.fwd(), e.ttl(), e.is_stored()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn cache_status_details() {
+        let status = CacheStatus::parse_header_value(
+            "Edge; fwd=stale; fwd-status=304; collapsed; key=\"/a\"; detail=\"spread\"",
+        )
+        .unwrap();
+        let entry = status.get("Edge").unwrap();
+        assert_eq!(entry.fwd(), Some(CacheForward::Stale));
+        assert_eq!(entry.fwd_status(), Some(304));
+        assert!(entry.is_collapsed());
+        assert_eq!(entry.key(), Some("/a"));
+        assert_eq!(entry.detail(), Some("spread"));
+        assert_eq!(status.get("Origin"), None);
+    }
+}
@@ -0,0 +1,217 @@
+use super::authorization::{is_token68, split_scheme, AuthParams};
+use super::{split_param, Authorization};
+use crate::from_header_value::{split_list, trim, SplitList};
+use crate::FromHeaderValue;
+use core::iter::Peekable;
+
+/// The `Challenge` type is one authentication challenge of a
+/// `WWW-Authenticate` or `Proxy-Authenticate` header, such as
+/// `Basic realm="simple"`, borrowed from the header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Challenge<'a> {
+    scheme: &'a str,
+    params: &'a str,
+}
+
+impl<'a> Challenge<'a> {
+    /// Returns the authentication scheme as it was sent.
+    pub fn scheme(&self) -> &'a str {
+        self.scheme
+    }
+
+    /// Returns `true` if the scheme is `scheme`, compared
+    /// case-insensitively.
+    pub fn is(&self, scheme: &str) -> bool {
+        self.scheme.eq_ignore_ascii_case(scheme)
+    }
+
+    /// Returns the token68 of a challenge that carries one instead of
+    /// parameters.
+    pub fn token68(&self) -> Option<&'a str> {
+        is_token68(self.params).then_some(self.params)
+    }
+
+    /// Returns the parameters of the challenge, which are empty if it
+    /// carries a token68.
+    pub fn params(&self) -> AuthParams<'a> {
+        match self.token68() {
+            Some(_) => AuthParams(""),
+            None => AuthParams(self.params),
+        }
+    }
+
+    /// Returns the `realm` parameter.
+    pub fn realm(&self) -> Option<&'a str> {
+        self.params().get("realm")
+    }
+}
+
+/// Iterates over the challenges of a header. The commas of the list
+/// separate both challenges and the parameters of a challenge, an element
+/// that is a lone parameter continues the previous challenge.
+struct Challenges<'a> {
+    raw: &'a str,
+    elements: Peekable<SplitList<'a>>,
+}
+
+impl<'a> Challenges<'a> {
+    fn new(raw: &'a str) -> Self {
+        Challenges {
+            raw,
+            elements: split_list(raw).peekable(),
+        }
+    }
+
+    fn offset(&self, slice: &str) -> usize {
+        slice.as_ptr() as usize - self.raw.as_ptr() as usize
+    }
+}
+
+impl<'a> Iterator for Challenges<'a> {
+    type Item = Option<Challenge<'a>>;
+
+    /// Yields `Some(None)` for a malformed challenge.
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.elements.find(|element| !trim(element).is_empty())?;
+        let Some((scheme, first)) = split_scheme(element) else {
+            return Some(None);
+        };
+        if split_param(element).is_some() {
+            // a parameter without a challenge
+            return Some(None);
+        }
+        let start = self.offset(first);
+        let mut end = start + first.len();
+        if first.is_empty() || split_param(first).is_some() {
+            while let Some(param) = self
+                .elements
+                .next_if(|element| trim(element).is_empty() || split_param(element).is_some())
+            {
+                let param = trim(param);
+                if !param.is_empty() {
+                    end = self.offset(param) + param.len();
+                }
+            }
+        } else if !is_token68(first) {
+            return Some(None);
+        }
+        let params = trim(self.raw[start..end].trim_start_matches(','));
+        Some(Some(Challenge { scheme, params }))
+    }
+}
+
+fn parse_challenges(slice: &str) -> Option<&str> {
+    let mut challenges = Challenges::new(slice);
+    let mut count = 0;
+    for challenge in challenges.by_ref() {
+        challenge?;
+        count += 1;
+    }
+    (count > 0).then_some(slice)
+}
+
+/// The `WwwAuthenticate` type is the value of a `WWW-Authenticate` header,
+/// the challenges a server offers with a 401 response.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct WwwAuthenticate<'a>(&'a str);
+
+impl<'a> WwwAuthenticate<'a> {
+    /// Returns an iterator over the challenges, in the order they were
+    /// sent in.
+    pub fn challenges(&self) -> impl Iterator<Item = Challenge<'a>> {
+        Challenges::new(self.0).flatten()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for WwwAuthenticate<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        parse_challenges(slice).map(WwwAuthenticate)
+    }
+}
+
+/// The `ProxyAuthenticate` type is the value of a `Proxy-Authenticate`
+/// header, the challenges a proxy offers with a 407 response.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ProxyAuthenticate<'a>(&'a str);
+
+impl<'a> ProxyAuthenticate<'a> {
+    /// Returns an iterator over the challenges, in the order they were
+    /// sent in.
+    pub fn challenges(&self) -> impl Iterator<Item = Challenge<'a>> {
+        Challenges::new(self.0).flatten()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ProxyAuthenticate<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        parse_challenges(slice).map(ProxyAuthenticate)
+    }
+}
+
+/// The `ProxyAuthorization` type is the value of a `Proxy-Authorization`
+/// header, the credentials a client presents to a proxy. They are parsed
+/// exactly like those of `Authorization`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ProxyAuthorization<'a>(pub Authorization<'a>);
+
+impl<'de> FromHeaderValue<'de> for ProxyAuthorization<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Authorization::parse_header_value(slice).map(ProxyAuthorization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(r#"Basic realm="simple""#, vec![("Basic", r#"realm="simple""#)])]
+    #[case(
+        r#"Newauth realm="apps", type=1, title="Login to \"apps\"", Basic realm="simple""#,
+        vec![("Newauth", r#"realm="apps", type=1, title="Login to \"apps\"""#), ("Basic", r#"realm="simple""#)]
+    )]
+    #[case("Negotiate, Basic", vec![("Negotiate", ""), ("Basic", "")])]
+    #[case("Negotiate YIIB==, Bearer", vec![("Negotiate", "YIIB=="), ("Bearer", "")])]
+    #[case(r#"Bearer realm="a", , error="invalid_token""#, vec![("Bearer", r#"realm="a", , error="invalid_token""#)])]
+    fn challenges_test(#[case] input: &str, #[case] expected: Vec<(&str, &str)>) {
+        let parsed = WwwAuthenticate::parse_header_value(input).unwrap();
+        let challenges: Vec<_> = parsed
+            .challenges()
+            .map(|challenge| (challenge.scheme(), challenge.params))
+            .collect();
+        assert_eq!(expected, challenges);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(r#"realm="a""#)]
+    #[case("Basic a b")]
+    #[case(r#"Basic realm="a" x"#)]
+    fn challenges_invalid(#[case] input: &str) {
+        assert_eq!(None, WwwAuthenticate::parse_header_value(input));
+        assert_eq!(None, ProxyAuthenticate::parse_header_value(input));
+    }
+
+    #[test]
+    fn challenge_params() {
+        let parsed = ProxyAuthenticate::parse_header_value(
+            r#"Negotiate YIIB==, Basic realm="proxy", charset="UTF-8""#,
+        )
+        .unwrap();
+        let challenges: Vec<_> = parsed.challenges().collect();
+        assert_eq!(challenges[0].token68(), Some("YIIB=="));
+        assert_eq!(challenges[0].params().iter().count(), 0);
+        assert!(challenges[1].is("basic"));
+        assert_eq!(challenges[1].token68(), None);
+        assert_eq!(challenges[1].realm(), Some("proxy"));
+        assert_eq!(challenges[1].params().get("charset"), Some("UTF-8"));
+    }
+
+    #[test]
+    fn proxy_authorization() {
+        let parsed = ProxyAuthorization::parse_header_value("Bearer abc").unwrap();
+        assert_eq!(parsed, ProxyAuthorization(Authorization::Bearer("abc")));
+        assert_eq!(None, ProxyAuthorization::parse_header_value("Bearer"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::from_header_value::{split_list, trim};
+use crate::FromHeaderValue;
+
+/// A type of data cleared by a `Clear-Site-Data` header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SiteData {
+    /// `"cache"`
+    Cache,
+    /// `"cookies"`
+    Cookies,
+    /// `"storage"`, such as local storage and IndexedDB
+    Storage,
+    /// `"executionContexts"`, reloading the documents of the origin
+    ExecutionContexts,
+}
+
+impl SiteData {
+    const ALL: [SiteData; 4] = [
+        SiteData::Cache,
+        SiteData::Cookies,
+        SiteData::Storage,
+        SiteData::ExecutionContexts,
+    ];
+
+    /// Returns the name of the type, without quotes.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SiteData::Cache => "cache",
+            SiteData::Cookies => "cookies",
+            SiteData::Storage => "storage",
+            SiteData::ExecutionContexts => "executionContexts",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// The `ClearSiteData` type is the value of a `Clear-Site-Data` header, the
+/// set of data types to clear such as `"cache", "cookies"`.
+///
+/// Every type has to be a quoted string, unquoted values are rejected.
+/// Unknown types are ignored so newer ones don't invalidate the header,
+/// and `"*"` clears every type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct ClearSiteData {
+    bits: u8,
+}
+
+impl ClearSiteData {
+    /// Returns `true` if `data` is to be cleared.
+    pub fn contains(&self, data: SiteData) -> bool {
+        self.bits & data.bit() != 0
+    }
+
+    /// Returns `true` if every known type is to be cleared.
+    pub fn is_all(&self) -> bool {
+        SiteData::ALL.iter().all(|data| self.contains(*data))
+    }
+
+    /// Returns an iterator over the types to clear.
+    pub fn iter(&self) -> impl Iterator<Item = SiteData> + '_ {
+        SiteData::ALL
+            .into_iter()
+            .filter(|data| self.contains(*data))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ClearSiteData {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut clear = ClearSiteData::default();
+        for value in split_list(slice)
+            .map(trim)
+            .filter(|value| !value.is_empty())
+        {
+            let name = value.strip_prefix('"')?.strip_suffix('"')?;
+            if name == "*" {
+                clear.bits = SiteData::ALL.iter().fold(0, |bits, data| bits | data.bit());
+            } else if let Some(data) = SiteData::ALL.into_iter().find(|data| data.as_str() == name)
+            {
+                clear.bits |= data.bit();
+            }
+        }
+        Some(clear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use SiteData::*;
+
+    #[rstest]
+    #[case("\"cache\"", Some(vec![Cache]))]
+    #[case("\"cookies\", \"storage\", \"cache\"", Some(vec![Cache, Cookies, Storage]))]
+    #[case("\"executionContexts\"", Some(vec![ExecutionContexts]))]
+    #[case("\"*\"", Some(vec![Cache, Cookies, Storage, ExecutionContexts]))]
+    #[case("\"cookies\", \"clientHints\"", Some(vec![Cookies]))]
+    #[case("\"Cache\"", Some(vec![]))]
+    #[case("", Some(vec![]))]
+    #[case("cache", None)]
+    #[case("\"cache\", cookies", None)]
+    #[case("*", None)]
+    #[case("\"cache", None)]
+    fn clear_site_data_test(#[case] input: &str, #[case] expected: Option<Vec<SiteData>>) {
+        let parsed = ClearSiteData::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|clear| clear.iter().collect()));
+    }
+
+    #[test]
+    fn clear_site_data_all() {
+        assert!(ClearSiteData::parse_header_value("\"*\"").unwrap().is_all());
+        let clear = ClearSiteData::parse_header_value("\"cache\", \"cookies\"").unwrap();
+        assert!(!clear.is_all());
+        assert!(clear.contains(Cookies));
+        assert!(!clear.contains(Storage));
+    }
+}
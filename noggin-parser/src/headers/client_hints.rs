@@ -0,0 +1,225 @@
+use crate::structured::{BareItem, Item, List, ListMember};
+use crate::FromHeaderValue;
+
+/// A brand of a `Sec-CH-UA` header, such as `"Chromium";v="124"`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Brand<'a> {
+    name: &'a str,
+    version: &'a str,
+}
+
+impl<'a> Brand<'a> {
+    /// Returns the name of the brand, with its escapes kept.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the `v` parameter, the significant or full version of the
+    /// brand.
+    pub fn version(&self) -> &'a str {
+        self.version
+    }
+
+    /// Returns `true` for the made up brands, such as `Not_A Brand`, that
+    /// browsers add so servers don't rely on an exact list.
+    pub fn is_grease(&self) -> bool {
+        let name = self.name.trim_start();
+        name.starts_with("Not") && name.ends_with("Brand")
+    }
+
+    fn from_member(member: ListMember<'a>) -> Option<Self> {
+        let item = member.as_item()?;
+        Some(Brand {
+            name: item.bare().as_string()?,
+            version: item.params().get("v")?.as_string()?,
+        })
+    }
+}
+
+/// The `SecChUa` type is the value of a `Sec-CH-UA` or
+/// `Sec-CH-UA-Full-Version-List` header, the brands of the user agent such
+/// as `"Chromium";v="124", "Google Chrome";v="124", "Not-A.Brand";v="99"`.
+///
+/// Every member must be a string with a string `v` parameter.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SecChUa<'a>(List<'a>);
+
+impl<'a> SecChUa<'a> {
+    /// Returns an iterator over the brands, in order.
+    pub fn brands(&self) -> impl Iterator<Item = Brand<'a>> {
+        self.0.members().filter_map(Brand::from_member)
+    }
+
+    /// Returns the version of the brand called `name`.
+    pub fn version_of(&self, name: &str) -> Option<&'a str> {
+        self.brands()
+            .find(|brand| brand.name == name)
+            .map(|brand| brand.version)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SecChUa<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let list = List::parse_header_value(slice)?;
+        let valid = list
+            .members()
+            .all(|member| Brand::from_member(member).is_some());
+        valid.then_some(SecChUa(list))
+    }
+}
+
+/// The `SecChUaMobile` type is the value of a `Sec-CH-UA-Mobile` header,
+/// `?1` if the user agent prefers a mobile experience.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SecChUaMobile(pub bool);
+
+impl<'de> FromHeaderValue<'de> for SecChUaMobile {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match Item::parse_header_value(slice)?.bare() {
+            BareItem::Boolean(mobile) => Some(SecChUaMobile(mobile)),
+            _ => None,
+        }
+    }
+}
+
+/// The `SecChUaPlatform` type is the value of a `Sec-CH-UA-Platform` header,
+/// the platform the user agent runs on such as `"macOS"` or `"Android"`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SecChUaPlatform<'a>(&'a str);
+
+impl<'a> SecChUaPlatform<'a> {
+    /// Returns the platform, with its escapes kept.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl PartialEq<str> for SecChUaPlatform<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for SecChUaPlatform<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SecChUaPlatform<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Item::parse_header_value(slice)?
+            .bare()
+            .as_string()
+            .map(SecChUaPlatform)
+    }
+}
+
+/// The `AcceptCh` type is the value of an `Accept-CH` response header, the
+/// client hints a server asks for such as `Sec-CH-UA-Model, Sec-CH-UA-Arch`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AcceptCh<'a>(List<'a>);
+
+impl<'a> AcceptCh<'a> {
+    /// Returns an iterator over the names of the requested hints, in order.
+    pub fn hints(&self) -> impl Iterator<Item = &'a str> {
+        self.0
+            .members()
+            .filter_map(|member| member.as_item()?.bare().as_token())
+    }
+
+    /// Returns `true` if the server asks for the hint called `name`,
+    /// compared case-insensitively.
+    pub fn contains(&self, name: &str) -> bool {
+        self.hints().any(|hint| hint.eq_ignore_ascii_case(name))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for AcceptCh<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let list = List::parse_header_value(slice)?;
+        let valid = list.members().all(|member| {
+            member
+                .as_item()
+                .is_some_and(|item| item.bare().as_token().is_some())
+        });
+        valid.then_some(AcceptCh(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "\"Chromium\";v=\"124\", \"Google Chrome\";v=\"124\", \"Not-A.Brand\";v=\"99\"",
+        Some(vec![("Chromium", "124", false), ("Google Chrome", "124", false), ("Not-A.Brand", "99", true)])
+    )]
+    #[case(
+        "\" Not A;Brand\";v=\"99.0.0.0\", \"Chromium\";v=\"99.0.4844.51\"",
+        Some(vec![(" Not A;Brand", "99.0.0.0", true), ("Chromium", "99.0.4844.51", false)])
+    )]
+    #[case("\"Not)A;Brand\";v=\"8\"", Some(vec![("Not)A;Brand", "8", true)]))]
+    #[case("", Some(vec![]))]
+    #[case("\"Chromium\"", None)]
+    #[case("\"Chromium\";v=124", None)]
+    #[case("Chromium;v=\"124\"", None)]
+    fn sec_ch_ua_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str, bool)>>) {
+        let parsed = SecChUa::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|ua| ua
+                .brands()
+                .map(|brand| (brand.name(), brand.version(), brand.is_grease()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn sec_ch_ua_version_of() {
+        let ua =
+            SecChUa::parse_header_value("\"Chromium\";v=\"124\", \"Microsoft Edge\";v=\"123\"")
+                .unwrap();
+        assert_eq!(ua.version_of("Microsoft Edge"), Some("123"));
+        assert_eq!(ua.version_of("Firefox"), None);
+    }
+
+    #[rstest]
+    #[case("?1", Some(true))]
+    #[case(" ?0 ", Some(false))]
+    #[case("1", None)]
+    #[case("true", None)]
+    fn sec_ch_ua_mobile_test(#[case] input: &str, #[case] expected: Option<bool>) {
+        let parsed = SecChUaMobile::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|mobile| mobile.0));
+    }
+
+    #[rstest]
+    #[case("\"macOS\"", Some("macOS"))]
+    #[case("\"Chrome OS\"", Some("Chrome OS"))]
+    #[case("\"\"", Some(""))]
+    #[case("Windows", None)]
+    fn sec_ch_ua_platform_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        let parsed = SecChUaPlatform::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|platform| platform.as_str()));
+    }
+
+    #[rstest]
+    #[case("Sec-CH-UA-Model, Sec-CH-UA-Arch", Some(vec!["Sec-CH-UA-Model", "Sec-CH-UA-Arch"]))]
+    #[case("", Some(vec![]))]
+    #[case("\"Sec-CH-UA-Model\"", None)]
+    #[case("Sec-CH-UA-Model,", None)]
+    fn accept_ch_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = AcceptCh::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|accept| accept.hints().collect()));
+    }
+
+    #[test]
+    fn accept_ch_contains() {
+        let accept = AcceptCh::parse_header_value("Sec-CH-UA-Model, DPR").unwrap();
+        assert!(accept.contains("sec-ch-ua-model"));
+        assert!(!accept.contains("Sec-CH-UA-Arch"));
+    }
+}
@@ -0,0 +1,223 @@
+use super::{EntityTag, IfMatch, IfNoneMatch};
+use crate::{FromHeaderValue, HttpDate};
+
+/// The `IfRange` type is the value of an `If-Range` header, either an
+/// entity tag or an HTTP-date.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IfRange<'a> {
+    /// The range applies if the current entity tag is strongly equal.
+    EntityTag(EntityTag<'a>),
+    /// The range applies if the last modification date is exactly equal.
+    Date(HttpDate),
+}
+
+impl<'a> IfRange<'a> {
+    /// Returns `true` if the `Range` header should be honored for the
+    /// current representation, identified by `etag` and `last_modified`.
+    /// A weak entity tag never allows the range.
+    pub fn allows_range(
+        &self,
+        etag: Option<&EntityTag<'_>>,
+        last_modified: Option<HttpDate>,
+    ) -> bool {
+        match self {
+            IfRange::EntityTag(tag) => etag.is_some_and(|etag| tag.strong_eq(etag)),
+            IfRange::Date(date) => last_modified == Some(*date),
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for IfRange<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        EntityTag::parse_header_value(slice)
+            .map(IfRange::EntityTag)
+            .or_else(|| HttpDate::parse_header_value(slice).map(IfRange::Date))
+    }
+}
+
+/// The outcome of evaluating the [`Conditions`] of a request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Precondition {
+    /// Perform the request method as usual.
+    Proceed,
+    /// Respond with `304 Not Modified`.
+    NotModified,
+    /// Respond with `412 Precondition Failed`.
+    PreconditionFailed,
+    /// Perform the request but ignore its `Range` header and send the whole
+    /// representation.
+    IgnoreRange,
+}
+
+/// The `Conditions` type gathers the conditional headers of a request so
+/// they can be evaluated together, in the order of RFC 9110 section 13.2.2.
+///
+/// The fields are usually copied from a derived header struct. Conditions
+/// are evaluated against an existing representation, a server without one
+/// should fail any `If-Match` on its own.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Conditions<'a> {
+    /// The `If-Match` header.
+    pub if_match: Option<IfMatch<'a>>,
+    /// The `If-None-Match` header.
+    pub if_none_match: Option<IfNoneMatch<'a>>,
+    /// The `If-Modified-Since` header.
+    pub if_modified_since: Option<HttpDate>,
+    /// The `If-Unmodified-Since` header.
+    pub if_unmodified_since: Option<HttpDate>,
+    /// The `If-Range` header.
+    pub if_range: Option<IfRange<'a>>,
+}
+
+impl<'a> Conditions<'a> {
+    /// Evaluates the conditions for a request with the given `method`
+    /// against the current `etag` and `last_modified` date of the target
+    /// representation.
+    ///
+    /// Date conditions are ignored when there is no `last_modified` date,
+    /// and `If-Range` is only considered for `GET`. `IgnoreRange` is only
+    /// meaningful when the request has a `Range` header.
+    pub fn evaluate(
+        &self,
+        method: &str,
+        etag: Option<&EntityTag<'_>>,
+        last_modified: Option<HttpDate>,
+    ) -> Precondition {
+        let is_get_or_head = method == "GET" || method == "HEAD";
+        if let Some(if_match) = &self.if_match {
+            if !etag.map_or(if_match.is_any(), |etag| if_match.matches(etag)) {
+                return Precondition::PreconditionFailed;
+            }
+        } else if let (Some(since), Some(modified)) = (self.if_unmodified_since, last_modified) {
+            if modified > since {
+                return Precondition::PreconditionFailed;
+            }
+        }
+        if let Some(if_none_match) = &self.if_none_match {
+            if etag.map_or(if_none_match.is_any(), |etag| if_none_match.matches(etag)) {
+                return match is_get_or_head {
+                    true => Precondition::NotModified,
+                    false => Precondition::PreconditionFailed,
+                };
+            }
+        } else if let (true, Some(since), Some(modified)) =
+            (is_get_or_head, self.if_modified_since, last_modified)
+        {
+            if modified <= since {
+                return Precondition::NotModified;
+            }
+        }
+        match self.if_range {
+            Some(if_range) if method == "GET" && !if_range.allows_range(etag, last_modified) => {
+                Precondition::IgnoreRange
+            }
+            _ => Precondition::Proceed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MODIFIED: &str = "Tue, 15 Nov 1994 08:12:31 GMT";
+    const BEFORE: &str = "Mon, 14 Nov 1994 08:12:31 GMT";
+
+    fn conditions<'a>(headers: &[(&str, &'a str)]) -> Conditions<'a> {
+        let mut conditions = Conditions::default();
+        for &(name, value) in headers {
+            match name {
+                "if-match" => conditions.if_match = IfMatch::parse_header_value(value),
+                "if-none-match" => {
+                    conditions.if_none_match = IfNoneMatch::parse_header_value(value)
+                }
+                "if-modified-since" => {
+                    conditions.if_modified_since = HttpDate::parse_header_value(value)
+                }
+                "if-unmodified-since" => {
+                    conditions.if_unmodified_since = HttpDate::parse_header_value(value)
+                }
+                "if-range" => conditions.if_range = IfRange::parse_header_value(value),
+                _ => unreachable!(),
+            }
+        }
+        conditions
+    }
+
+    #[rstest]
+    #[case("GET", &[], Precondition::Proceed)]
+    #[case("PUT", &[("if-match", r#""v1""#)], Precondition::PreconditionFailed)]
+    #[case("PUT", &[("if-match", r#""v2""#)], Precondition::Proceed)]
+    #[case("PUT", &[("if-match", r#"W/"v2""#)], Precondition::PreconditionFailed)]
+    #[case("PUT", &[("if-match", "*")], Precondition::Proceed)]
+    #[case("PUT", &[("if-unmodified-since", BEFORE)], Precondition::PreconditionFailed)]
+    #[case("PUT", &[("if-unmodified-since", MODIFIED)], Precondition::Proceed)]
+    #[case(
+        "PUT",
+        &[("if-match", r#""v2""#), ("if-unmodified-since", BEFORE)],
+        Precondition::Proceed
+    )]
+    #[case("GET", &[("if-none-match", r#"W/"v2""#)], Precondition::NotModified)]
+    #[case("HEAD", &[("if-none-match", "*")], Precondition::NotModified)]
+    #[case("POST", &[("if-none-match", "*")], Precondition::PreconditionFailed)]
+    #[case("GET", &[("if-none-match", r#""v1""#)], Precondition::Proceed)]
+    #[case("GET", &[("if-modified-since", MODIFIED)], Precondition::NotModified)]
+    #[case("GET", &[("if-modified-since", BEFORE)], Precondition::Proceed)]
+    #[case("POST", &[("if-modified-since", MODIFIED)], Precondition::Proceed)]
+    #[case(
+        "GET",
+        &[("if-none-match", r#""v1""#), ("if-modified-since", MODIFIED)],
+        Precondition::Proceed
+    )]
+    #[case("GET", &[("if-range", r#""v2""#)], Precondition::Proceed)]
+    #[case("GET", &[("if-range", r#""v1""#)], Precondition::IgnoreRange)]
+    #[case("GET", &[("if-range", r#"W/"v2""#)], Precondition::IgnoreRange)]
+    #[case("GET", &[("if-range", MODIFIED)], Precondition::Proceed)]
+    #[case("GET", &[("if-range", BEFORE)], Precondition::IgnoreRange)]
+    #[case("HEAD", &[("if-range", BEFORE)], Precondition::Proceed)]
+    fn conditions_evaluate(
+        #[case] method: &str,
+        #[case] headers: &[(&str, &str)],
+        #[case] expected: Precondition,
+    ) {
+        let etag = EntityTag::strong("v2").unwrap();
+        let last_modified = HttpDate::parse_header_value(MODIFIED);
+        let conditions = conditions(headers);
+        assert_eq!(
+            expected,
+            conditions.evaluate(method, Some(&etag), last_modified)
+        );
+    }
+
+    #[rstest]
+    #[case(r#""v2""#, true)]
+    #[case(r#""v1""#, false)]
+    #[case(r#"W/"v2""#, false)]
+    #[case(MODIFIED, true)]
+    #[case(BEFORE, false)]
+    fn if_range_allows_range(#[case] input: &str, #[case] expected: bool) {
+        let etag = EntityTag::strong("v2").unwrap();
+        let last_modified = HttpDate::parse_header_value(MODIFIED);
+        let if_range = IfRange::parse_header_value(input).unwrap();
+        assert_eq!(expected, if_range.allows_range(Some(&etag), last_modified));
+        assert!(!if_range.allows_range(None, None));
+    }
+
+    #[test]
+    fn conditions_without_validators() {
+        let conditions = conditions(&[("if-match", r#""v2""#), ("if-modified-since", MODIFIED)]);
+        assert_eq!(
+            Precondition::PreconditionFailed,
+            conditions.evaluate("GET", None, None)
+        );
+        let conditions = Conditions {
+            if_match: None,
+            ..conditions
+        };
+        assert_eq!(
+            Precondition::Proceed,
+            conditions.evaluate("GET", None, None)
+        );
+    }
+}
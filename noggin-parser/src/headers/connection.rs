@@ -0,0 +1,158 @@
+use super::split_directive;
+use crate::from_header_value::{parse_digits, split_list, trim};
+use crate::token::is_token;
+use crate::{FromHeaderValue, HttpVersion, Token};
+use core::time::Duration;
+
+/// The `Connection` type is the value of a `Connection` header, a list of
+/// connection options such as `close`, `keep-alive` or `upgrade`, borrowed
+/// from the header.
+///
+/// The default value is an empty list, standing in for an absent header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Connection<'a>(&'a str);
+
+impl<'a> Connection<'a> {
+    /// Returns an iterator over the connection options.
+    pub fn options(&self) -> impl Iterator<Item = Token<'a>> {
+        split_list(self.0)
+            .filter(|option| !trim(option).is_empty())
+            .filter_map(Token::parse_header_value)
+    }
+
+    /// Returns `true` if `option` is listed, compared case-insensitively.
+    pub fn contains(&self, option: &str) -> bool {
+        self.options().any(|listed| listed == option)
+    }
+
+    /// Returns `true` if the `close` option is listed.
+    pub fn is_close(&self) -> bool {
+        self.contains("close")
+    }
+
+    /// Returns `true` if the `keep-alive` option is listed.
+    pub fn is_keep_alive(&self) -> bool {
+        self.contains("keep-alive")
+    }
+
+    /// Returns `true` if the `upgrade` option is listed.
+    pub fn is_upgrade(&self) -> bool {
+        self.contains("upgrade")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Connection<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        split_list(slice)
+            .map(trim)
+            .all(|option| option.is_empty() || is_token(option))
+            .then_some(Connection(slice))
+    }
+}
+
+/// Returns `true` if the connection should stay open after the message,
+/// given its version and `Connection` header.
+///
+/// `close` always ends the connection. HTTP/1.1 and later connections are
+/// persistent by default, while HTTP/1.0 ones only persist with the
+/// `keep-alive` option. Pass `&Connection::default()` if the header is
+/// absent.
+pub fn should_keep_alive(version: HttpVersion, connection: &Connection<'_>) -> bool {
+    if connection.is_close() {
+        return false;
+    }
+    version >= HttpVersion::Http11 || connection.is_keep_alive()
+}
+
+/// The `KeepAlive` type is the value of a `Keep-Alive` header, such as
+/// `timeout=5, max=1000`, hinting how long an idle HTTP/1.0 connection is
+/// kept open and how many more requests it may carry.
+///
+/// Unknown parameters are ignored, the first `timeout` and `max` win.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct KeepAlive {
+    timeout: Option<Duration>,
+    max: Option<u64>,
+}
+
+impl KeepAlive {
+    /// Returns the `timeout` parameter.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the `max` parameter.
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for KeepAlive {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut keep_alive = KeepAlive::default();
+        for param in split_list(slice).filter(|param| !trim(param).is_empty()) {
+            let (name, value) = split_directive(param)?;
+            if name.eq_ignore_ascii_case("timeout") {
+                let timeout = Duration::from_secs(parse_digits(value?)?);
+                keep_alive.timeout.get_or_insert(timeout);
+            } else if name.eq_ignore_ascii_case("max") {
+                let max = parse_digits(value?)?;
+                keep_alive.max.get_or_insert(max);
+            }
+        }
+        Some(keep_alive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("keep-alive", Some(vec!["keep-alive"]))]
+    #[case("Upgrade, HTTP2-Settings", Some(vec!["Upgrade", "HTTP2-Settings"]))]
+    #[case("close,", Some(vec!["close"]))]
+    #[case("", Some(vec![]))]
+    #[case("keep alive", None)]
+    fn connection_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = Connection::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|connection| connection.options().map(|o| o.as_str()).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(HttpVersion::Http11, "", true)]
+    #[case(HttpVersion::Http11, "Close", false)]
+    #[case(HttpVersion::Http11, "keep-alive, close", false)]
+    #[case(HttpVersion::Http10, "", false)]
+    #[case(HttpVersion::Http10, "Keep-Alive", true)]
+    #[case(HttpVersion::Http2, "", true)]
+    fn should_keep_alive_test(
+        #[case] version: HttpVersion,
+        #[case] connection: &str,
+        #[case] expected: bool,
+    ) {
+        let connection = Connection::parse_header_value(connection).unwrap();
+        assert_eq!(expected, should_keep_alive(version, &connection));
+        assert!(!connection.is_upgrade());
+    }
+
+    #[rstest]
+    #[case("timeout=5, max=1000", Some((Some(5), Some(1000))))]
+    #[case("Timeout=5", Some((Some(5), None)))]
+    #[case("timeout=5, timeout=10, foo=\"bar\"", Some((Some(5), None)))]
+    #[case("", Some((None, None)))]
+    #[case("timeout", None)]
+    #[case("timeout=-1", None)]
+    #[case("max=many", None)]
+    fn keep_alive_test(#[case] input: &str, #[case] expected: Option<(Option<u64>, Option<u64>)>) {
+        let parsed = KeepAlive::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|keep_alive| (keep_alive.timeout().map(|t| t.as_secs()), keep_alive.max()))
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use super::{are_params_valid, Params};
+use crate::from_header_value::trim;
+#[cfg(feature = "alloc")]
+use crate::from_header_value::{split_params, unquote};
+use crate::token::is_token;
+#[cfg(feature = "alloc")]
+use crate::ExtValue;
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+/// The disposition type of a [`ContentDisposition`], compared
+/// case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DispositionType<'a> {
+    /// `inline`, displayed as part of the page
+    Inline,
+    /// `attachment`, downloaded
+    Attachment,
+    /// `form-data`, a part of a `multipart/form-data` body
+    FormData,
+    /// Any other disposition type
+    Other(&'a str),
+}
+
+/// The `ContentDisposition` type is the value of a `Content-Disposition`
+/// header, of a download such as `attachment; filename="report.pdf"` or of
+/// a multipart part such as `form-data; name="file"; filename="a.txt"`.
+///
+/// The parameters are borrowed from the header. With the `alloc` feature
+/// [`filename`](ContentDisposition::filename) also decodes the RFC 8187
+/// `filename*` parameter, which carries non-ASCII names.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentDisposition<'a> {
+    disposition: DispositionType<'a>,
+    params: &'a str,
+}
+
+impl<'a> ContentDisposition<'a> {
+    /// Returns the disposition type.
+    pub fn disposition(&self) -> DispositionType<'a> {
+        self.disposition
+    }
+
+    /// Returns `true` for the `attachment` disposition type.
+    pub fn is_attachment(&self) -> bool {
+        self.disposition == DispositionType::Attachment
+    }
+
+    /// Returns an iterator over the parameters.
+    pub fn params(&self) -> Params<'a> {
+        Params::new(self.params)
+    }
+
+    /// Returns the value of the parameter `name`, compared
+    /// case-insensitively, with quoted-pairs left as they are.
+    pub fn param(&self, name: &str) -> Option<&'a str> {
+        self.params()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the `name` parameter of a `form-data` part.
+    pub fn name(&self) -> Option<&'a str> {
+        self.param("name")
+    }
+
+    /// Returns the file name, preferring the decoded `filename*` parameter
+    /// over `filename` when it is present and valid.
+    #[cfg(feature = "alloc")]
+    pub fn filename(&self) -> Option<Cow<'a, str>> {
+        let extended = self
+            .raw_param("filename*")
+            .and_then(ExtValue::parse_header_value)
+            .map(|ext| Cow::Owned(ext.into_value()));
+        extended.or_else(|| unquote(self.raw_param("filename")?))
+    }
+
+    /// Returns the still quoted value of the parameter `name`.
+    #[cfg(feature = "alloc")]
+    fn raw_param(&self, name: &str) -> Option<&'a str> {
+        split_params(self.params).find_map(|param| {
+            let (param, value) = param.split_once('=')?;
+            trim(param).eq_ignore_ascii_case(name).then(|| trim(value))
+        })
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentDisposition<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (disposition, params) = match slice.split_once(';') {
+            Some((disposition, params)) => (trim(disposition), params),
+            None => (trim(slice), ""),
+        };
+        if !is_token(disposition) || !are_params_valid(params) {
+            return None;
+        }
+        let disposition = if disposition.eq_ignore_ascii_case("inline") {
+            DispositionType::Inline
+        } else if disposition.eq_ignore_ascii_case("attachment") {
+            DispositionType::Attachment
+        } else if disposition.eq_ignore_ascii_case("form-data") {
+            DispositionType::FormData
+        } else {
+            DispositionType::Other(disposition)
+        };
+        Some(ContentDisposition {
+            disposition,
+            params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("inline", Some((DispositionType::Inline, None)))]
+    #[case(
+        r#"Attachment; filename="report.pdf""#,
+        Some((DispositionType::Attachment, Some("report.pdf")))
+    )]
+    #[case(
+        r#"form-data; name="file"; filename="a.txt""#,
+        Some((DispositionType::FormData, Some("a.txt")))
+    )]
+    #[case(
+        r#"attachment; filename="EURO rates.txt"; filename*=utf-8''%e2%82%ac%20rates.txt"#,
+        Some((DispositionType::Attachment, Some("€ rates.txt")))
+    )]
+    #[case(
+        r#"attachment; filename*=unknown''x; filename="fallback.txt""#,
+        Some((DispositionType::Attachment, Some("fallback.txt")))
+    )]
+    #[case(
+        r#"attachment; filename="say \"hi\".txt""#,
+        Some((DispositionType::Attachment, Some(r#"say "hi".txt"#)))
+    )]
+    #[case("x-custom; a=b", Some((DispositionType::Other("x-custom"), None)))]
+    #[case("attachment; filename", None)]
+    #[case("attach ment", None)]
+    fn content_disposition_test(
+        #[case] input: &str,
+        #[case] expected: Option<(DispositionType, Option<&str>)>,
+    ) {
+        let parsed = ContentDisposition::parse_header_value(input);
+        let disposition = parsed.map(|cd| cd.disposition());
+        assert_eq!(expected.map(|(disposition, _)| disposition), disposition);
+        let filename = parsed.and_then(|cd| cd.filename());
+        assert_eq!(
+            expected.and_then(|(_, filename)| filename),
+            filename.as_deref()
+        );
+    }
+
+    #[test]
+    fn content_disposition_name() {
+        let parsed = ContentDisposition::parse_header_value(r#"form-data; NAME="field""#).unwrap();
+        assert_eq!(parsed.name(), Some("field"));
+        assert!(!parsed.is_attachment());
+    }
+}
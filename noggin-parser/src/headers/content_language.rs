@@ -0,0 +1,69 @@
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, LanguageTag};
+
+/// The `ContentLanguage` type is the value of a `Content-Language` header,
+/// the languages of the intended audience such as `mi, en`.
+///
+/// The tags are validated when the header is parsed and borrowed from the
+/// header on iteration. The `*` wildcard isn't a valid tag here.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentLanguage<'a>(&'a str);
+
+impl<'a> ContentLanguage<'a> {
+    /// Returns an iterator over the language tags, in the order they were
+    /// sent in.
+    pub fn tags(&self) -> impl Iterator<Item = LanguageTag<'a>> {
+        split_list(self.0)
+            .filter(|tag| !trim(tag).is_empty())
+            .filter_map(LanguageTag::parse_header_value)
+    }
+
+    /// Returns `true` if `tag` is listed, compared case-insensitively.
+    pub fn contains(&self, tag: &LanguageTag<'_>) -> bool {
+        self.tags().any(|listed| listed.matches(tag))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentLanguage<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut count = 0;
+        let valid = split_list(slice)
+            .map(trim)
+            .filter(|tag| !tag.is_empty())
+            .all(|tag| {
+                count += 1;
+                tag != "*" && LanguageTag::parse_header_value(tag).is_some()
+            });
+        (valid && count > 0).then_some(ContentLanguage(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("da", Some(vec!["da"]))]
+    #[case("mi, en", Some(vec!["mi", "en"]))]
+    #[case(" zh-Hant-TW ,, de-CH-1996", Some(vec!["zh-Hant-TW", "de-CH-1996"]))]
+    #[case("*", None)]
+    #[case("en_US", None)]
+    #[case("", None)]
+    fn content_language_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = ContentLanguage::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|language| language.tags().map(|tag| tag.as_str()).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("mi, en-US", "EN-us", true)]
+    #[case("mi, en-US", "en", false)]
+    fn content_language_contains(#[case] input: &str, #[case] tag: &str, #[case] expected: bool) {
+        let language = ContentLanguage::parse_header_value(input).unwrap();
+        let tag = LanguageTag::parse_header_value(tag).unwrap();
+        assert_eq!(expected, language.contains(&tag));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `ContentRange` type is the value of a `Content-Range` header, either
+/// a satisfied range such as `bytes 0-499/1234` or `bytes 0-499/*`, or the
+/// unsatisfied form `bytes */1234` sent with a 416 response.
+///
+/// Only the `bytes` unit is supported. Positions are inclusive. `Display`
+/// writes the header value back out.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentRange {
+    range: Option<(u64, u64)>,
+    complete_length: Option<u64>,
+}
+
+impl ContentRange {
+    /// Creates a satisfied range from `first` to `last` inclusive, returning
+    /// `None` if the range is empty or doesn't fit in `complete_length`.
+    pub fn bytes(first: u64, last: u64, complete_length: Option<u64>) -> Option<Self> {
+        let valid = first <= last && complete_length.is_none_or(|length| last < length);
+        valid.then_some(ContentRange {
+            range: Some((first, last)),
+            complete_length,
+        })
+    }
+
+    /// Creates the unsatisfied form for a representation of
+    /// `complete_length` bytes.
+    pub fn unsatisfied(complete_length: u64) -> Self {
+        ContentRange {
+            range: None,
+            complete_length: Some(complete_length),
+        }
+    }
+
+    /// Returns the first position of a satisfied range.
+    pub fn start(&self) -> Option<u64> {
+        self.range.map(|(first, _)| first)
+    }
+
+    /// Returns the last position of a satisfied range, inclusive.
+    pub fn end(&self) -> Option<u64> {
+        self.range.map(|(_, last)| last)
+    }
+
+    /// Returns the length of the whole representation, if it is known.
+    pub fn complete_length(&self) -> Option<u64> {
+        self.complete_length
+    }
+
+    /// Returns `true` for the unsatisfied `bytes */length` form.
+    pub fn is_unsatisfied(&self) -> bool {
+        self.range.is_none()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentRange {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (unit, rest) = trim(slice).split_once(' ')?;
+        if !unit.eq_ignore_ascii_case("bytes") {
+            return None;
+        }
+        let (range, length) = rest.split_once('/')?;
+        let length = match length {
+            "*" => None,
+            length => Some(parse_digits(length)?),
+        };
+        match range {
+            "*" => length.map(ContentRange::unsatisfied),
+            range => {
+                let (first, last) = range.split_once('-')?;
+                ContentRange::bytes(parse_digits(first)?, parse_digits(last)?, length)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ContentRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.range {
+            Some((first, last)) => write!(f, "bytes {first}-{last}/")?,
+            None => f.write_str("bytes */")?,
+        }
+        match self.complete_length {
+            Some(length) => write!(f, "{length}"),
+            None => f.write_str("*"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("bytes 0-499/1234", Some((Some(0), Some(499), Some(1234))))]
+    #[case(" bytes 500-999/* ", Some((Some(500), Some(999), None)))]
+    #[case("bytes */1234", Some((None, None, Some(1234))))]
+    #[case("bytes */*", None)]
+    #[case("bytes 500-400/1234", None)]
+    #[case("bytes 0-1234/1234", None)]
+    #[case("bytes 0-499", None)]
+    #[case("bytes=0-499/1234", None)]
+    #[case("items 0-4/5", None)]
+    fn content_range_test(
+        #[case] input: &str,
+        #[case] expected: Option<(Option<u64>, Option<u64>, Option<u64>)>,
+    ) {
+        let parsed = ContentRange::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|range| (range.start(), range.end(), range.complete_length()))
+        );
+    }
+
+    #[rstest]
+    #[case("bytes 0-499/1234")]
+    #[case("bytes 500-999/*")]
+    #[case("bytes */1234")]
+    fn content_range_display(#[case] input: &str) {
+        let parsed = ContentRange::parse_header_value(input).unwrap();
+        assert_eq!(parsed.to_string(), input);
+    }
+
+    #[test]
+    fn content_range_constructors() {
+        assert_eq!(
+            ContentRange::bytes(0, 9, Some(10)).unwrap().to_string(),
+            "bytes 0-9/10"
+        );
+        assert_eq!(ContentRange::bytes(0, 10, Some(10)), None);
+        assert!(ContentRange::unsatisfied(10).is_unsatisfied());
+    }
+}
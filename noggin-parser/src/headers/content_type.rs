@@ -0,0 +1,145 @@
+use super::{are_params_valid, Params};
+use crate::from_header_value::trim;
+use crate::token::is_token;
+use crate::FromHeaderValue;
+
+/// The `ContentType` type is the value of a `Content-Type` header, such as
+/// `text/html; charset=utf-8`.
+///
+/// The type, subtype and parameters are borrowed from the header. Types and
+/// parameter names are case-insensitive, use the helpers rather than
+/// comparing them directly.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentType<'a> {
+    essence: &'a str,
+    slash: usize,
+    params: &'a str,
+}
+
+impl<'a> ContentType<'a> {
+    /// Returns the type and subtype without the parameters, such as
+    /// `text/html`.
+    pub fn essence(&self) -> &'a str {
+        self.essence
+    }
+
+    /// Returns the top-level type, such as `text` in `text/html`.
+    pub fn media_type(&self) -> &'a str {
+        &self.essence[..self.slash]
+    }
+
+    /// Returns the subtype, such as `html` in `text/html`.
+    pub fn subtype(&self) -> &'a str {
+        &self.essence[self.slash + 1..]
+    }
+
+    /// Returns an iterator over the parameters.
+    pub fn params(&self) -> Params<'a> {
+        Params::new(self.params)
+    }
+
+    /// Returns the value of the parameter `name`, compared
+    /// case-insensitively.
+    pub fn param(&self, name: &str) -> Option<&'a str> {
+        self.params()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the `charset` parameter.
+    pub fn charset(&self) -> Option<&'a str> {
+        self.param("charset")
+    }
+
+    /// Returns the `boundary` parameter of a multipart body.
+    pub fn boundary(&self) -> Option<&'a str> {
+        self.param("boundary")
+    }
+
+    /// Returns `true` if the essence is `type/subtype`, compared
+    /// case-insensitively.
+    pub fn is(&self, essence: &str) -> bool {
+        self.essence.eq_ignore_ascii_case(essence)
+    }
+
+    /// Returns `true` for `application/json` and any `+json` structured
+    /// syntax suffix, such as `application/problem+json`.
+    pub fn is_json(&self) -> bool {
+        let subtype = self.subtype();
+        subtype.eq_ignore_ascii_case("json")
+            || subtype.len() > 5 && subtype[subtype.len() - 5..].eq_ignore_ascii_case("+json")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentType<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (essence, params) = match slice.split_once(';') {
+            Some((essence, params)) => (trim(essence), params),
+            None => (trim(slice), ""),
+        };
+        let (media_type, subtype) = essence.split_once('/')?;
+        if !is_token(media_type) || !is_token(subtype) || !are_params_valid(params) {
+            return None;
+        }
+        Some(ContentType {
+            essence,
+            slash: media_type.len(),
+            params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("text/html", Some(("text", "html", None, None)))]
+    #[case(" text/html; charset=utf-8 ", Some(("text", "html", Some("utf-8"), None)))]
+    #[case(
+        r#"multipart/form-data; boundary="a;b c""#,
+        Some(("multipart", "form-data", None, Some("a;b c")))
+    )]
+    #[case("text/plain;CHARSET=\"us-ascii\";", Some(("text", "plain", Some("us-ascii"), None)))]
+    #[case("text", None)]
+    #[case("text/", None)]
+    #[case("text/html; charset", None)]
+    #[case("text/html; charset=a b", None)]
+    #[case("text/html; charset=\"utf-8", None)]
+    fn content_type_test(
+        #[case] input: &str,
+        #[case] expected: Option<(&str, &str, Option<&str>, Option<&str>)>,
+    ) {
+        let parsed = ContentType::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|ct| (ct.media_type(), ct.subtype(), ct.charset(), ct.boundary()))
+        );
+    }
+
+    #[rstest]
+    #[case("application/json", true)]
+    #[case("Application/JSON; charset=utf-8", true)]
+    #[case("application/problem+json", true)]
+    #[case("application/+json", false)]
+    #[case("application/jsonp", false)]
+    #[case("text/html", false)]
+    fn content_type_is_json(#[case] input: &str, #[case] expected: bool) {
+        assert_eq!(
+            expected,
+            ContentType::parse_header_value(input).unwrap().is_json()
+        );
+    }
+
+    #[test]
+    fn content_type_essence() {
+        let parsed = ContentType::parse_header_value("Text/HTML; level=1; charset=utf-8").unwrap();
+        assert_eq!(parsed.essence(), "Text/HTML");
+        assert!(parsed.is("text/html"));
+        assert_eq!(
+            parsed.params().collect::<Vec<_>>(),
+            [("level", "1"), ("charset", "utf-8")]
+        );
+    }
+}
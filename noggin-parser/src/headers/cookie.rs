@@ -0,0 +1,116 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+/// The `Cookies` type is the value of a `Cookie` request header, such as
+/// `session=38afes7a8; theme=dark`, borrowed from the header.
+///
+/// Pairs are separated by `;` only, commas and parentheses are part of the
+/// values. Every pair needs a name and a `=`, a value wrapped in DQUOTEs is
+/// returned without them.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cookies<'a>(&'a str);
+
+/// Splits a `name=value` pair, stripping the DQUOTEs around the value.
+pub(super) fn split_cookie(pair: &str) -> Option<(&str, &str)> {
+    let (name, value) = pair.split_once('=')?;
+    let name = trim(name);
+    let value = trim(value);
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+    (!name.is_empty()).then_some((name, value))
+}
+
+impl<'a> Cookies<'a> {
+    /// Returns an iterator over the cookies, in the order they were sent
+    /// in.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.0
+            .split(';')
+            .filter(|pair| !trim(pair).is_empty())
+            .filter_map(split_cookie)
+    }
+
+    /// Returns the value of the first cookie called `name`, compared
+    /// case-sensitively.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.iter()
+            .find(|(cookie, _)| *cookie == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the percent-decoded value of the first cookie called `name`.
+    /// The value is borrowed unless it contains percent-encoded bytes, and
+    /// `None` is also returned if they don't decode to UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn get_decoded(&self, name: &str) -> Option<Cow<'a, str>> {
+        percent_decode(self.get(name)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn percent_decode(value: &str) -> Option<Cow<'_, str>> {
+    if !value.contains('%') {
+        return Some(Cow::Borrowed(value));
+    }
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'%' {
+            decoded.push(b);
+            continue;
+        }
+        let high = (bytes.next()? as char).to_digit(16)?;
+        let low = (bytes.next()? as char).to_digit(16)?;
+        decoded.push((high << 4 | low) as u8);
+    }
+    alloc::string::String::from_utf8(decoded)
+        .ok()
+        .map(Cow::Owned)
+}
+
+impl<'de> FromHeaderValue<'de> for Cookies<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        slice
+            .split(';')
+            .filter(|pair| !trim(pair).is_empty())
+            .all(|pair| split_cookie(pair).is_some())
+            .then_some(Cookies(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("session=38afes7a8; theme=dark", Some(vec![("session", "38afes7a8"), ("theme", "dark")]))]
+    #[case("a=1;b=\"two\";", Some(vec![("a", "1"), ("b", "two")]))]
+    #[case("list=a,b; paren=(x", Some(vec![("list", "a,b"), ("paren", "(x")]))]
+    #[case("token=abc==", Some(vec![("token", "abc==")]))]
+    #[case("empty=", Some(vec![("empty", "")]))]
+    #[case("", Some(vec![]))]
+    #[case("flag; a=1", None)]
+    #[case("=value", None)]
+    fn cookies_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let parsed = Cookies::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|cookies| cookies.iter().collect()));
+    }
+
+    #[test]
+    fn cookies_get() {
+        let cookies = Cookies::parse_header_value("id=1; name=J%C3%BCrgen; id=2; bad=%zz").unwrap();
+        assert_eq!(cookies.get("id"), Some("1"));
+        assert_eq!(cookies.get("ID"), None);
+        assert!(matches!(
+            cookies.get_decoded("id"),
+            Some(Cow::Borrowed("1"))
+        ));
+        assert_eq!(cookies.get_decoded("name").as_deref(), Some("Jürgen"));
+        assert_eq!(cookies.get_decoded("bad"), None);
+    }
+}
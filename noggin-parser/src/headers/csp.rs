@@ -0,0 +1,158 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+
+/// The `Csp` type is a single policy of a `Content-Security-Policy` header,
+/// such as `default-src 'self'; img-src *; frame-ancestors 'none'`.
+///
+/// Directives are borrowed from the header, kept in order and include
+/// unknown ones. As in CSP3, directives with an invalid name or source list
+/// are skipped rather than rejecting the policy, which only has to keep at
+/// least one valid directive. Directive names are case-insensitive and only
+/// the first occurrence of a directive is enforced, which is what
+/// [`get`](Csp::get) returns. A header can carry several comma separated
+/// policies, collect them with `Vec<Csp>`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Csp<'a>(&'a str);
+
+/// Splits a directive into its name and source list, skipping empty and
+/// invalid directives.
+fn split_directive(directive: &str) -> Option<(&str, &str)> {
+    let directive = trim(directive);
+    if directive.is_empty() {
+        return None;
+    }
+    let end = directive.find([' ', '\t']).unwrap_or(directive.len());
+    let (name, sources) = directive.split_at(end);
+    is_directive_valid(name, sources).then_some((name, sources))
+}
+
+fn is_directive_valid(name: &str, sources: &str) -> bool {
+    name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        && sources
+            .bytes()
+            .all(|b| matches!(b, b' ' | b'\t' | b'!'..=b'~') && b != b';' && b != b',')
+}
+
+impl<'a> Csp<'a> {
+    /// Returns an iterator over the directive names and their source lists,
+    /// in the order they were sent in.
+    pub fn directives(&self) -> impl Iterator<Item = (&'a str, impl Iterator<Item = &'a str>)> {
+        self.0
+            .split(';')
+            .filter_map(split_directive)
+            .map(|(name, sources)| (name, sources.split_ascii_whitespace()))
+    }
+
+    /// Returns the source list of the directive called `name`, compared
+    /// case-insensitively.
+    pub fn get(&self, name: &str) -> Option<impl Iterator<Item = &'a str>> {
+        self.directives()
+            .find(|(directive, _)| directive.eq_ignore_ascii_case(name))
+            .map(|(_, sources)| sources)
+    }
+
+    /// Returns `true` if the directive called `name` is present, even with
+    /// an empty source list like `upgrade-insecure-requests`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Returns the `default-src` source list, the fallback of the fetch
+    /// directives.
+    pub fn default_src(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("default-src")
+    }
+
+    /// Returns the `script-src` source list.
+    pub fn script_src(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("script-src")
+    }
+
+    /// Returns the `style-src` source list.
+    pub fn style_src(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("style-src")
+    }
+
+    /// Returns the `img-src` source list.
+    pub fn img_src(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("img-src")
+    }
+
+    /// Returns the `connect-src` source list.
+    pub fn connect_src(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("connect-src")
+    }
+
+    /// Returns the `frame-ancestors` source list, which controls who may
+    /// embed the page.
+    pub fn frame_ancestors(&self) -> Option<impl Iterator<Item = &'a str>> {
+        self.get("frame-ancestors")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Csp<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut directives = slice.split(';').filter_map(split_directive);
+        directives.next().map(|_| Csp(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "default-src 'self'; img-src * data:; upgrade-insecure-requests;",
+        Some(vec![
+            ("default-src", vec!["'self'"]),
+            ("img-src", vec!["*", "data:"]),
+            ("upgrade-insecure-requests", vec![]),
+        ])
+    )]
+    #[case(
+        "  script-src\t'nonce-abc'  https://cdn.example ;; x-unknown a",
+        Some(vec![
+            ("script-src", vec!["'nonce-abc'", "https://cdn.example"]),
+            ("x-unknown", vec!["a"]),
+        ])
+    )]
+    #[case(
+        "default_src 'self'; img-src *; script-src caf\u{e9}",
+        Some(vec![("img-src", vec!["*"])])
+    )]
+    #[case("", None)]
+    #[case(" ; ", None)]
+    #[case("default-src 'self', img-src *", None)]
+    #[case("default_src 'self'", None)]
+    #[case("default-src caf\u{e9}", None)]
+    fn csp_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, Vec<&str>)>>) {
+        let parsed = Csp::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|csp| csp
+                .directives()
+                .map(|(name, sources)| (name, sources.collect()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn csp_accessors() {
+        let csp = Csp::parse_header_value(
+            "Default-Src 'self'; frame_ancestors *; frame-ancestors 'none'; default-src *; upgrade-insecure-requests",
+        )
+        .unwrap();
+        assert_eq!(
+            csp.default_src().unwrap().collect::<Vec<_>>(),
+            vec!["'self'"]
+        );
+        assert_eq!(
+            csp.frame_ancestors().unwrap().collect::<Vec<_>>(),
+            vec!["'none'"]
+        );
+        assert!(csp.script_src().is_none());
+        assert!(csp.contains("upgrade-insecure-requests"));
+    }
+}
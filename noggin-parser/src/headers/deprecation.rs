@@ -0,0 +1,100 @@
+use crate::structured::{BareItem, Item};
+use crate::{FromHeaderValue, HttpDate};
+
+/// The `Deprecation` type is the value of a `Deprecation` header (RFC 9745),
+/// the date a resource is or will be deprecated, given as a structured field
+/// date such as `@1688169599`.
+///
+/// Dates outside the years 0 to 9999, the range of [`HttpDate`], are
+/// rejected.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Deprecation(pub HttpDate);
+
+impl Deprecation {
+    /// Returns `true` if the resource is deprecated at `now`.
+    ///
+    /// With the `std` feature, `now` can also be a `SystemTime`.
+    pub fn is_deprecated_at(&self, now: impl Into<HttpDate>) -> bool {
+        self.0 <= now.into()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Deprecation {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match Item::parse_header_value(slice)?.bare() {
+            BareItem::Date(timestamp) => {
+                let date = HttpDate::from_unix_timestamp(timestamp);
+                (HttpDate::MIN..=HttpDate::MAX)
+                    .contains(&date)
+                    .then_some(Deprecation(date))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The `Sunset` type is the value of a `Sunset` header (RFC 8594), the
+/// HTTP-date after which a resource is expected to become unresponsive.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Sunset(pub HttpDate);
+
+impl Sunset {
+    /// Returns `true` if the sunset date is at or before `now`.
+    ///
+    /// With the `std` feature, `now` can also be a `SystemTime`.
+    pub fn has_passed(&self, now: impl Into<HttpDate>) -> bool {
+        self.0 <= now.into()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Sunset {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        HttpDate::parse_header_value(slice).map(Sunset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const NOW: HttpDate = HttpDate::from_unix_timestamp(1688169599);
+
+    #[rstest]
+    #[case("@1688169599", Some((1688169599, true)))]
+    #[case(" @1688169600 ", Some((1688169600, false)))]
+    #[case("@1688169599;reason=\"moved\"", Some((1688169599, true)))]
+    #[case("@-1", Some((-1, true)))]
+    #[case("@253402300799", Some((253402300799, false)))]
+    #[case("@-62167219200", Some((-62167219200, true)))]
+    #[case("@253402300800", None)]
+    #[case("@999999999999999", None)]
+    #[case("@-62167219201", None)]
+    #[case("1688169599", None)]
+    #[case("?1", None)]
+    #[case("Sun, 30 Jun 2023 23:59:59 GMT", None)]
+    #[case("", None)]
+    fn deprecation_test(#[case] input: &str, #[case] expected: Option<(i64, bool)>) {
+        let parsed = Deprecation::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|deprecation| (
+                deprecation.0.unix_timestamp(),
+                deprecation.is_deprecated_at(NOW)
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("Fri, 30 Jun 2023 23:59:59 GMT", Some((1688169599, true)))]
+    #[case("Sat, 01 Jul 2023 00:00:00 GMT", Some((1688169600, false)))]
+    #[case("@1688169599", None)]
+    #[case("tomorrow", None)]
+    fn sunset_test(#[case] input: &str, #[case] expected: Option<(i64, bool)>) {
+        let parsed = Sunset::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|sunset| (sunset.0.unix_timestamp(), sunset.has_passed(NOW)))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::structured::{BareItem, Dictionary};
+#[cfg(feature = "base64")]
+use crate::Base64;
+#[cfg(all(feature = "base64", feature = "alloc"))]
+use crate::Base64Bytes;
+use crate::FromHeaderValue;
+
+/// The `ContentDigest` type is the value of a `Content-Digest` or
+/// `Repr-Digest` header (RFC 9530), a dictionary of hashing algorithms to
+/// base64 encoded digests such as
+/// `sha-256=:RK/0qy18MlBSVnWgjwz6lZEWjP/lF5HF9bvEF8FabDg=:`.
+///
+/// Every member must be a byte sequence. The digests are decoded on demand,
+/// and with the `sha2` feature the `sha-256` and `sha-512` ones can be
+/// verified against a body.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentDigest<'a>(Dictionary<'a>);
+
+/// The value of a `Repr-Digest` header, which shares the syntax of
+/// [`ContentDigest`] but covers the selected representation instead of the
+/// content of the message.
+pub type ReprDigest<'a> = ContentDigest<'a>;
+
+impl<'a> ContentDigest<'a> {
+    /// Returns an iterator over the algorithms and their still base64
+    /// encoded digests, in order.
+    pub fn digests(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.0.iter().filter_map(|(algorithm, member)| {
+            Some((algorithm, member.as_item()?.bare().as_byte_sequence()?))
+        })
+    }
+
+    /// Returns the still base64 encoded digest computed with `algorithm`,
+    /// such as `sha-256`.
+    pub fn get(&self, algorithm: &str) -> Option<&'a str> {
+        self.digests()
+            .filter(|(name, _)| *name == algorithm)
+            .last()
+            .map(|(_, digest)| digest)
+    }
+
+    /// Returns the digest computed with `algorithm`, decoded into exactly
+    /// `N` bytes, such as 32 for `sha-256`.
+    #[cfg(feature = "base64")]
+    pub fn decode<const N: usize>(&self, algorithm: &str) -> Option<[u8; N]> {
+        Base64::<[u8; N]>::parse_header_value(self.get(algorithm)?).map(|digest| digest.0)
+    }
+
+    /// Returns the digest computed with `algorithm`, decoded whatever its
+    /// length.
+    #[cfg(all(feature = "base64", feature = "alloc"))]
+    pub fn decode_bytes(&self, algorithm: &str) -> Option<alloc::vec::Vec<u8>> {
+        Base64Bytes::parse_header_value(self.get(algorithm)?).map(|digest| digest.0)
+    }
+
+    /// Checks `body` against the `sha-256` and `sha-512` digests. Returns
+    /// `None` if neither is present, otherwise whether every one of them
+    /// matches. Other algorithms are ignored.
+    #[cfg(feature = "sha2")]
+    pub fn verify(&self, body: &[u8]) -> Option<bool> {
+        use sha2::{Digest, Sha256, Sha512};
+
+        let sha256 = self.get("sha-256").map(|_| {
+            self.decode::<32>("sha-256")
+                .is_some_and(|digest| digest[..] == Sha256::digest(body)[..])
+        });
+        let sha512 = self.get("sha-512").map(|_| {
+            self.decode::<64>("sha-512")
+                .is_some_and(|digest| digest[..] == Sha512::digest(body)[..])
+        });
+        match (sha256, sha512) {
+            (None, None) => None,
+            (sha256, sha512) => Some(sha256.unwrap_or(true) && sha512.unwrap_or(true)),
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ContentDigest<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let dictionary = Dictionary::parse_header_value(slice)?;
+        let mut count = 0;
+        let valid = dictionary.iter().all(|(_, member)| {
+            count += 1;
+            member
+                .as_item()
+                .is_some_and(|item| matches!(item.bare(), BareItem::ByteSequence(_)))
+        });
+        (valid && count > 0).then_some(ContentDigest(dictionary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[cfg(feature = "sha2")]
+    const HELLO_SHA256: &str = "sha-256=:uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek=:";
+    #[cfg(feature = "sha2")]
+    const HELLO_SHA512: &str = "sha-512=:MJ7MSJwS1utMxA9QyQLytNDtd+5RGnx6m808qG1M2G+YndNbxf9JlnDaNCVbRbDP2DDoH2Bdz33FVC6TrpzXbw==:";
+
+    #[rstest]
+    #[case(
+        "sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:, unixsum=:MTIz:",
+        Some(vec![("sha-256", "X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE="), ("unixsum", "MTIz")])
+    )]
+    #[case("sha-256=:abc=:;x=1", Some(vec![("sha-256", "abc=")]))]
+    #[case("sha-256=\"abc\"", None)]
+    #[case("sha-256", None)]
+    #[case("sha-256=(:abc=:)", None)]
+    #[case("SHA-256=:abc=:", None)]
+    #[case("", None)]
+    fn content_digest_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let parsed = ContentDigest::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|digest| digest.digests().collect()));
+    }
+
+    #[test]
+    fn content_digest_get() {
+        let digest = ContentDigest::parse_header_value("md5=:a:, sha-256=:b:, md5=:c:").unwrap();
+        assert_eq!(digest.get("md5"), Some("c"));
+        assert_eq!(digest.get("sha-256"), Some("b"));
+        assert_eq!(digest.get("sha-512"), None);
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn content_digest_decode() {
+        let digest = ContentDigest::parse_header_value("unixsum=:MTIz:, short=:MTI=:").unwrap();
+        assert_eq!(digest.decode::<3>("unixsum"), Some(*b"123"));
+        assert_eq!(digest.decode::<4>("unixsum"), None);
+        assert_eq!(digest.decode::<3>("short"), None);
+        #[cfg(feature = "alloc")]
+        assert_eq!(digest.decode_bytes("unixsum"), Some(b"123".to_vec()));
+    }
+
+    #[cfg(feature = "sha2")]
+    #[rstest]
+    #[case(HELLO_SHA256, b"hello world", Some(true))]
+    #[case(HELLO_SHA512, b"hello world", Some(true))]
+    #[case(HELLO_SHA256, b"hello world!", Some(false))]
+    #[case("sha-256=:MTIz:", b"hello world", Some(false))]
+    #[case("md5=:XrY7u+Ae7tCTyyK7j1rNww==:", b"hello world", None)]
+    fn content_digest_verify(
+        #[case] input: &str,
+        #[case] body: &[u8],
+        #[case] expected: Option<bool>,
+    ) {
+        let digest = ContentDigest::parse_header_value(input).unwrap();
+        assert_eq!(expected, digest.verify(body));
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn content_digest_verify_both() {
+        let both = [HELLO_SHA256, HELLO_SHA512].join(", ");
+        let digest = ContentDigest::parse_header_value(&both).unwrap();
+        assert_eq!(digest.verify(b"hello world"), Some(true));
+        let mismatch = [HELLO_SHA256, "sha-512=:MTIz:"].join(", ");
+        let digest = ContentDigest::parse_header_value(&mismatch).unwrap();
+        assert_eq!(digest.verify(b"hello world"), Some(false));
+    }
+}
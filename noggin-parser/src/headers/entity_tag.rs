@@ -0,0 +1,153 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `EntityTag` type is the value of an `ETag` header and an element of
+/// `If-Match` and `If-None-Match` lists, such as `"xyzzy"` or `W/"xyzzy"`.
+///
+/// The opaque tag is borrowed from the header without its DQUOTEs. Use
+/// [`strong_eq`](EntityTag::strong_eq) or [`weak_eq`](EntityTag::weak_eq)
+/// to compare tags as RFC 9110 section 8.8.3.2 describes, the derived
+/// `PartialEq` also compares the weakness.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct EntityTag<'a> {
+    weak: bool,
+    tag: &'a str,
+}
+
+fn is_etagc(c: char) -> bool {
+    c == '!' || ('#'..='~').contains(&c) || !c.is_ascii()
+}
+
+impl<'a> EntityTag<'a> {
+    /// Creates a strong entity tag, returning `None` if `tag` contains
+    /// characters that can't appear in one, such as a DQUOTE.
+    pub fn strong(tag: &'a str) -> Option<Self> {
+        tag.chars()
+            .all(is_etagc)
+            .then_some(EntityTag { weak: false, tag })
+    }
+
+    /// Creates a weak entity tag, returning `None` if `tag` contains
+    /// characters that can't appear in one, such as a DQUOTE.
+    pub fn weak(tag: &'a str) -> Option<Self> {
+        tag.chars()
+            .all(is_etagc)
+            .then_some(EntityTag { weak: true, tag })
+    }
+
+    /// Returns the opaque tag, without its DQUOTEs.
+    pub fn tag(&self) -> &'a str {
+        self.tag
+    }
+
+    /// Returns `true` if the tag has the `W/` weakness indicator.
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// Returns `true` if both tags are strong and their opaque tags are
+    /// equal, as required by `If-Match` and `If-Range`.
+    pub fn strong_eq(&self, other: &EntityTag<'_>) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    /// Returns `true` if the opaque tags are equal regardless of weakness,
+    /// as used by `If-None-Match`.
+    pub fn weak_eq(&self, other: &EntityTag<'_>) -> bool {
+        self.tag == other.tag
+    }
+}
+
+/// Splits a comma separated list of entity tags into its raw elements.
+///
+/// Unlike `split_list`, a backslash is an ordinary `etagc` rather than an
+/// escape, so a quoted tag always ends at the next DQUOTE.
+pub(crate) fn split_entity_tags(slice: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(slice);
+    core::iter::from_fn(move || {
+        let list = rest?;
+        let mut quoted = false;
+        for (i, b) in list.bytes().enumerate() {
+            match b {
+                b'"' => quoted = !quoted,
+                b',' if !quoted => {
+                    rest = Some(&list[i + 1..]);
+                    return Some(&list[..i]);
+                }
+                _ => {}
+            }
+        }
+        rest = None;
+        Some(list)
+    })
+}
+
+impl<'de> FromHeaderValue<'de> for EntityTag<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let (weak, quoted) = match value.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, value),
+        };
+        let tag = quoted.strip_prefix('"')?.strip_suffix('"')?;
+        match weak {
+            true => EntityTag::weak(tag),
+            false => EntityTag::strong(tag),
+        }
+    }
+}
+
+impl<'a> fmt::Display for EntityTag<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weak {
+            f.write_str("W/")?;
+        }
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(r#""xyzzy""#, Some((false, "xyzzy")))]
+    #[case(r#" W/"xyzzy" "#, Some((true, "xyzzy")))]
+    #[case(r#""""#, Some((false, "")))]
+    #[case("xyzzy", None)]
+    #[case(r#"w/"xyzzy""#, None)]
+    #[case(r#""xy"zy""#, None)]
+    #[case(r#""xy zy""#, None)]
+    #[case(r#""open"#, None)]
+    fn entity_tag_test(#[case] input: &str, #[case] expected: Option<(bool, &str)>) {
+        let parsed = EntityTag::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|etag| (etag.is_weak(), etag.tag())));
+    }
+
+    #[rstest]
+    #[case(r#"W/"1""#, r#"W/"1""#, false, true)]
+    #[case(r#"W/"1""#, r#"W/"2""#, false, false)]
+    #[case(r#"W/"1""#, r#""1""#, false, true)]
+    #[case(r#""1""#, r#""1""#, true, true)]
+    fn entity_tag_comparison(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] strong: bool,
+        #[case] weak: bool,
+    ) {
+        let a = EntityTag::parse_header_value(a).unwrap();
+        let b = EntityTag::parse_header_value(b).unwrap();
+        assert_eq!(strong, a.strong_eq(&b));
+        assert_eq!(weak, a.weak_eq(&b));
+    }
+
+    #[rstest]
+    #[case(r#""xyzzy""#)]
+    #[case(r#"W/"xyzzy""#)]
+    fn entity_tag_display(#[case] input: &str) {
+        let parsed = EntityTag::parse_header_value(input).unwrap();
+        assert_eq!(parsed.to_string(), input);
+    }
+}
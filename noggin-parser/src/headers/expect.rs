@@ -0,0 +1,91 @@
+use super::split_directive;
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, HttpVersion};
+
+/// The `Expect` type is the value of an `Expect` header, the expectations a
+/// client has of the server before it sends the body, in practice only
+/// `100-continue`.
+///
+/// Expectations are compared case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Expect<'a>(&'a str);
+
+impl<'a> Expect<'a> {
+    /// Returns an iterator over the names of the expectations, in order.
+    pub fn expectations(&self) -> impl Iterator<Item = &'a str> {
+        split_list(self.0)
+            .filter_map(split_directive)
+            .map(|(name, _)| name)
+    }
+
+    /// Returns `true` if the client waits for a `100 Continue` before
+    /// sending the body.
+    pub fn is_continue(&self) -> bool {
+        self.expectations()
+            .any(|name| name.eq_ignore_ascii_case("100-continue"))
+    }
+
+    /// Returns the first expectation other than `100-continue`, which a
+    /// server should answer with `417 Expectation Failed`.
+    pub fn unsupported(&self) -> Option<&'a str> {
+        self.expectations()
+            .find(|name| !name.eq_ignore_ascii_case("100-continue"))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Expect<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut expectations = split_list(slice).filter(|element| !trim(element).is_empty());
+        let mut count = 0;
+        let valid = expectations.all(|element| {
+            count += 1;
+            split_directive(element).is_some()
+        });
+        (valid && count > 0).then_some(Expect(slice))
+    }
+}
+
+/// Returns `true` if a server must send `100 Continue` before reading the
+/// body, given the version of the request and its `Expect` header.
+///
+/// HTTP/1.0 clients don't understand interim responses, so they never get
+/// one. Pass `None` if the header is absent.
+pub fn should_send_continue(version: HttpVersion, expect: Option<&Expect<'_>>) -> bool {
+    version >= HttpVersion::Http11 && expect.is_some_and(Expect::is_continue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("100-continue", Some((true, None)))]
+    #[case(" 100-Continue ", Some((true, None)))]
+    #[case("100-continue, foo=bar", Some((true, Some("foo"))))]
+    #[case("x-wait=\"5\"", Some((false, Some("x-wait"))))]
+    #[case("", None)]
+    #[case("100 continue", None)]
+    fn expect_test(#[case] input: &str, #[case] expected: Option<(bool, Option<&str>)>) {
+        let parsed = Expect::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|expect| (expect.is_continue(), expect.unsupported()))
+        );
+    }
+
+    #[rstest]
+    #[case(HttpVersion::Http11, Some("100-continue"), true)]
+    #[case(HttpVersion::Http2, Some("100-continue"), true)]
+    #[case(HttpVersion::Http10, Some("100-continue"), false)]
+    #[case(HttpVersion::Http11, Some("x-other"), false)]
+    #[case(HttpVersion::Http11, None, false)]
+    fn should_send_continue_test(
+        #[case] version: HttpVersion,
+        #[case] expect: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let expect = expect.map(|expect| Expect::parse_header_value(expect).unwrap());
+        assert_eq!(expected, should_send_continue(version, expect.as_ref()));
+    }
+}
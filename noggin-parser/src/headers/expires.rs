@@ -0,0 +1,63 @@
+use crate::{FromHeaderValue, HttpDate};
+
+/// The `Expires` type is the value of an `Expires` header, the date after
+/// which a response is stale.
+///
+/// As the specification requires, an invalid date such as `0` parses as
+/// already expired rather than failing, so it is never `None` when the
+/// header is present.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Expires {
+    /// A valid HTTP-date.
+    At(HttpDate),
+    /// An invalid date, meaning a time in the past.
+    Past,
+}
+
+impl Expires {
+    /// Returns the date, if it was valid.
+    pub fn date(&self) -> Option<HttpDate> {
+        match self {
+            Expires::At(date) => Some(*date),
+            Expires::Past => None,
+        }
+    }
+
+    /// Returns `true` if the response is expired at `now`.
+    ///
+    /// With the `std` feature, `now` can also be a `SystemTime`.
+    pub fn is_expired_at(&self, now: impl Into<HttpDate>) -> bool {
+        match self {
+            Expires::At(date) => *date <= now.into(),
+            Expires::Past => true,
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Expires {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Some(match HttpDate::parse_header_value(slice) {
+            Some(date) => Expires::At(date),
+            None => Expires::Past,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const NOW: HttpDate = HttpDate::from_unix_timestamp(1445412480);
+
+    #[rstest]
+    #[case("Wed, 21 Oct 2015 07:30:00 GMT", Some(1445412600), false)]
+    #[case("Wed, 21 Oct 2015 07:28:00 GMT", Some(1445412480), true)]
+    #[case("0", None, true)]
+    #[case("", None, true)]
+    fn expires_test(#[case] input: &str, #[case] date: Option<i64>, #[case] expired: bool) {
+        let expires = Expires::parse_header_value(input).unwrap();
+        assert_eq!(date, expires.date().map(|date| date.unix_timestamp()));
+        assert_eq!(expired, expires.is_expired_at(NOW));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::structured::Item;
+use crate::{FromHeaderValue, Method};
+
+/// The `SecFetchSite` type is the value of a `Sec-Fetch-Site` header, how
+/// the origin of the request relates to the origin of the target.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SecFetchSite {
+    /// `cross-site`
+    CrossSite,
+    /// `same-origin`
+    SameOrigin,
+    /// `same-site`, a different origin of the same site
+    SameSite,
+    /// `none`, initiated by the user such as by typing the URL
+    None,
+}
+
+impl SecFetchSite {
+    const ALL: [SecFetchSite; 4] = [
+        SecFetchSite::CrossSite,
+        SecFetchSite::SameOrigin,
+        SecFetchSite::SameSite,
+        SecFetchSite::None,
+    ];
+
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecFetchSite::CrossSite => "cross-site",
+            SecFetchSite::SameOrigin => "same-origin",
+            SecFetchSite::SameSite => "same-site",
+            SecFetchSite::None => "none",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SecFetchSite {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let token = Item::parse_header_value(slice)?.bare().as_token()?;
+        SecFetchSite::ALL
+            .into_iter()
+            .find(|site| site.as_str() == token)
+    }
+}
+
+/// The `SecFetchMode` type is the value of a `Sec-Fetch-Mode` header, the
+/// mode of the request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SecFetchMode {
+    /// `cors`
+    Cors,
+    /// `navigate`, a navigation between documents
+    Navigate,
+    /// `no-cors`
+    NoCors,
+    /// `same-origin`
+    SameOrigin,
+    /// `websocket`
+    WebSocket,
+}
+
+impl SecFetchMode {
+    const ALL: [SecFetchMode; 5] = [
+        SecFetchMode::Cors,
+        SecFetchMode::Navigate,
+        SecFetchMode::NoCors,
+        SecFetchMode::SameOrigin,
+        SecFetchMode::WebSocket,
+    ];
+
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecFetchMode::Cors => "cors",
+            SecFetchMode::Navigate => "navigate",
+            SecFetchMode::NoCors => "no-cors",
+            SecFetchMode::SameOrigin => "same-origin",
+            SecFetchMode::WebSocket => "websocket",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SecFetchMode {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let token = Item::parse_header_value(slice)?.bare().as_token()?;
+        SecFetchMode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == token)
+    }
+}
+
+/// The `SecFetchDest` type is the value of a `Sec-Fetch-Dest` header, how
+/// the response will be used.
+///
+/// Destinations this type doesn't know about fail to parse.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SecFetchDest {
+    /// `audio`
+    Audio,
+    /// `audioworklet`
+    AudioWorklet,
+    /// `document`, a top-level navigation
+    Document,
+    /// `embed`
+    Embed,
+    /// `empty`, such as `fetch()` or `XMLHttpRequest`
+    Empty,
+    /// `font`
+    Font,
+    /// `frame`
+    Frame,
+    /// `iframe`
+    Iframe,
+    /// `image`
+    Image,
+    /// `manifest`
+    Manifest,
+    /// `object`
+    Object,
+    /// `paintworklet`
+    PaintWorklet,
+    /// `report`
+    Report,
+    /// `script`
+    Script,
+    /// `serviceworker`
+    ServiceWorker,
+    /// `sharedworker`
+    SharedWorker,
+    /// `style`
+    Style,
+    /// `track`
+    Track,
+    /// `video`
+    Video,
+    /// `webidentity`
+    WebIdentity,
+    /// `worker`
+    Worker,
+    /// `xslt`
+    Xslt,
+}
+
+impl SecFetchDest {
+    const ALL: [SecFetchDest; 22] = [
+        SecFetchDest::Audio,
+        SecFetchDest::AudioWorklet,
+        SecFetchDest::Document,
+        SecFetchDest::Embed,
+        SecFetchDest::Empty,
+        SecFetchDest::Font,
+        SecFetchDest::Frame,
+        SecFetchDest::Iframe,
+        SecFetchDest::Image,
+        SecFetchDest::Manifest,
+        SecFetchDest::Object,
+        SecFetchDest::PaintWorklet,
+        SecFetchDest::Report,
+        SecFetchDest::Script,
+        SecFetchDest::ServiceWorker,
+        SecFetchDest::SharedWorker,
+        SecFetchDest::Style,
+        SecFetchDest::Track,
+        SecFetchDest::Video,
+        SecFetchDest::WebIdentity,
+        SecFetchDest::Worker,
+        SecFetchDest::Xslt,
+    ];
+
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecFetchDest::Audio => "audio",
+            SecFetchDest::AudioWorklet => "audioworklet",
+            SecFetchDest::Document => "document",
+            SecFetchDest::Embed => "embed",
+            SecFetchDest::Empty => "empty",
+            SecFetchDest::Font => "font",
+            SecFetchDest::Frame => "frame",
+            SecFetchDest::Iframe => "iframe",
+            SecFetchDest::Image => "image",
+            SecFetchDest::Manifest => "manifest",
+            SecFetchDest::Object => "object",
+            SecFetchDest::PaintWorklet => "paintworklet",
+            SecFetchDest::Report => "report",
+            SecFetchDest::Script => "script",
+            SecFetchDest::ServiceWorker => "serviceworker",
+            SecFetchDest::SharedWorker => "sharedworker",
+            SecFetchDest::Style => "style",
+            SecFetchDest::Track => "track",
+            SecFetchDest::Video => "video",
+            SecFetchDest::WebIdentity => "webidentity",
+            SecFetchDest::Worker => "worker",
+            SecFetchDest::Xslt => "xslt",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SecFetchDest {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let token = Item::parse_header_value(slice)?.bare().as_token()?;
+        SecFetchDest::ALL
+            .into_iter()
+            .find(|dest| dest.as_str() == token)
+    }
+}
+
+/// The `SecFetchUser` type is the value of a `Sec-Fetch-User` header, `?1`
+/// if a navigation was triggered by the user.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SecFetchUser(pub bool);
+
+impl<'de> FromHeaderValue<'de> for SecFetchUser {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Item::parse_header_value(slice)?
+            .bare()
+            .as_bool()
+            .map(SecFetchUser)
+    }
+}
+
+/// Applies a resource isolation policy to a request with `method` and the
+/// given fetch metadata headers, returning `false` if it should be
+/// rejected as a likely cross-site attack.
+///
+/// Requests without `Sec-Fetch-Site`, from user agents that don't send
+/// fetch metadata, are allowed, as are same-site and user-initiated ones.
+/// A cross-site request is only allowed as a simple `GET` navigation that
+/// doesn't load the response into an `<object>` or `<embed>`.
+pub fn resource_isolation_allows(
+    method: Method<'_>,
+    site: Option<SecFetchSite>,
+    mode: Option<SecFetchMode>,
+    dest: Option<SecFetchDest>,
+) -> bool {
+    match site {
+        None | Some(SecFetchSite::SameOrigin | SecFetchSite::SameSite | SecFetchSite::None) => true,
+        Some(SecFetchSite::CrossSite) => {
+            mode == Some(SecFetchMode::Navigate)
+                && method == Method::Get
+                && !matches!(dest, Some(SecFetchDest::Object | SecFetchDest::Embed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("cross-site", Some(SecFetchSite::CrossSite))]
+    #[case(" same-origin ", Some(SecFetchSite::SameOrigin))]
+    #[case("same-site", Some(SecFetchSite::SameSite))]
+    #[case("none", Some(SecFetchSite::None))]
+    #[case("Same-Site", None)]
+    #[case("\"none\"", None)]
+    #[case("", None)]
+    fn sec_fetch_site_test(#[case] input: &str, #[case] expected: Option<SecFetchSite>) {
+        assert_eq!(expected, SecFetchSite::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("navigate", Some(SecFetchMode::Navigate))]
+    #[case("no-cors", Some(SecFetchMode::NoCors))]
+    #[case("websocket", Some(SecFetchMode::WebSocket))]
+    #[case("cors, navigate", None)]
+    fn sec_fetch_mode_test(#[case] input: &str, #[case] expected: Option<SecFetchMode>) {
+        assert_eq!(expected, SecFetchMode::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("document", Some(SecFetchDest::Document))]
+    #[case("iframe", Some(SecFetchDest::Iframe))]
+    #[case("empty", Some(SecFetchDest::Empty))]
+    #[case("serviceworker", Some(SecFetchDest::ServiceWorker))]
+    #[case("hologram", None)]
+    fn sec_fetch_dest_test(#[case] input: &str, #[case] expected: Option<SecFetchDest>) {
+        assert_eq!(expected, SecFetchDest::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("?1", Some(true))]
+    #[case("?0", Some(false))]
+    #[case("1", None)]
+    fn sec_fetch_user_test(#[case] input: &str, #[case] expected: Option<bool>) {
+        let parsed = SecFetchUser::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|user| user.0));
+    }
+
+    #[rstest]
+    #[case(Method::Post, None, None, None, true)]
+    #[case(
+        Method::Post,
+        Some(SecFetchSite::SameOrigin),
+        Some(SecFetchMode::Cors),
+        None,
+        true
+    )]
+    #[case(
+        Method::Get,
+        Some(SecFetchSite::None),
+        Some(SecFetchMode::Navigate),
+        None,
+        true
+    )]
+    #[case(
+        Method::Get,
+        Some(SecFetchSite::CrossSite),
+        Some(SecFetchMode::Navigate),
+        Some(SecFetchDest::Document),
+        true
+    )]
+    #[case(
+        Method::Post,
+        Some(SecFetchSite::CrossSite),
+        Some(SecFetchMode::Navigate),
+        Some(SecFetchDest::Document),
+        false
+    )]
+    #[case(
+        Method::Get,
+        Some(SecFetchSite::CrossSite),
+        Some(SecFetchMode::Navigate),
+        Some(SecFetchDest::Embed),
+        false
+    )]
+    #[case(
+        Method::Get,
+        Some(SecFetchSite::CrossSite),
+        Some(SecFetchMode::NoCors),
+        Some(SecFetchDest::Image),
+        false
+    )]
+    fn resource_isolation_test(
+        #[case] method: Method,
+        #[case] site: Option<SecFetchSite>,
+        #[case] mode: Option<SecFetchMode>,
+        #[case] dest: Option<SecFetchDest>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            expected,
+            resource_isolation_allows(method, site, mode, dest)
+        );
+    }
+}
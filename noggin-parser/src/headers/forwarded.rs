@@ -0,0 +1,287 @@
+use super::{split_param, Host};
+use crate::from_header_value::{split_list, split_params, trim};
+use crate::FromHeaderValue;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// The identifier of a [`Node`], either an IP address, `unknown` or an
+/// obfuscated identifier such as `_hidden`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum NodeName<'a> {
+    Ip(IpAddr),
+    Unknown,
+    Obfuscated(&'a str),
+}
+
+/// The port of a [`Node`], either a number or an obfuscated identifier.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum NodePort<'a> {
+    Port(u16),
+    Obfuscated(&'a str),
+}
+
+/// The `Node` type is the value of the `for` and `by` parameters of a
+/// `Forwarded` element, such as `192.0.2.43` or `"[2001:db8:cafe::17]:4711"`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Node<'a> {
+    name: NodeName<'a>,
+    port: Option<NodePort<'a>>,
+}
+
+impl<'a> Node<'a> {
+    /// Returns the identifier of the node.
+    pub fn name(&self) -> NodeName<'a> {
+        self.name
+    }
+
+    /// Returns the port of the node, if one was given.
+    pub fn port(&self) -> Option<NodePort<'a>> {
+        self.port
+    }
+
+    /// Returns the IP address of the node, if it isn't hidden.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self.name {
+            NodeName::Ip(ip) => Some(ip),
+            _ => None,
+        }
+    }
+}
+
+fn parse_obfuscated(value: &str) -> Option<&str> {
+    let valid = value.len() > 1
+        && value.starts_with('_')
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'));
+    valid.then_some(value)
+}
+
+impl<'de> FromHeaderValue<'de> for Node<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let (name, port) = if let Some(bracketed) = value.strip_prefix('[') {
+            let (ip, rest) = bracketed.split_once(']')?;
+            let port = match rest {
+                "" => None,
+                _ => Some(rest.strip_prefix(':')?),
+            };
+            (NodeName::Ip(IpAddr::V6(ip.parse::<Ipv6Addr>().ok()?)), port)
+        } else {
+            let (name, port) = match value.split_once(':') {
+                Some((name, port)) => (name, Some(port)),
+                None => (value, None),
+            };
+            let name = if name == "unknown" {
+                NodeName::Unknown
+            } else if let Ok(ip) = name.parse::<Ipv4Addr>() {
+                NodeName::Ip(IpAddr::V4(ip))
+            } else {
+                NodeName::Obfuscated(parse_obfuscated(name)?)
+            };
+            (name, port)
+        };
+        let port = match port {
+            None => None,
+            Some(port) if port.starts_with('_') => {
+                Some(NodePort::Obfuscated(parse_obfuscated(port)?))
+            }
+            Some(port) if port.bytes().all(|b| b.is_ascii_digit()) => {
+                Some(NodePort::Port(port.parse().ok()?))
+            }
+            Some(_) => return None,
+        };
+        Some(Node { name, port })
+    }
+}
+
+/// The `ForwardedElement` type is a single hop of a `Forwarded` header, the
+/// `;` separated parameters added by one proxy.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ForwardedElement<'a> {
+    raw: &'a str,
+    for_node: Option<Node<'a>>,
+    by: Option<Node<'a>>,
+    host: Option<Host<'a>>,
+    proto: Option<&'a str>,
+}
+
+impl<'a> ForwardedElement<'a> {
+    /// Returns the `for` parameter, the client or previous proxy the
+    /// request came from.
+    pub fn for_node(&self) -> Option<Node<'a>> {
+        self.for_node
+    }
+
+    /// Returns the `by` parameter, the interface of the proxy that received
+    /// the request.
+    pub fn by(&self) -> Option<Node<'a>> {
+        self.by
+    }
+
+    /// Returns the `host` parameter, the `Host` the proxy received.
+    pub fn host(&self) -> Option<Host<'a>> {
+        self.host
+    }
+
+    /// Returns the `proto` parameter, the scheme the proxy was contacted
+    /// with such as `https`.
+    pub fn proto(&self) -> Option<&'a str> {
+        self.proto
+    }
+
+    /// Returns the unquoted value of the parameter called `name`, compared
+    /// case-insensitively, including extension parameters.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        split_params(self.raw)
+            .filter_map(split_param)
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+}
+
+fn is_scheme(value: &str) -> bool {
+    value
+        .bytes()
+        .next()
+        .is_some_and(|b| b.is_ascii_alphabetic())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Stores a parameter that may appear only once per element.
+fn set_once<T>(slot: &mut Option<T>, value: Option<T>) -> Option<()> {
+    if slot.is_some() {
+        return None;
+    }
+    *slot = Some(value?);
+    Some(())
+}
+
+impl<'de> FromHeaderValue<'de> for ForwardedElement<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut element = ForwardedElement {
+            raw: slice,
+            for_node: None,
+            by: None,
+            host: None,
+            proto: None,
+        };
+        let mut count = 0;
+        for param in split_params(slice).filter(|param| !trim(param).is_empty()) {
+            count += 1;
+            let (name, value) = split_param(param)?;
+            if name.eq_ignore_ascii_case("for") {
+                set_once(&mut element.for_node, Node::parse_header_value(value))?;
+            } else if name.eq_ignore_ascii_case("by") {
+                set_once(&mut element.by, Node::parse_header_value(value))?;
+            } else if name.eq_ignore_ascii_case("host") {
+                set_once(&mut element.host, Host::parse_header_value(value))?;
+            } else if name.eq_ignore_ascii_case("proto") {
+                set_once(&mut element.proto, is_scheme(value).then_some(value))?;
+            }
+        }
+        (count > 0).then_some(element)
+    }
+}
+
+/// The `Forwarded` type is the value of a `Forwarded` header (RFC 7239),
+/// the chain of proxies a request went through such as
+/// `for=192.0.2.60;proto=http;by=203.0.113.43, for="[2001:db8::17]"`.
+///
+/// Elements are yielded in the order they were added, so the first one was
+/// added by the proxy closest to the client. Only the last elements, added
+/// by proxies you trust, can be relied upon.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Forwarded<'a>(&'a str);
+
+impl<'a> Forwarded<'a> {
+    /// Returns an iterator over the elements, from the client side to the
+    /// server side.
+    pub fn elements(&self) -> impl Iterator<Item = ForwardedElement<'a>> {
+        split_list(self.0)
+            .filter(|element| !trim(element).is_empty())
+            .filter_map(ForwardedElement::parse_header_value)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Forwarded<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut elements = split_list(slice).filter(|element| !trim(element).is_empty());
+        let mut count = 0;
+        let valid = elements.all(|element| {
+            count += 1;
+            ForwardedElement::parse_header_value(element).is_some()
+        });
+        (valid && count > 0).then_some(Forwarded(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("192.0.2.43", Some((NodeName::Ip([192, 0, 2, 43].into()), None)))]
+    #[case("192.0.2.43:47011", Some((NodeName::Ip([192, 0, 2, 43].into()), Some(NodePort::Port(47011)))))]
+    #[case("[2001:db8:cafe::17]:4711", Some((NodeName::Ip("2001:db8:cafe::17".parse().unwrap()), Some(NodePort::Port(4711)))))]
+    #[case("unknown", Some((NodeName::Unknown, None)))]
+    #[case("_hidden:_SEVKISEK", Some((NodeName::Obfuscated("_hidden"), Some(NodePort::Obfuscated("_SEVKISEK")))))]
+    #[case("_", None)]
+    #[case("hidden", None)]
+    #[case("2001:db8::17", None)]
+    #[case("192.0.2.43:port", None)]
+    #[case("192.0.2.43:99999", None)]
+    #[case("[2001:db8::17]4711", None)]
+    fn node_test(#[case] input: &str, #[case] expected: Option<(NodeName, Option<NodePort>)>) {
+        let parsed = Node::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|node| (node.name(), node.port())));
+    }
+
+    #[test]
+    fn forwarded_chain() {
+        let forwarded = Forwarded::parse_header_value(
+            "for=192.0.2.60;proto=http;by=203.0.113.43;host=Example.com, \
+             For=\"[2001:db8:cafe::17]:4711\";secret=abc, for=unknown",
+        )
+        .unwrap();
+        let elements: Vec<_> = forwarded.elements().collect();
+        assert_eq!(elements.len(), 3);
+
+        assert_eq!(
+            elements[0].for_node().unwrap().ip(),
+            Some([192, 0, 2, 60].into())
+        );
+        assert_eq!(
+            elements[0].by().unwrap().ip(),
+            Some([203, 0, 113, 43].into())
+        );
+        assert_eq!(elements[0].proto(), Some("http"));
+        assert!(elements[0].host().unwrap().is("example.com"));
+
+        assert_eq!(
+            elements[1].for_node().unwrap().ip(),
+            Some("2001:db8:cafe::17".parse().unwrap())
+        );
+        assert_eq!(elements[1].get("secret"), Some("abc"));
+        assert_eq!(elements[1].proto(), None);
+
+        assert_eq!(elements[2].for_node().unwrap().name(), NodeName::Unknown);
+    }
+
+    #[rstest]
+    #[case("for=192.0.2.60", true)]
+    #[case("for=192.0.2.60;;, ", true)]
+    #[case("ext=\"a, b\";for=_x", true)]
+    #[case("", false)]
+    #[case("for=192.0.2.60;for=192.0.2.61", false)]
+    #[case("for=2001:db8::17", false)]
+    #[case("for=\"2001:db8::17\"", false)]
+    #[case("proto=1http", false)]
+    #[case("host=exa mple", false)]
+    #[case("for", false)]
+    fn forwarded_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, Forwarded::parse_header_value(input).is_some());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{FromHeaderValue, HostPort};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::net::{IpAddr, Ipv4Addr};
+
+/// The `Host` type is the value of a `Host` request header, a host with an
+/// optional port such as `Example.com:8080`, meant for virtual host routing.
+///
+/// On top of the syntax accepted by [`HostPort`], percent-encodings in a
+/// registered name must be complete and a host made of digits and dots must
+/// be a valid IPv4 address. Hosts are compared, hashed and displayed in
+/// lowercase, [`host`](Host::host) returns it as it appeared.
+#[derive(Clone, Copy, Debug)]
+pub struct Host<'a> {
+    host: &'a str,
+    port: Option<u16>,
+}
+
+impl<'a> Host<'a> {
+    /// Returns the host as it appeared, without the brackets of an IPv6
+    /// address.
+    pub fn host(&self) -> &'a str {
+        self.host
+    }
+
+    /// Returns the port, if one was given.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Returns the host as an IP address if it is one.
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.host.parse().ok()
+    }
+
+    /// Returns `true` if the host is `name`, compared case-insensitively
+    /// and ignoring the port.
+    pub fn is(&self, name: &str) -> bool {
+        self.host.eq_ignore_ascii_case(name)
+    }
+}
+
+fn is_pct_encoding_valid(host: &str) -> bool {
+    let mut bytes = host.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%'
+            && !(bytes.next().is_some_and(|b| b.is_ascii_hexdigit())
+                && bytes.next().is_some_and(|b| b.is_ascii_hexdigit()))
+        {
+            return false;
+        }
+    }
+    true
+}
+
+fn is_ipv4_valid(host: &str) -> bool {
+    !host.bytes().all(|b| b.is_ascii_digit() || b == b'.') || host.parse::<Ipv4Addr>().is_ok()
+}
+
+impl<'de> FromHeaderValue<'de> for Host<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let host_port = HostPort::parse_header_value(slice)?;
+        let host = host_port.host();
+        let is_valid = host.contains(':') || is_pct_encoding_valid(host) && is_ipv4_valid(host);
+        if !is_valid {
+            return None;
+        }
+        Some(Host {
+            host,
+            port: host_port.port(),
+        })
+    }
+}
+
+impl<'a, 'b> PartialEq<Host<'b>> for Host<'a> {
+    fn eq(&self, other: &Host<'b>) -> bool {
+        self.is(other.host) && self.port == other.port
+    }
+}
+
+impl<'a> Eq for Host<'a> {}
+
+impl<'a> Hash for Host<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.host.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+        self.port.hash(state);
+    }
+}
+
+impl<'a> fmt::Display for Host<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ipv6 = self.host.contains(':');
+        if ipv6 {
+            f.write_str("[")?;
+        }
+        for c in self.host.chars() {
+            fmt::Write::write_char(f, c.to_ascii_lowercase())?;
+        }
+        if ipv6 {
+            f.write_str("]")?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Example.com", Some(("Example.com", None)))]
+    #[case("example.com:8080", Some(("example.com", Some(8080))))]
+    #[case("192.0.2.1:80", Some(("192.0.2.1", Some(80))))]
+    #[case("[2001:DB8::1]:443", Some(("2001:DB8::1", Some(443))))]
+    #[case("caf%C3%A9.example", Some(("caf%C3%A9.example", None)))]
+    #[case("1.example", Some(("1.example", None)))]
+    #[case("", None)]
+    #[case("999.0.0.1", None)]
+    #[case("192.0.2", None)]
+    #[case("bad%2", None)]
+    #[case("bad%zz.example", None)]
+    #[case("example.com/evil", None)]
+    #[case("user@example.com", None)]
+    #[case("example.com:80:80", None)]
+    #[case("[::1", None)]
+    fn host_test(#[case] input: &str, #[case] expected: Option<(&str, Option<u16>)>) {
+        let parsed = Host::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|host| (host.host(), host.port())));
+    }
+
+    #[rstest]
+    #[case("Example.COM:8080", "example.com:8080")]
+    #[case("[2001:DB8::1]", "[2001:db8::1]")]
+    fn host_display(#[case] input: &str, #[case] expected: &str) {
+        let parsed = Host::parse_header_value(input).unwrap();
+        assert_eq!(parsed.to_string(), expected);
+    }
+
+    #[test]
+    fn host_eq() {
+        let host = Host::parse_header_value("EXAMPLE.com:80").unwrap();
+        assert!(host.is("example.COM"));
+        assert_eq!(host, Host::parse_header_value("example.com:80").unwrap());
+        assert_ne!(host, Host::parse_header_value("example.com").unwrap());
+        assert_eq!(host.ip(), None);
+    }
+}
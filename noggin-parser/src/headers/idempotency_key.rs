@@ -0,0 +1,138 @@
+use crate::structured::Item;
+use crate::FromHeaderValue;
+
+/// The format an [`IdempotencyKey`] has to follow.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum IdempotencyKeyFormat {
+    /// Any non-empty printable ASCII string
+    #[default]
+    Opaque,
+    /// A UUID in its hyphenated form, such as
+    /// `8e03978e-40d5-43e8-bc93-6894a57f9324`
+    Uuid,
+}
+
+/// The `IdempotencyKey` type is the value of an `Idempotency-Key` header,
+/// the key a server uses to recognize retries of the same request, such as
+/// `"8e03978e-40d5-43e8-bc93-6894a57f9324"`.
+///
+/// The header is a structured field string of at most
+/// [`IdempotencyKey::MAX_LEN`] characters. Parsing accepts any such key,
+/// use [`IdempotencyKey::new`] to also require a UUID.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IdempotencyKey<'a>(&'a str);
+
+impl<'a> IdempotencyKey<'a> {
+    /// The longest key accepted.
+    pub const MAX_LEN: usize = 255;
+
+    /// Creates a key from its unquoted value, or returns `None` if it is
+    /// empty, too long, not printable ASCII or not in `format`.
+    pub fn new(key: &'a str, format: IdempotencyKeyFormat) -> Option<Self> {
+        let valid = !key.is_empty()
+            && key.len() <= Self::MAX_LEN
+            && key.bytes().all(|b| matches!(b, b' '..=b'~'))
+            && (format == IdempotencyKeyFormat::Opaque || is_uuid(key));
+        valid.then_some(IdempotencyKey(key))
+    }
+
+    /// Returns the key, with its escapes kept.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns `true` if the key is a hyphenated UUID.
+    pub fn is_uuid(&self) -> bool {
+        is_uuid(self.0)
+    }
+
+    /// Returns the key as a UUID, if it is one.
+    #[cfg(feature = "uuid")]
+    pub fn as_uuid(&self) -> Option<uuid::Uuid> {
+        if !self.is_uuid() {
+            return None;
+        }
+        uuid::Uuid::try_parse(self.0).ok()
+    }
+}
+
+/// Returns `true` if `key` is a hyphenated UUID, in either case.
+fn is_uuid(key: &str) -> bool {
+    key.len() == 36
+        && key.bytes().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+impl<'de> FromHeaderValue<'de> for IdempotencyKey<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let key = Item::parse_header_value(slice)?.bare().as_string()?;
+        IdempotencyKey::new(key, IdempotencyKeyFormat::Opaque)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("\"8e03978e-40d5-43e8-bc93-6894a57f9324\"", Some(("8e03978e-40d5-43e8-bc93-6894a57f9324", true)))]
+    #[case(" \"order-42\" ", Some(("order-42", false)))]
+    #[case("\"order-42\"; scope=checkout", Some(("order-42", false)))]
+    #[case("\"\"", None)]
+    #[case("order-42", None)]
+    #[case("\"a\", \"b\"", None)]
+    fn idempotency_key_test(#[case] input: &str, #[case] expected: Option<(&str, bool)>) {
+        let parsed = IdempotencyKey::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|key| (key.as_str(), key.is_uuid())));
+    }
+
+    #[test]
+    fn idempotency_key_too_long() {
+        let key = "k".repeat(IdempotencyKey::MAX_LEN);
+        assert!(IdempotencyKey::parse_header_value(&format!("\"{key}\"")).is_some());
+        assert!(IdempotencyKey::parse_header_value(&format!("\"{key}k\"")).is_none());
+    }
+
+    #[rstest]
+    #[case("order-42", IdempotencyKeyFormat::Opaque, true)]
+    #[case("order-42", IdempotencyKeyFormat::Uuid, false)]
+    #[case(
+        "8E03978E-40D5-43E8-BC93-6894A57F9324",
+        IdempotencyKeyFormat::Uuid,
+        true
+    )]
+    #[case("8e03978e40d543e8bc936894a57f9324", IdempotencyKeyFormat::Uuid, false)]
+    #[case(
+        "8e03978e-40d5-43e8-bc93-6894a57f932g",
+        IdempotencyKeyFormat::Uuid,
+        false
+    )]
+    #[case("caf\u{e9}", IdempotencyKeyFormat::Opaque, false)]
+    #[case("", IdempotencyKeyFormat::Opaque, false)]
+    fn idempotency_key_new(
+        #[case] key: &str,
+        #[case] format: IdempotencyKeyFormat,
+        #[case] valid: bool,
+    ) {
+        assert_eq!(valid, IdempotencyKey::new(key, format).is_some());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn idempotency_key_as_uuid() {
+        let key = IdempotencyKey::new(
+            "8e03978e-40d5-43e8-bc93-6894a57f9324",
+            IdempotencyKeyFormat::Uuid,
+        )
+        .unwrap();
+        assert_eq!(
+            key.as_uuid(),
+            Some(uuid::Uuid::from_u128(0x8e03978e40d543e8bc936894a57f9324))
+        );
+        let opaque = IdempotencyKey::new("order-42", IdempotencyKeyFormat::Opaque).unwrap();
+        assert_eq!(opaque.as_uuid(), None);
+    }
+}
@@ -0,0 +1,150 @@
+use super::entity_tag::split_entity_tags;
+use super::EntityTag;
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+
+/// Either `*` or a validated list of entity tags, borrowed from the header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum EntityTags<'a> {
+    Any,
+    List(&'a str),
+}
+
+impl<'a> EntityTags<'a> {
+    fn tags(&self) -> impl Iterator<Item = EntityTag<'a>> {
+        let list = match self {
+            EntityTags::Any => "",
+            EntityTags::List(list) => list,
+        };
+        split_entity_tags(list)
+            .filter(|tag| !trim(tag).is_empty())
+            .filter_map(EntityTag::parse_header_value)
+    }
+
+    fn parse(slice: &str) -> Option<EntityTags<'_>> {
+        if trim(slice) == "*" {
+            return Some(EntityTags::Any);
+        }
+        let mut found = false;
+        for tag in split_entity_tags(slice).filter(|tag| !trim(tag).is_empty()) {
+            EntityTag::parse_header_value(tag)?;
+            found = true;
+        }
+        found.then_some(EntityTags::List(slice))
+    }
+}
+
+/// The `IfMatch` type is the value of an `If-Match` header, either `*` or a
+/// list of entity tags compared with the strong comparison.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IfMatch<'a>(EntityTags<'a>);
+
+impl<'a> IfMatch<'a> {
+    /// Returns `true` for the `*` form.
+    pub fn is_any(&self) -> bool {
+        self.0 == EntityTags::Any
+    }
+
+    /// Returns an iterator over the listed entity tags, which is empty for
+    /// the `*` form.
+    pub fn tags(&self) -> impl Iterator<Item = EntityTag<'a>> {
+        self.0.tags()
+    }
+
+    /// Returns `true` if the condition holds for a current representation
+    /// with the tag `etag`: the header is `*` or lists a tag that is
+    /// strongly equal to it.
+    pub fn matches(&self, etag: &EntityTag<'_>) -> bool {
+        self.is_any() || self.tags().any(|tag| tag.strong_eq(etag))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for IfMatch<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        EntityTags::parse(slice).map(IfMatch)
+    }
+}
+
+/// The `IfNoneMatch` type is the value of an `If-None-Match` header, either
+/// `*` or a list of entity tags compared with the weak comparison.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IfNoneMatch<'a>(EntityTags<'a>);
+
+impl<'a> IfNoneMatch<'a> {
+    /// Returns `true` for the `*` form.
+    pub fn is_any(&self) -> bool {
+        self.0 == EntityTags::Any
+    }
+
+    /// Returns an iterator over the listed entity tags, which is empty for
+    /// the `*` form.
+    pub fn tags(&self) -> impl Iterator<Item = EntityTag<'a>> {
+        self.0.tags()
+    }
+
+    /// Returns `true` if a current representation with the tag `etag`
+    /// matches the header, which makes the condition false: the header is
+    /// `*` or lists a tag that is weakly equal to it.
+    pub fn matches(&self, etag: &EntityTag<'_>) -> bool {
+        self.is_any() || self.tags().any(|tag| tag.weak_eq(etag))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for IfNoneMatch<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        EntityTags::parse(slice).map(IfNoneMatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("*", true)]
+    #[case(r#""a", W/"b""#, true)]
+    #[case(r#""a,b""#, true)]
+    #[case(r#""a\", "b""#, true)]
+    #[case(r#""a\"b""#, false)]
+    #[case(r#""a", *"#, false)]
+    #[case("a", false)]
+    #[case("", false)]
+    #[case(" , ", false)]
+    #[case(r#", "a","#, true)]
+    fn if_match_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, IfMatch::parse_header_value(input).is_some());
+        assert_eq!(valid, IfNoneMatch::parse_header_value(input).is_some());
+    }
+
+    #[rstest]
+    #[case("*", r#""x""#, true, true)]
+    #[case(r#""x", "y""#, r#""y""#, true, true)]
+    #[case(r#"W/"x""#, r#""x""#, false, true)]
+    #[case(r#""x""#, r#"W/"x""#, false, true)]
+    #[case(r#""x""#, r#""z""#, false, false)]
+    fn if_match_matches(
+        #[case] input: &str,
+        #[case] etag: &str,
+        #[case] if_match: bool,
+        #[case] if_none_match: bool,
+    ) {
+        let etag = EntityTag::parse_header_value(etag).unwrap();
+        let parsed = IfMatch::parse_header_value(input).unwrap();
+        assert_eq!(if_match, parsed.matches(&etag));
+        let parsed = IfNoneMatch::parse_header_value(input).unwrap();
+        assert_eq!(if_none_match, parsed.matches(&etag));
+    }
+
+    #[test]
+    fn if_match_tags() {
+        let parsed = IfNoneMatch::parse_header_value(r#"W/"a", "b""#).unwrap();
+        assert!(!parsed.is_any());
+        let tags: Vec<_> = parsed.tags().map(|tag| tag.tag()).collect();
+        assert_eq!(tags, ["a", "b"]);
+        assert_eq!(IfMatch::parse_header_value("*").unwrap().tags().count(), 0);
+        let parsed = IfNoneMatch::parse_header_value(r#""a\", "b,c""#).unwrap();
+        let tags: Vec<_> = parsed.tags().map(|tag| tag.tag()).collect();
+        assert_eq!(tags, [r"a\", "b,c"]);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{FromHeaderValue, UriReference};
+
+/// The `Location` type is the value of a `Location` header, where a
+/// redirect points to or where a created resource lives.
+///
+/// A relative reference is relative to the URI of the request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Location<'a>(pub UriReference<'a>);
+
+impl<'de> FromHeaderValue<'de> for Location<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        UriReference::parse_header_value(slice).map(Location)
+    }
+}
+
+/// The `ContentLocation` type is the value of a `Content-Location` header,
+/// the URI of the representation sent in the message.
+///
+/// A relative reference is relative to the URI of the request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ContentLocation<'a>(pub UriReference<'a>);
+
+impl<'de> FromHeaderValue<'de> for ContentLocation<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        UriReference::parse_header_value(slice).map(ContentLocation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("https://example.com/new", Some("https://example.com/new"))]
+    #[case(" /items/42 ", Some("/items/42"))]
+    #[case("/a b", None)]
+    fn location_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        let location = Location::parse_header_value(input);
+        assert_eq!(expected, location.map(|location| location.0.as_str()));
+        let content_location = ContentLocation::parse_header_value(input);
+        assert_eq!(
+            expected,
+            content_location.map(|location| location.0.as_str())
+        );
+    }
+}
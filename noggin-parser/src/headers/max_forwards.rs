@@ -0,0 +1,78 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::{FromHeaderValue, Method};
+
+/// The `MaxForwards` type is the value of a `Max-Forwards` header, how many
+/// more times a `TRACE` or `OPTIONS` request may be forwarded.
+///
+/// A value too large for a `u32` is capped to `u32::MAX`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct MaxForwards(pub u32);
+
+impl<'de> FromHeaderValue<'de> for MaxForwards {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let hops = parse_digits(value).map_or(u32::MAX, |hops| hops.min(u32::MAX.into()) as u32);
+        Some(MaxForwards(hops))
+    }
+}
+
+/// What a proxy should do with a request, as decided by [`forwarding`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Forwarding {
+    /// Respond as the final recipient instead of forwarding the request.
+    Respond,
+    /// Forward the request, with this `Max-Forwards` header if any.
+    Forward(Option<MaxForwards>),
+}
+
+/// Decides whether a proxy forwards a request with `method` and the
+/// `Max-Forwards` header `max_forwards`.
+///
+/// Only `TRACE` and `OPTIONS` honor the header: at zero the proxy responds
+/// itself, otherwise it forwards the request with the value decremented.
+/// Any other request is forwarded with the header unchanged.
+pub fn forwarding(method: Method<'_>, max_forwards: Option<MaxForwards>) -> Forwarding {
+    if !matches!(method, Method::Trace | Method::Options) {
+        return Forwarding::Forward(max_forwards);
+    }
+    match max_forwards {
+        Some(MaxForwards(0)) => Forwarding::Respond,
+        Some(MaxForwards(hops)) => Forwarding::Forward(Some(MaxForwards(hops - 1))),
+        None => Forwarding::Forward(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0", Some(0))]
+    #[case(" 10 ", Some(10))]
+    #[case("4294967296", Some(u32::MAX))]
+    #[case("99999999999999999999999", Some(u32::MAX))]
+    #[case("-1", None)]
+    #[case("", None)]
+    fn max_forwards_test(#[case] input: &str, #[case] expected: Option<u32>) {
+        let parsed = MaxForwards::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|max_forwards| max_forwards.0));
+    }
+
+    #[rstest]
+    #[case(Method::Trace, Some(0), Forwarding::Respond)]
+    #[case(Method::Options, Some(0), Forwarding::Respond)]
+    #[case(Method::Trace, Some(5), Forwarding::Forward(Some(MaxForwards(4))))]
+    #[case(Method::Options, None, Forwarding::Forward(None))]
+    #[case(Method::Get, Some(0), Forwarding::Forward(Some(MaxForwards(0))))]
+    fn forwarding_test(
+        #[case] method: Method,
+        #[case] max_forwards: Option<u32>,
+        #[case] expected: Forwarding,
+    ) {
+        assert_eq!(expected, forwarding(method, max_forwards.map(MaxForwards)));
+    }
+}
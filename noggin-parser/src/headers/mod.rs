@@ -0,0 +1,177 @@
+//! Typed values of common headers, for fields that need more than a string.
+
+mod accept;
+mod accept_charset;
+mod accept_encoding;
+mod accept_language;
+mod accept_ranges;
+mod age;
+mod allow;
+mod alt_svc;
+mod authorization;
+mod cache_control;
+mod cache_status;
+mod challenge;
+mod clear_site_data;
+mod client_hints;
+mod conditional;
+mod connection;
+mod content_disposition;
+mod content_language;
+mod content_range;
+mod content_type;
+mod cookie;
+mod csp;
+mod deprecation;
+mod digest;
+mod entity_tag;
+mod expect;
+mod expires;
+mod fetch_metadata;
+mod forwarded;
+mod host;
+mod idempotency_key;
+mod if_match;
+mod location;
+mod max_forwards;
+mod prefer;
+mod priority;
+mod proxy_status;
+mod range;
+mod rate_limit;
+mod reporting;
+mod retry_after;
+mod robots;
+mod security;
+mod server_timing;
+mod set_cookie;
+mod trace_context;
+mod transfer_encoding;
+mod upgrade;
+#[cfg(feature = "alloc")]
+mod vary;
+mod via;
+#[cfg(feature = "sha1")]
+mod websocket;
+mod x_forwarded;
+
+pub use accept::Accept;
+pub use accept_charset::AcceptCharset;
+pub use accept_encoding::{AcceptEncoding, ContentCoding};
+pub use accept_language::AcceptLanguage;
+pub use accept_ranges::AcceptRanges;
+pub use age::Age;
+pub use allow::Allow;
+pub use alt_svc::{AltService, AltSvc};
+pub use authorization::{AuthParams, Authorization};
+pub use cache_control::CacheControl;
+pub use cache_status::{CacheForward, CacheStatus, CacheStatusEntry};
+pub use challenge::{Challenge, ProxyAuthenticate, ProxyAuthorization, WwwAuthenticate};
+pub use clear_site_data::{ClearSiteData, SiteData};
+pub use client_hints::{AcceptCh, Brand, SecChUa, SecChUaMobile, SecChUaPlatform};
+pub use conditional::{Conditions, IfRange, Precondition};
+pub use connection::{should_keep_alive, Connection, KeepAlive};
+pub use content_disposition::{ContentDisposition, DispositionType};
+pub use content_language::ContentLanguage;
+pub use content_range::ContentRange;
+pub use content_type::ContentType;
+pub use cookie::Cookies;
+pub use csp::Csp;
+pub use deprecation::{Deprecation, Sunset};
+pub use digest::{ContentDigest, ReprDigest};
+pub use entity_tag::EntityTag;
+pub use expect::{should_send_continue, Expect};
+pub use expires::Expires;
+pub use fetch_metadata::{
+    resource_isolation_allows, SecFetchDest, SecFetchMode, SecFetchSite, SecFetchUser,
+};
+pub use forwarded::{Forwarded, ForwardedElement, Node, NodeName, NodePort};
+pub use host::Host;
+pub use idempotency_key::{IdempotencyKey, IdempotencyKeyFormat};
+pub use if_match::{IfMatch, IfNoneMatch};
+pub use location::{ContentLocation, Location};
+pub use max_forwards::{forwarding, Forwarding, MaxForwards};
+pub use prefer::{Prefer, Preference, PreferenceApplied, Return};
+pub use priority::Priority;
+pub use proxy_status::{ProxyStatus, ProxyStatusEntry};
+pub use range::{ByteRangeSpec, Range};
+pub use rate_limit::{QuotaPolicy, RateLimit, RateLimitItem, RateLimitPolicy};
+pub use reporting::ReportingEndpoints;
+#[cfg(feature = "serde_json")]
+pub use reporting::{EndpointGroup, Nel, ReportTo, ReportingEndpoint};
+pub use retry_after::RetryAfter;
+pub use robots::{RobotsDirective, XRobotsTag};
+pub use security::{
+    is_cross_origin_isolated, CrossOriginEmbedderPolicy, CrossOriginOpenerPolicy,
+    CrossOriginResourcePolicy, XContentTypeOptions, XFrameOptions,
+};
+pub use server_timing::{ServerTiming, ServerTimingMetric};
+pub use set_cookie::{SameSite, SetCookie};
+pub use trace_context::{Traceparent, Tracestate};
+pub use transfer_encoding::{Te, TransferCoding, TransferEncoding};
+pub use upgrade::{Protocol, Upgrade};
+#[cfg(feature = "alloc")]
+pub use vary::Vary;
+pub use via::{Via, ViaHop};
+#[cfg(feature = "sha1")]
+pub use websocket::WebSocketAccept;
+pub use x_forwarded::{XForwardedFor, XForwardedHost, XForwardedProto};
+
+use crate::from_header_value::{split_params, trim, SplitList};
+use crate::token::is_token;
+
+/// The `Params` iterator yields the `name=value` parameters following a
+/// header value, such as the `charset` of a `Content-Type`.
+///
+/// Quoted values are returned without their DQUOTEs, quoted-pairs inside
+/// them are left as they are.
+#[derive(Clone)]
+pub struct Params<'a>(SplitList<'a>);
+
+impl<'a> Params<'a> {
+    /// Creates an iterator over the already validated parameters.
+    fn new(params: &'a str) -> Self {
+        Params(split_params(params))
+    }
+}
+
+impl<'a> Iterator for Params<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(split_param)
+    }
+}
+
+/// Splits a single `name=value` parameter, unquoting the value. Empty
+/// parameters and malformed ones yield `None`.
+fn split_param(param: &str) -> Option<(&str, &str)> {
+    match split_directive(param)? {
+        (name, Some(value)) => Some((name, value)),
+        (_, None) => None,
+    }
+}
+
+/// Splits a directive that is either a bare `name` or a `name=value`
+/// parameter, unquoting the value. Empty directives and malformed ones
+/// yield `None`.
+fn split_directive(directive: &str) -> Option<(&str, Option<&str>)> {
+    let Some((name, value)) = directive.split_once('=') else {
+        let name = trim(directive);
+        return is_token(name).then_some((name, None));
+    };
+    let name = trim(name);
+    let value = trim(value);
+    let value = match value.strip_prefix('"') {
+        Some(quoted) => quoted.strip_suffix('"')?,
+        None if is_token(value) => value,
+        None => return None,
+    };
+    is_token(name).then_some((name, Some(value)))
+}
+
+/// Returns `true` if every parameter is well formed, allowing empty
+/// parameters such as a trailing `;`.
+fn are_params_valid(params: &str) -> bool {
+    split_params(params).all(|param| trim(param).is_empty() || split_param(param).is_some())
+}
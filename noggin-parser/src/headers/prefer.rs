@@ -0,0 +1,261 @@
+use super::{are_params_valid, split_directive, Params};
+use crate::from_header_value::{parse_digits, split_list, split_params, trim};
+use crate::FromHeaderValue;
+use core::time::Duration;
+
+/// The `return` preference of a [`Prefer`] header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Return {
+    /// `return=minimal`, only a status and the headers are wanted
+    Minimal,
+    /// `return=representation`, the current representation is wanted
+    Representation,
+}
+
+/// A single preference of a [`Prefer`] or [`PreferenceApplied`] header,
+/// such as `return=minimal` or `respond-async`, and its parameters.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Preference<'a> {
+    name: &'a str,
+    value: Option<&'a str>,
+    params: &'a str,
+}
+
+impl<'a> Preference<'a> {
+    /// Returns the name of the preference.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the unquoted value of the preference, if it has one.
+    pub fn value(&self) -> Option<&'a str> {
+        self.value
+    }
+
+    /// Returns an iterator over the parameters of the preference.
+    pub fn params(&self) -> Params<'a> {
+        Params::new(self.params)
+    }
+
+    /// Returns `true` if the preference is called `name`, compared
+    /// case-insensitively.
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Preference<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let preference = split_params(slice).next()?;
+        let params = &slice[preference.len()..];
+        let (name, value) = split_directive(preference)?;
+        are_params_valid(params).then_some(Preference {
+            name,
+            value,
+            params,
+        })
+    }
+}
+
+/// Parses the non-empty elements of a preference list.
+fn parse_preferences(slice: &str) -> impl Iterator<Item = Option<Preference<'_>>> {
+    split_list(slice)
+        .filter(|element| !trim(element).is_empty())
+        .map(Preference::parse_header_value)
+}
+
+/// Returns the first preference called `name`, the only one that counts.
+fn find_preference<'a>(
+    mut preferences: impl Iterator<Item = Preference<'a>>,
+    name: &str,
+) -> Option<Preference<'a>> {
+    preferences.find(|preference| preference.is(name))
+}
+
+/// The `Prefer` type is the value of a `Prefer` header (RFC 7240), the
+/// optional behaviors a client asks of the server such as
+/// `respond-async, wait=10, return=minimal`.
+///
+/// When a preference is given more than once only the first one is
+/// considered, as the specification requires.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Prefer<'a>(&'a str);
+
+impl<'a> Prefer<'a> {
+    /// Returns an iterator over the preferences, in order.
+    pub fn preferences(&self) -> impl Iterator<Item = Preference<'a>> {
+        parse_preferences(self.0).flatten()
+    }
+
+    /// Returns the preference called `name`, compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<Preference<'a>> {
+        find_preference(self.preferences(), name)
+    }
+
+    /// Returns the `return` preference, ignoring unknown values.
+    pub fn return_preference(&self) -> Option<Return> {
+        let value = self.get("return")?.value()?;
+        if value.eq_ignore_ascii_case("minimal") {
+            Some(Return::Minimal)
+        } else if value.eq_ignore_ascii_case("representation") {
+            Some(Return::Representation)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `wait` preference, how long the client is willing to
+    /// wait for a synchronous response.
+    pub fn wait(&self) -> Option<Duration> {
+        let wait = parse_digits(self.get("wait")?.value()?)?;
+        Some(Duration::from_secs(wait))
+    }
+
+    /// Returns `true` if the client prefers an asynchronous
+    /// `202 Accepted` response.
+    pub fn respond_async(&self) -> bool {
+        self.get("respond-async").is_some()
+    }
+
+    /// Returns the `handling` preference, `strict` or `lenient`.
+    pub fn handling(&self) -> Option<&'a str> {
+        self.get("handling")?.value()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Prefer<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut count = 0;
+        let valid = parse_preferences(slice).all(|preference| {
+            count += 1;
+            preference.is_some()
+        });
+        (valid && count > 0).then_some(Prefer(slice))
+    }
+}
+
+/// The `PreferenceApplied` type is the value of a `Preference-Applied`
+/// response header, the preferences of a [`Prefer`] header the server
+/// honored such as `return=minimal`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PreferenceApplied<'a>(&'a str);
+
+impl<'a> PreferenceApplied<'a> {
+    /// Returns an iterator over the applied preferences, in order.
+    pub fn preferences(&self) -> impl Iterator<Item = Preference<'a>> {
+        parse_preferences(self.0).flatten()
+    }
+
+    /// Returns the applied preference called `name`, compared
+    /// case-insensitively.
+    pub fn get(&self, name: &str) -> Option<Preference<'a>> {
+        find_preference(self.preferences(), name)
+    }
+
+    /// Returns `true` if the server applied `preference`, with the same
+    /// value. Names and values are compared case-insensitively.
+    pub fn applies(&self, preference: &Preference<'_>) -> bool {
+        self.get(preference.name)
+            .is_some_and(|applied| match (applied.value, preference.value) {
+                (Some(applied), Some(requested)) => applied.eq_ignore_ascii_case(requested),
+                (applied, requested) => applied.is_none() && requested.is_none(),
+            })
+    }
+
+    /// Returns an iterator over the preferences of `prefer` that the server
+    /// didn't apply.
+    pub fn ignored(&self, prefer: &Prefer<'a>) -> impl Iterator<Item = Preference<'a>> {
+        let applied = *self;
+        prefer
+            .preferences()
+            .filter(move |preference| !applied.applies(preference))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for PreferenceApplied<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut count = 0;
+        let valid = parse_preferences(slice).all(|preference| {
+            count += 1;
+            preference.is_some_and(|preference| preference.params.is_empty())
+        });
+        (valid && count > 0).then_some(PreferenceApplied(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Pref<'a> = (&'a str, Option<&'a str>, Vec<(&'a str, &'a str)>);
+
+    #[rstest]
+    #[case("respond-async", Some(vec![("respond-async", None, vec![])]))]
+    #[case(
+        "return=minimal; foo=\"some, parameter\", wait = 10",
+        Some(vec![("return", Some("minimal"), vec![("foo", "some, parameter")]), ("wait", Some("10"), vec![])])
+    )]
+    #[case("foo=\"bar baz\";;, , priority=5", Some(vec![("foo", Some("bar baz"), vec![]), ("priority", Some("5"), vec![])]))]
+    #[case("", None)]
+    #[case("wait=", None)]
+    #[case("re turn=minimal", None)]
+    #[case("return=minimal; =x", None)]
+    fn prefer_test(#[case] input: &str, #[case] expected: Option<Vec<Pref>>) {
+        let parsed = Prefer::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|prefer| prefer
+                .preferences()
+                .map(|p| (p.name(), p.value(), p.params().collect()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn prefer_well_known() {
+        let prefer = Prefer::parse_header_value(
+            "Respond-Async, wait=100, return=minimal, wait=5, handling=lenient",
+        )
+        .unwrap();
+        assert!(prefer.respond_async());
+        assert_eq!(prefer.wait(), Some(Duration::from_secs(100)));
+        assert_eq!(prefer.return_preference(), Some(Return::Minimal));
+        assert_eq!(prefer.handling(), Some("lenient"));
+
+        let prefer = Prefer::parse_header_value("return=everything, wait=soon").unwrap();
+        assert!(!prefer.respond_async());
+        assert_eq!(prefer.wait(), None);
+        assert_eq!(prefer.return_preference(), None);
+    }
+
+    #[rstest]
+    #[case("return=minimal", Some(vec![("return", Some("minimal"))]))]
+    #[case("respond-async, wait=\"10\"", Some(vec![("respond-async", None), ("wait", Some("10"))]))]
+    #[case("return=minimal; foo=bar", None)]
+    #[case("", None)]
+    fn preference_applied_test(
+        #[case] input: &str,
+        #[case] expected: Option<Vec<(&str, Option<&str>)>>,
+    ) {
+        let parsed = PreferenceApplied::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|applied| applied
+                .preferences()
+                .map(|p| (p.name(), p.value()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn preference_applied_matching() {
+        let prefer = Prefer::parse_header_value("return=minimal, respond-async, wait=10").unwrap();
+        let applied = PreferenceApplied::parse_header_value("Return=MINIMAL, wait=5").unwrap();
+        assert!(applied.applies(&prefer.get("return").unwrap()));
+        assert!(!applied.applies(&prefer.get("wait").unwrap()));
+        assert!(!applied.applies(&prefer.get("respond-async").unwrap()));
+        let ignored: Vec<_> = applied.ignored(&prefer).map(|p| p.name()).collect();
+        assert_eq!(ignored, vec!["respond-async", "wait"]);
+    }
+}
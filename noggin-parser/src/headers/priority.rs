@@ -0,0 +1,66 @@
+use crate::structured::{BareItem, Dictionary};
+use crate::FromHeaderValue;
+
+/// The `Priority` type is the value of a `Priority` header (RFC 9218), a
+/// structured dictionary such as `u=1, i`.
+///
+/// Missing parameters, and parameters that are out of range or of the wrong
+/// type, fall back to the defaults of urgency 3, not incremental. Unknown
+/// parameters are ignored.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Priority {
+    /// The urgency, from 0 (most urgent) to 7.
+    pub urgency: u8,
+    /// Whether the response can be processed incrementally.
+    pub incremental: bool,
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Priority {
+            urgency: 3,
+            incremental: false,
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Priority {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let dictionary = Dictionary::parse_header_value(slice)?;
+        let bare = |key| dictionary.get(key)?.as_item().map(|item| item.bare());
+        let mut priority = Priority::default();
+        if let Some(BareItem::Integer(urgency @ 0..=7)) = bare("u") {
+            priority.urgency = urgency as u8;
+        }
+        if let Some(BareItem::Boolean(incremental)) = bare("i") {
+            priority.incremental = incremental;
+        }
+        Some(priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("u=1, i", Some((1, true)))]
+    #[case("u=0", Some((0, false)))]
+    #[case("i=?0, u=7", Some((7, false)))]
+    #[case("", Some((3, false)))]
+    #[case("u=8", Some((3, false)))]
+    #[case("u=-1", Some((3, false)))]
+    #[case("u=1.0, i=1", Some((3, false)))]
+    #[case("u=2, u=5", Some((5, false)))]
+    #[case("u=(1 2), foo=bar", Some((3, false)))]
+    #[case("U=1", None)]
+    #[case("u=1,", None)]
+    fn priority_test(#[case] input: &str, #[case] expected: Option<(u8, bool)>) {
+        let parsed = Priority::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|priority| (priority.urgency, priority.incremental))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use super::cache_status::{member_name, text_param};
+use crate::structured::{List, Parameters};
+use crate::FromHeaderValue;
+
+/// How a single intermediary handled a response, a member of a
+/// `Proxy-Status` header such as
+/// `SomeReverseProxy; error=http_protocol_error; details="Malformed response header"`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ProxyStatusEntry<'a> {
+    name: &'a str,
+    params: Parameters<'a>,
+}
+
+impl<'a> ProxyStatusEntry<'a> {
+    /// Returns the name of the intermediary.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns all the parameters, including the ones specific to an error
+    /// type such as `rcode` or `info-code`.
+    pub fn params(&self) -> Parameters<'a> {
+        self.params
+    }
+
+    /// Returns the error type, such as `dns_timeout` or
+    /// `connection_refused`.
+    pub fn error(&self) -> Option<&'a str> {
+        self.params.get("error")?.as_token()
+    }
+
+    /// Returns the hop the request was forwarded to, or that the
+    /// intermediary tried to forward it to.
+    pub fn next_hop(&self) -> Option<&'a str> {
+        text_param(&self.params, "next-hop")
+    }
+
+    /// Returns the ALPN protocol used to reach the next hop, such as `h2`,
+    /// given as a token. Protocols sent as a byte sequence aren't decoded.
+    pub fn next_protocol(&self) -> Option<&'a str> {
+        self.params.get("next-protocol")?.as_token()
+    }
+
+    /// Returns the status of the response received from the next hop.
+    pub fn received_status(&self) -> Option<u16> {
+        self.params
+            .get("received-status")?
+            .as_integer()?
+            .try_into()
+            .ok()
+    }
+
+    /// Returns additional details about the error, with its escapes kept.
+    pub fn details(&self) -> Option<&'a str> {
+        self.params.get("details")?.as_string()
+    }
+}
+
+/// The `ProxyStatus` type is the value of a `Proxy-Status` header
+/// (RFC 9209), how each intermediary on the path handled a response, such
+/// as `ExampleCDN, CacheHost; error=dns_timeout`.
+///
+/// Entries are ordered from the origin towards the user.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ProxyStatus<'a>(List<'a>);
+
+impl<'a> ProxyStatus<'a> {
+    /// Returns an iterator over the entries, from the origin side to the
+    /// user side.
+    pub fn entries(&self) -> impl Iterator<Item = ProxyStatusEntry<'a>> {
+        self.0
+            .members()
+            .filter_map(member_name)
+            .map(|(name, params)| ProxyStatusEntry { name, params })
+    }
+
+    /// Returns the entry of the intermediary called `name`.
+    pub fn get(&self, name: &str) -> Option<ProxyStatusEntry<'a>> {
+        self.entries().find(|entry| entry.name == name)
+    }
+
+    /// Returns the first entry reporting an error.
+    pub fn error(&self) -> Option<ProxyStatusEntry<'a>> {
+        self.entries().find(|entry| entry.error().is_some())
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ProxyStatus<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let list = List::parse_header_value(slice)?;
+        let mut count = 0;
+        let valid = list.members().all(|member| {
+            count += 1;
+            member_name(member).is_some()
+        });
+        (valid && count > 0).then_some(ProxyStatus(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("ExampleCDN", Some(vec![("ExampleCDN", None)]))]
+    #[case(
+        "ExampleCDN, \"Cache Host\"; error=dns_timeout",
+        Some(vec![("ExampleCDN", None), ("Cache Host", Some("dns_timeout"))])
+    )]
+    #[case("ExampleCDN; error=\"dns_timeout\"", Some(vec![("ExampleCDN", None)]))]
+    #[case("", None)]
+    #[case("?1", None)]
+    fn proxy_status_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, Option<&str>)>>) {
+        let parsed = ProxyStatus::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|status| status.entries().map(|e| (e.name(), e.error())).collect())
+        );
+    }
+
+    #[test]
+    fn proxy_status_details() {
+        let status = ProxyStatus::parse_header_value(
+            "ExampleCDN; next-hop=origin.example; next-protocol=h2; received-status=503, \
+             SomeReverseProxy; error=http_protocol_error; details=\"Malformed response header: space before colon\"",
+        )
+        .unwrap();
+        let cdn = status.get("ExampleCDN").unwrap();
+        assert_eq!(cdn.next_hop(), Some("origin.example"));
+        assert_eq!(cdn.next_protocol(), Some("h2"));
+        assert_eq!(cdn.received_status(), Some(503));
+        let error = status.error().unwrap();
+        assert_eq!(error.name(), "SomeReverseProxy");
+        assert_eq!(error.error(), Some("http_protocol_error"));
+        assert_eq!(
+            error.details(),
+            Some("Malformed response header: space before colon")
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::from_header_value::{parse_digits, split_list, trim};
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A single byte range of a [`Range`] header, with inclusive positions.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ByteRangeSpec {
+    /// `first-last`
+    Bounded(u64, u64),
+    /// `first-`, from an offset to the end
+    From(u64),
+    /// `-length`, the last `length` bytes
+    Suffix(u64),
+}
+
+impl ByteRangeSpec {
+    /// Resolves the range against a representation of `len` bytes into
+    /// inclusive `(first, last)` positions, clamping the end. Returns `None`
+    /// if the range is unsatisfiable.
+    pub fn resolve(&self, len: u64) -> Option<(u64, u64)> {
+        let last = len.checked_sub(1)?;
+        match *self {
+            ByteRangeSpec::Bounded(first, end) if first <= last => Some((first, end.min(last))),
+            ByteRangeSpec::From(first) if first <= last => Some((first, last)),
+            ByteRangeSpec::Suffix(length) if length > 0 => Some((len.saturating_sub(length), last)),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ByteRangeSpec {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (first, last) = trim(slice).split_once('-')?;
+        match (first, last) {
+            ("", length) => parse_digits(length).map(ByteRangeSpec::Suffix),
+            (first, "") => parse_digits(first).map(ByteRangeSpec::From),
+            (first, last) => {
+                let (first, last) = (parse_digits(first)?, parse_digits(last)?);
+                (first <= last).then_some(ByteRangeSpec::Bounded(first, last))
+            }
+        }
+    }
+}
+
+/// The `Range` type is the value of a `Range` header, such as
+/// `bytes=0-499, -500, 9500-`.
+///
+/// Only the `bytes` unit is supported. The ranges are validated when the
+/// header is parsed and borrowed from the header on iteration.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Range<'a>(&'a str);
+
+impl<'a> Range<'a> {
+    /// Returns an iterator over the requested byte ranges, in the order
+    /// they were sent in.
+    pub fn specs(&self) -> impl Iterator<Item = ByteRangeSpec> + 'a {
+        split_list(self.0)
+            .filter(|spec| !trim(spec).is_empty())
+            .filter_map(ByteRangeSpec::parse_header_value)
+    }
+
+    /// Resolves every range against a representation of `len` bytes into
+    /// inclusive `(first, last)` positions, dropping the unsatisfiable
+    /// ones. An empty result calls for `416 Range Not Satisfiable`.
+    #[cfg(feature = "alloc")]
+    pub fn resolve(&self, len: u64) -> Vec<(u64, u64)> {
+        self.specs().filter_map(|spec| spec.resolve(len)).collect()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Range<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (unit, specs) = trim(slice).split_once('=')?;
+        if !unit.eq_ignore_ascii_case("bytes") {
+            return None;
+        }
+        let mut count = 0;
+        for spec in split_list(specs).filter(|spec| !trim(spec).is_empty()) {
+            ByteRangeSpec::parse_header_value(spec)?;
+            count += 1;
+        }
+        (count > 0).then_some(Range(specs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use ByteRangeSpec::*;
+
+    #[rstest]
+    #[case("bytes=0-499", Some(vec![Bounded(0, 499)]))]
+    #[case(" bytes=0-499, -500, 9500- ", Some(vec![Bounded(0, 499), Suffix(500), From(9500)]))]
+    #[case("Bytes=1-1,", Some(vec![Bounded(1, 1)]))]
+    #[case("bytes=", None)]
+    #[case("bytes=5-4", None)]
+    #[case("bytes=-", None)]
+    #[case("bytes=1-2-3", None)]
+    #[case("bytes=+1-2", None)]
+    #[case("items=0-5", None)]
+    #[case("0-5", None)]
+    fn range_test(#[case] input: &str, #[case] expected: Option<Vec<ByteRangeSpec>>) {
+        let parsed = Range::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|range| range.specs().collect()));
+    }
+
+    #[rstest]
+    #[case("bytes=0-499", 10000, vec![(0, 499)])]
+    #[case("bytes=9500-20000", 10000, vec![(9500, 9999)])]
+    #[case("bytes=-500", 10000, vec![(9500, 9999)])]
+    #[case("bytes=-20000", 10000, vec![(0, 9999)])]
+    #[case("bytes=9500-", 10000, vec![(9500, 9999)])]
+    #[case("bytes=10000-, -0, 0-1", 10000, vec![(0, 1)])]
+    #[case("bytes=0-", 0, vec![])]
+    #[case("bytes=-5", 0, vec![])]
+    fn range_resolve(#[case] input: &str, #[case] len: u64, #[case] expected: Vec<(u64, u64)>) {
+        let parsed = Range::parse_header_value(input).unwrap();
+        assert_eq!(expected, parsed.resolve(len));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::structured::{BareItem, List, ListMember, Parameters};
+use crate::FromHeaderValue;
+use core::time::Duration;
+
+/// Returns the name and parameters of a member of a rate limit list, a
+/// string item such as `"default";r=50`.
+fn split_item(member: ListMember<'_>) -> Option<(&str, Parameters<'_>)> {
+    let item = member.as_item()?;
+    Some((item.bare().as_string()?, item.params()))
+}
+
+fn non_negative(params: &Parameters<'_>, key: &str) -> Option<Option<u64>> {
+    match params.get(key) {
+        None => Some(None),
+        Some(BareItem::Integer(value)) => Some(Some(value.try_into().ok()?)),
+        Some(_) => None,
+    }
+}
+
+/// A single policy of a `RateLimit-Policy` header, such as
+/// `"burst";q=100;w=60`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct QuotaPolicy<'a> {
+    name: &'a str,
+    limit: u64,
+    quota_units: Option<&'a str>,
+    window: Option<u64>,
+    partition_key: Option<&'a str>,
+}
+
+impl<'a> QuotaPolicy<'a> {
+    /// Returns the name of the policy, with its escapes kept.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the `q` parameter, the quota allocated per window.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Returns the `qu` parameter, what the quota counts such as
+    /// `requests`, the default, or `content-bytes`.
+    pub fn quota_units(&self) -> &'a str {
+        self.quota_units.unwrap_or("requests")
+    }
+
+    /// Returns the `w` parameter, the length of the window.
+    pub fn window(&self) -> Option<Duration> {
+        self.window.map(Duration::from_secs)
+    }
+
+    /// Returns the `pk` parameter, the still base64 encoded key of the
+    /// partition the quota applies to.
+    pub fn partition_key(&self) -> Option<&'a str> {
+        self.partition_key
+    }
+
+    fn from_member(member: ListMember<'a>) -> Option<Self> {
+        let (name, params) = split_item(member)?;
+        let quota_units = match params.get("qu") {
+            None => None,
+            Some(units) => Some(units.as_token()?),
+        };
+        let partition_key = match params.get("pk") {
+            None => None,
+            Some(key) => Some(key.as_byte_sequence()?),
+        };
+        Some(QuotaPolicy {
+            name,
+            limit: non_negative(&params, "q")??,
+            quota_units,
+            window: non_negative(&params, "w")?,
+            partition_key,
+        })
+    }
+}
+
+/// The `RateLimitPolicy` type is the value of a `RateLimit-Policy` header,
+/// the quota policies a server enforces, such as
+/// `"default";q=100;w=10, "daily";q=1000;w=86400`.
+///
+/// Policies that lack a valid `q` parameter are skipped, as the
+/// specification requires.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RateLimitPolicy<'a>(List<'a>);
+
+impl<'a> RateLimitPolicy<'a> {
+    /// Returns an iterator over the valid policies, in order.
+    pub fn policies(&self) -> impl Iterator<Item = QuotaPolicy<'a>> {
+        self.0.members().filter_map(QuotaPolicy::from_member)
+    }
+
+    /// Returns the policy called `name`.
+    pub fn get(&self, name: &str) -> Option<QuotaPolicy<'a>> {
+        self.policies().find(|policy| policy.name == name)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for RateLimitPolicy<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        List::parse_header_value(slice).map(RateLimitPolicy)
+    }
+}
+
+/// The current state of a single policy of a `RateLimit` header, such as
+/// `"default";r=50;t=30`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RateLimitItem<'a> {
+    policy: &'a str,
+    remaining: u64,
+    reset: Option<u64>,
+    partition_key: Option<&'a str>,
+}
+
+impl<'a> RateLimitItem<'a> {
+    /// Returns the name of the policy the item reports on, with its escapes
+    /// kept.
+    pub fn policy(&self) -> &'a str {
+        self.policy
+    }
+
+    /// Returns the `r` parameter, the quota left in the current window.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the `t` parameter, the time until the quota resets.
+    pub fn reset(&self) -> Option<Duration> {
+        self.reset.map(Duration::from_secs)
+    }
+
+    /// Returns the `pk` parameter, the still base64 encoded key of the
+    /// partition the item reports on.
+    pub fn partition_key(&self) -> Option<&'a str> {
+        self.partition_key
+    }
+
+    /// Returns `true` if no quota is left, so the client should wait for
+    /// [`reset`](Self::reset) before trying again.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+
+    fn from_member(member: ListMember<'a>) -> Option<Self> {
+        let (policy, params) = split_item(member)?;
+        let partition_key = match params.get("pk") {
+            None => None,
+            Some(key) => Some(key.as_byte_sequence()?),
+        };
+        Some(RateLimitItem {
+            policy,
+            remaining: non_negative(&params, "r")??,
+            reset: non_negative(&params, "t")?,
+            partition_key,
+        })
+    }
+}
+
+/// The `RateLimit` type is the value of a `RateLimit` header, the quota
+/// left on the policies of a `RateLimit-Policy`, such as
+/// `"default";r=50;t=30`.
+///
+/// Items that lack a valid `r` parameter are skipped, as the specification
+/// requires.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RateLimit<'a>(List<'a>);
+
+impl<'a> RateLimit<'a> {
+    /// Returns an iterator over the valid items, in order.
+    pub fn items(&self) -> impl Iterator<Item = RateLimitItem<'a>> {
+        self.0.members().filter_map(RateLimitItem::from_member)
+    }
+
+    /// Returns the item reporting on the policy called `policy`.
+    pub fn get(&self, policy: &str) -> Option<RateLimitItem<'a>> {
+        self.items().find(|item| item.policy == policy)
+    }
+
+    /// Returns how long to back off before the next request: the longest
+    /// reset of the exhausted items, or `None` if every policy has quota
+    /// left. An exhausted item without a reset counts as zero.
+    pub fn backoff(&self) -> Option<Duration> {
+        self.items()
+            .filter(RateLimitItem::is_exhausted)
+            .map(|item| item.reset().unwrap_or_default())
+            .max()
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for RateLimit<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        List::parse_header_value(slice).map(RateLimit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Policy<'a> = (&'a str, u64, &'a str, Option<u64>, Option<&'a str>);
+    type Item<'a> = (&'a str, u64, Option<u64>);
+
+    #[rstest]
+    #[case("\"default\";q=100;w=10", Some(vec![("default", 100, "requests", Some(10), None)]))]
+    #[case(
+        "\"a\";q=1000;qu=\"bytes\", \"b\";q=5000;qu=content-bytes;pk=:YWJj:",
+        Some(vec![("b", 5000, "content-bytes", None, Some("YWJj"))])
+    )]
+    #[case("\"a\";w=10, \"b\";q=-1, c;q=1, \"d\";q=1.5, \"e\";q=0", Some(vec![("e", 0, "requests", None, None)]))]
+    #[case("", Some(vec![]))]
+    #[case("\"a\";q=1,", None)]
+    fn rate_limit_policy_test(#[case] input: &str, #[case] expected: Option<Vec<Policy>>) {
+        let parsed = RateLimitPolicy::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|policy| policy
+                .policies()
+                .map(|p| (
+                    p.name(),
+                    p.limit(),
+                    p.quota_units(),
+                    p.window().map(|w| w.as_secs()),
+                    p.partition_key()
+                ))
+                .collect())
+        );
+    }
+
+    #[rstest]
+    #[case("\"default\";r=50;t=30", Some(vec![("default", 50, Some(30))]))]
+    #[case("\"a\";r=0, \"b\";t=5, \"c\";r=7", Some(vec![("a", 0, None), ("c", 7, None)]))]
+    #[case("\"a\";r=?1", Some(vec![]))]
+    #[case("\"a\" \"b\"", None)]
+    fn rate_limit_test(#[case] input: &str, #[case] expected: Option<Vec<Item>>) {
+        let parsed = RateLimit::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|limit| limit
+                .items()
+                .map(|item| (
+                    item.policy(),
+                    item.remaining(),
+                    item.reset().map(|t| t.as_secs())
+                ))
+                .collect())
+        );
+    }
+
+    #[rstest]
+    #[case("\"a\";r=5;t=10", None)]
+    #[case("\"a\";r=0;t=10, \"b\";r=0;t=60, \"c\";r=3;t=120", Some(60))]
+    #[case("\"a\";r=0", Some(0))]
+    fn rate_limit_backoff(#[case] input: &str, #[case] expected: Option<u64>) {
+        let limit = RateLimit::parse_header_value(input).unwrap();
+        assert_eq!(expected.map(Duration::from_secs), limit.backoff());
+    }
+
+    #[test]
+    fn rate_limit_get() {
+        let limit =
+            RateLimit::parse_header_value("\"daily\";r=900;t=3600, \"burst\";r=0;t=2").unwrap();
+        assert!(limit.get("burst").unwrap().is_exhausted());
+        assert_eq!(limit.get("daily").unwrap().remaining(), 900);
+        assert_eq!(limit.get("hourly"), None);
+    }
+}
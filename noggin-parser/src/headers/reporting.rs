@@ -0,0 +1,297 @@
+use crate::structured::Dictionary;
+use crate::FromHeaderValue;
+#[cfg(feature = "serde_json")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "serde_json")]
+use serde_json::{Map, Value};
+
+/// The `ReportingEndpoints` type is the value of a `Reporting-Endpoints`
+/// header, the named URLs a user agent sends reports to, such as
+/// `default="https://example.com/reports", csp="/csp-reports"`.
+///
+/// Members whose value isn't a string are ignored, like a user agent does.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ReportingEndpoints<'a>(Dictionary<'a>);
+
+impl<'a> ReportingEndpoints<'a> {
+    /// Returns an iterator over the endpoint names and their URLs, with
+    /// their escapes kept.
+    pub fn endpoints(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.0.iter().filter_map(|(name, member)| {
+            let url = member.as_item()?.bare().as_string()?;
+            Some((name, url))
+        })
+    }
+
+    /// Returns the URL of the endpoint called `name`.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.endpoints()
+            .filter(|(endpoint, _)| *endpoint == name)
+            .last()
+            .map(|(_, url)| url)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ReportingEndpoints<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Dictionary::parse_header_value(slice).map(ReportingEndpoints)
+    }
+}
+
+/// Parses a header made of comma separated JSON objects.
+#[cfg(feature = "serde_json")]
+fn parse_json_objects(slice: &str) -> Option<Vec<Map<String, Value>>> {
+    let mut json = String::with_capacity(slice.len() + 2);
+    json.push('[');
+    json.push_str(slice);
+    json.push(']');
+    let Value::Array(values) = serde_json::from_str(&json).ok()? else {
+        return None;
+    };
+    values
+        .into_iter()
+        .map(|value| match value {
+            Value::Object(object) => Some(object),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An endpoint of an [`EndpointGroup`], where reports are delivered.
+#[cfg(feature = "serde_json")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ReportingEndpoint {
+    pub url: String,
+    /// Endpoints with a lower priority are tried first, defaults to 1.
+    pub priority: u64,
+    /// The share of reports among endpoints of the same priority,
+    /// defaults to 1.
+    pub weight: u64,
+}
+
+#[cfg(feature = "serde_json")]
+impl ReportingEndpoint {
+    fn from_json(object: &Map<String, Value>) -> Option<Self> {
+        Some(ReportingEndpoint {
+            url: object.get("url")?.as_str()?.into(),
+            priority: object.get("priority").map_or(Some(1), Value::as_u64)?,
+            weight: object.get("weight").map_or(Some(1), Value::as_u64)?,
+        })
+    }
+}
+
+/// A named group of endpoints declared by a `Report-To` header.
+#[cfg(feature = "serde_json")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct EndpointGroup {
+    /// The name of the group, defaults to `default`.
+    pub group: String,
+    /// How long the group is remembered, in seconds. Zero removes it.
+    pub max_age: u64,
+    pub include_subdomains: bool,
+    pub endpoints: Vec<ReportingEndpoint>,
+}
+
+#[cfg(feature = "serde_json")]
+impl EndpointGroup {
+    fn from_json(object: &Map<String, Value>) -> Option<Self> {
+        let group = object.get("group").map_or(Some("default"), Value::as_str)?;
+        let endpoints = object
+            .get("endpoints")?
+            .as_array()?
+            .iter()
+            .filter_map(Value::as_object)
+            .filter_map(ReportingEndpoint::from_json)
+            .collect();
+        Some(EndpointGroup {
+            group: group.into(),
+            max_age: object.get("max_age")?.as_u64()?,
+            include_subdomains: object
+                .get("include_subdomains")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            endpoints,
+        })
+    }
+}
+
+/// The `ReportTo` type is the value of the legacy `Report-To` header, a
+/// list of JSON endpoint groups such as
+/// `{"group":"nel","max_age":86400,"endpoints":[{"url":"https://example.com/nel"}]}`.
+///
+/// Invalid groups and endpoints are skipped, but there has to be at least
+/// one valid group.
+#[cfg(feature = "serde_json")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ReportTo(pub Vec<EndpointGroup>);
+
+#[cfg(feature = "serde_json")]
+impl ReportTo {
+    /// Returns the group called `name`.
+    pub fn get(&self, name: &str) -> Option<&EndpointGroup> {
+        self.0.iter().find(|group| group.group == name)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<'de> FromHeaderValue<'de> for ReportTo {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let groups: Vec<_> = parse_json_objects(slice)?
+            .iter()
+            .filter_map(EndpointGroup::from_json)
+            .collect();
+        (!groups.is_empty()).then_some(ReportTo(groups))
+    }
+}
+
+/// The `Nel` type is the value of a `NEL` header, a Network Error Logging
+/// policy such as `{"report_to":"nel","max_age":31536000}`.
+///
+/// Only the first policy of the header is used. Sampling fractions outside
+/// of `0.0..=1.0` fall back to their defaults.
+#[cfg(feature = "serde_json")]
+#[derive(Clone, PartialEq, Debug)]
+pub struct Nel {
+    /// The endpoint group reports go to, required unless `max_age` is zero.
+    pub report_to: Option<String>,
+    /// How long the policy is remembered, in seconds. Zero removes it.
+    pub max_age: u64,
+    pub include_subdomains: bool,
+    /// The share of successful requests reported, defaults to 0.
+    pub success_fraction: f64,
+    /// The share of failed requests reported, defaults to 1.
+    pub failure_fraction: f64,
+}
+
+#[cfg(feature = "serde_json")]
+impl<'de> FromHeaderValue<'de> for Nel {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let objects = parse_json_objects(slice)?;
+        let object = objects.first()?;
+        let max_age = object.get("max_age")?.as_u64()?;
+        let report_to = object
+            .get("report_to")
+            .and_then(Value::as_str)
+            .map(String::from);
+        if report_to.is_none() && max_age > 0 {
+            return None;
+        }
+        let fraction = |key, default| {
+            object
+                .get(key)
+                .and_then(Value::as_f64)
+                .filter(|fraction| (0.0..=1.0).contains(fraction))
+                .unwrap_or(default)
+        };
+        Some(Nel {
+            report_to,
+            max_age,
+            include_subdomains: object
+                .get("include_subdomains")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            success_fraction: fraction("success_fraction", 0.0),
+            failure_fraction: fraction("failure_fraction", 1.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        r#"default="https://example.com/reports", csp="/csp-reports""#,
+        Some(vec![("default", "https://example.com/reports"), ("csp", "/csp-reports")])
+    )]
+    #[case(r#"default="/a", bad=42, other=?1"#, Some(vec![("default", "/a")]))]
+    #[case("", Some(vec![]))]
+    #[case("default=", None)]
+    fn reporting_endpoints_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let parsed = ReportingEndpoints::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|endpoints| endpoints.endpoints().collect())
+        );
+    }
+
+    #[test]
+    fn reporting_endpoints_get() {
+        let endpoints =
+            ReportingEndpoints::parse_header_value(r#"a="/1", b="/2", a="/3""#).unwrap();
+        assert_eq!(endpoints.get("a"), Some("/3"));
+        assert_eq!(endpoints.get("b"), Some("/2"));
+        assert_eq!(endpoints.get("c"), None);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn report_to_test() {
+        let report_to = ReportTo::parse_header_value(
+            r#"{"group":"nel","max_age":86400,"include_subdomains":true,"endpoints":[{"url":"https://a.example/nel","priority":2},{"weight":3}]}, {"max_age":60,"endpoints":[{"url":"/r"}]}, {"group":"bad"}"#,
+        )
+        .unwrap();
+        assert_eq!(report_to.0.len(), 2);
+        let nel = report_to.get("nel").unwrap();
+        assert_eq!(nel.max_age, 86400);
+        assert!(nel.include_subdomains);
+        assert_eq!(
+            nel.endpoints,
+            vec![ReportingEndpoint {
+                url: "https://a.example/nel".into(),
+                priority: 2,
+                weight: 1,
+            }]
+        );
+        let default = report_to.get("default").unwrap();
+        assert_eq!(default.endpoints[0].url, "/r");
+        assert!(!default.include_subdomains);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[rstest]
+    #[case(r#"{"group":"bad"}"#)]
+    #[case(r#"{"max_age":60,"endpoints":[{"url":"/r"}]"#)]
+    #[case("[]")]
+    #[case("")]
+    fn report_to_invalid(#[case] input: &str) {
+        assert_eq!(ReportTo::parse_header_value(input), None);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[rstest]
+    #[case(
+        r#"{"report_to":"nel","max_age":31536000,"include_subdomains":true}"#,
+        Some((Some("nel"), 31536000, true, 0.0, 1.0))
+    )]
+    #[case(
+        r#"{"report_to":"nel","max_age":60,"success_fraction":0.25,"failure_fraction":2}"#,
+        Some((Some("nel"), 60, false, 0.25, 1.0))
+    )]
+    #[case(r#"{"max_age":0}"#, Some((None, 0, false, 0.0, 1.0)))]
+    #[case(
+        r#"{"report_to":"a","max_age":1}, {"report_to":"b","max_age":2}"#,
+        Some((Some("a"), 1, false, 0.0, 1.0))
+    )]
+    #[case(r#"{"max_age":60}"#, None)]
+    #[case(r#"{"report_to":"nel"}"#, None)]
+    #[case("nel", None)]
+    fn nel_test(
+        #[case] input: &str,
+        #[case] expected: Option<(Option<&str>, u64, bool, f64, f64)>,
+    ) {
+        let parsed = Nel::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.as_ref().map(|nel| (
+                nel.report_to.as_deref(),
+                nel.max_age,
+                nel.include_subdomains,
+                nel.success_fraction,
+                nel.failure_fraction
+            ))
+        );
+    }
+}
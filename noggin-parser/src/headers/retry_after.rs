@@ -0,0 +1,69 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::{FromHeaderValue, HttpDate};
+use core::time::Duration;
+
+/// The `RetryAfter` type is the value of a `Retry-After` header, either a
+/// number of seconds or an HTTP-date.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RetryAfter {
+    Delay(Duration),
+    Date(HttpDate),
+}
+
+impl RetryAfter {
+    /// Returns how long to wait from `now`, whichever form the server used.
+    /// A date in the past yields a zero duration.
+    ///
+    /// With the `std` feature, `now` can also be a `SystemTime`.
+    pub fn duration_from(&self, now: impl Into<HttpDate>) -> Duration {
+        match self {
+            RetryAfter::Delay(delay) => *delay,
+            RetryAfter::Date(date) => {
+                let seconds = date.unix_timestamp() - now.into().unix_timestamp();
+                Duration::from_secs(seconds.try_into().unwrap_or(0))
+            }
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for RetryAfter {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match parse_digits(trim(slice)) {
+            Some(seconds) => Some(RetryAfter::Delay(Duration::from_secs(seconds))),
+            None => HttpDate::parse_header_value(slice).map(RetryAfter::Date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const NOW: HttpDate = HttpDate::from_unix_timestamp(1445412480);
+
+    #[rstest]
+    #[case("120", Some(120))]
+    #[case(" 0 ", Some(0))]
+    #[case("Wed, 21 Oct 2015 07:30:00 GMT", Some(120))]
+    #[case("Wed, 21 Oct 2015 07:00:00 GMT", Some(0))]
+    #[case("-1", None)]
+    #[case("1.5", None)]
+    #[case("soon", None)]
+    #[case("", None)]
+    fn retry_after_test(#[case] input: &str, #[case] expected: Option<u64>) {
+        let parsed = RetryAfter::parse_header_value(input);
+        assert_eq!(
+            expected.map(Duration::from_secs),
+            parsed.map(|retry_after| retry_after.duration_from(NOW))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn retry_after_system_time() {
+        let retry_after = RetryAfter::parse_header_value("Wed, 21 Oct 2015 07:28:10 GMT").unwrap();
+        let now = std::time::SystemTime::from(NOW);
+        assert_eq!(retry_after.duration_from(now), Duration::from_secs(10));
+    }
+}
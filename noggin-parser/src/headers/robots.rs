@@ -0,0 +1,291 @@
+use crate::from_header_value::{parse_digits, split_list, trim};
+use crate::FromHeaderValue;
+
+/// A single indexing directive of an [`XRobotsTag`].
+///
+/// Names are matched case-insensitively. Unknown directives, and known
+/// ones with an invalid value, are kept as [`RobotsDirective::Other`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RobotsDirective<'a> {
+    /// `all`, no restrictions
+    All,
+    /// `noindex`
+    NoIndex,
+    /// `nofollow`
+    NoFollow,
+    /// `none`, both `noindex` and `nofollow`
+    None,
+    /// `nosnippet`
+    NoSnippet,
+    /// `noarchive`
+    NoArchive,
+    /// `notranslate`
+    NoTranslate,
+    /// `noimageindex`
+    NoImageIndex,
+    /// `indexifembedded`
+    IndexIfEmbedded,
+    /// `max-snippet:<n>`, the length of a text snippet, `-1` for no limit
+    MaxSnippet(i64),
+    /// `max-image-preview:<setting>`, such as `none`, `standard` or `large`
+    MaxImagePreview(&'a str),
+    /// `max-video-preview:<n>`, in seconds, `-1` for no limit
+    MaxVideoPreview(i64),
+    /// `unavailable_after:<date>`, with the date left unparsed
+    UnavailableAfter(&'a str),
+    /// Any other directive, with its value if any
+    Other(&'a str, Option<&'a str>),
+}
+
+impl<'a> RobotsDirective<'a> {
+    fn is_valued(name: &str) -> bool {
+        [
+            "max-snippet",
+            "max-image-preview",
+            "max-video-preview",
+            "unavailable_after",
+        ]
+        .iter()
+        .any(|valued| valued.eq_ignore_ascii_case(name))
+    }
+
+    fn is_unavailable_after(directive: &str) -> bool {
+        directive
+            .split_once(':')
+            .is_some_and(|(name, _)| trim(name).eq_ignore_ascii_case("unavailable_after"))
+    }
+
+    fn parse(directive: &'a str) -> Self {
+        let (name, value) = match directive.split_once(':') {
+            Some((name, value)) => (trim(name), Some(trim(value))),
+            None => (directive, None),
+        };
+        let is = |expected: &str| name.eq_ignore_ascii_case(expected);
+        let length = |value: &str| match value.strip_prefix('-') {
+            Some("1") => Some(-1),
+            Some(_) => None,
+            None => parse_digits(value)?.try_into().ok(),
+        };
+        let parsed = match value {
+            None if is("all") => Some(RobotsDirective::All),
+            None if is("noindex") => Some(RobotsDirective::NoIndex),
+            None if is("nofollow") => Some(RobotsDirective::NoFollow),
+            None if is("none") => Some(RobotsDirective::None),
+            None if is("nosnippet") => Some(RobotsDirective::NoSnippet),
+            None if is("noarchive") => Some(RobotsDirective::NoArchive),
+            None if is("notranslate") => Some(RobotsDirective::NoTranslate),
+            None if is("noimageindex") => Some(RobotsDirective::NoImageIndex),
+            None if is("indexifembedded") => Some(RobotsDirective::IndexIfEmbedded),
+            Some(value) if is("max-snippet") => length(value).map(RobotsDirective::MaxSnippet),
+            Some(value) if is("max-image-preview") && !value.is_empty() => {
+                Some(RobotsDirective::MaxImagePreview(value))
+            }
+            Some(value) if is("max-video-preview") => {
+                length(value).map(RobotsDirective::MaxVideoPreview)
+            }
+            Some(value) if is("unavailable_after") && !value.is_empty() => {
+                Some(RobotsDirective::UnavailableAfter(value))
+            }
+            _ => None,
+        };
+        parsed.unwrap_or(RobotsDirective::Other(name, value))
+    }
+}
+
+/// The `XRobotsTag` type is the value of an `X-Robots-Tag` header, how
+/// crawlers may index a response, such as `noindex, max-snippet:50`.
+///
+/// The directives may be scoped to a crawler with a leading user agent, as
+/// in `googlebot: noindex`. Since each header has its own scope, collect
+/// repeated `X-Robots-Tag` headers with `#[noggin(no_split)]`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct XRobotsTag<'a> {
+    user_agent: Option<&'a str>,
+    directives: &'a str,
+}
+
+impl<'a> XRobotsTag<'a> {
+    /// Returns the user agent the directives are scoped to, or `None` if
+    /// they apply to every crawler.
+    pub fn user_agent(&self) -> Option<&'a str> {
+        self.user_agent
+    }
+
+    /// Returns `true` if the directives apply to the crawler `user_agent`,
+    /// compared case-insensitively.
+    pub fn applies_to(&self, user_agent: &str) -> bool {
+        self.user_agent
+            .is_none_or(|scope| scope.eq_ignore_ascii_case(user_agent))
+    }
+
+    /// Returns an iterator over the directives.
+    ///
+    /// The date of `unavailable_after` may contain commas, as in
+    /// `unavailable_after: Fri, 25 Jun 2010 15:00:00 GMT`, so it extends up
+    /// to the next element that is a known directive.
+    pub fn directives(&self) -> impl Iterator<Item = RobotsDirective<'a>> {
+        let directives = self.directives;
+        let end_of =
+            |item: &str| item.as_ptr() as usize - directives.as_ptr() as usize + item.len();
+        let mut items = split_list(directives)
+            .map(trim)
+            .filter(|directive| !directive.is_empty())
+            .peekable();
+        core::iter::from_fn(move || {
+            let first = items.next()?;
+            if !RobotsDirective::is_unavailable_after(first) {
+                return Some(RobotsDirective::parse(first));
+            }
+            let start = end_of(first) - first.len();
+            let mut end = end_of(first);
+            while let Some(item) = items
+                .next_if(|item| matches!(RobotsDirective::parse(item), RobotsDirective::Other(..)))
+            {
+                end = end_of(item);
+            }
+            Some(RobotsDirective::parse(&directives[start..end]))
+        })
+    }
+
+    /// Returns `true` if the response must not be indexed, from `noindex`
+    /// or `none`.
+    pub fn is_noindex(&self) -> bool {
+        self.directives()
+            .any(|directive| matches!(directive, RobotsDirective::NoIndex | RobotsDirective::None))
+    }
+
+    /// Returns `true` if the links of the response must not be followed,
+    /// from `nofollow` or `none`.
+    pub fn is_nofollow(&self) -> bool {
+        self.directives()
+            .any(|directive| matches!(directive, RobotsDirective::NoFollow | RobotsDirective::None))
+    }
+
+    /// Returns the most restrictive `max-snippet` length.
+    pub fn max_snippet(&self) -> Option<i64> {
+        self.directives()
+            .filter_map(|directive| match directive {
+                RobotsDirective::MaxSnippet(length) => Some(length),
+                _ => None,
+            })
+            .min_by_key(|length| if *length < 0 { i64::MAX } else { *length })
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for XRobotsTag<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let first = split_list(value).next().map(trim).unwrap_or_default();
+        let (user_agent, directives) = match first.split_once(':') {
+            Some((name, _)) if !RobotsDirective::is_valued(trim(name)) => {
+                let (user_agent, directives) = value.split_once(':')?;
+                (Some(trim(user_agent)), directives)
+            }
+            _ => (None, value),
+        };
+        let tag = XRobotsTag {
+            user_agent,
+            directives,
+        };
+        let mut count = 0;
+        let valid = user_agent.is_none_or(|user_agent| !user_agent.is_empty())
+            && tag.directives().all(|directive| {
+                count += 1;
+                !matches!(directive, RobotsDirective::Other("", _))
+            });
+        (valid && count > 0).then_some(tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "noindex, nofollow",
+        Some((None, vec![RobotsDirective::NoIndex, RobotsDirective::NoFollow]))
+    )]
+    #[case(
+        "googlebot: NoIndex, max-snippet:50",
+        Some((Some("googlebot"), vec![RobotsDirective::NoIndex, RobotsDirective::MaxSnippet(50)]))
+    )]
+    #[case(
+        "max-snippet: -1, max-image-preview:large, max-video-preview:0",
+        Some((None, vec![
+            RobotsDirective::MaxSnippet(-1),
+            RobotsDirective::MaxImagePreview("large"),
+            RobotsDirective::MaxVideoPreview(0),
+        ]))
+    )]
+    #[case(
+        "unavailable_after: 2025-06-25T15:00:00Z",
+        Some((None, vec![RobotsDirective::UnavailableAfter("2025-06-25T15:00:00Z")]))
+    )]
+    #[case(
+        "unavailable_after: Fri, 25 Jun 2010 15:00:00 GMT, noindex",
+        Some((None, vec![
+            RobotsDirective::UnavailableAfter("Fri, 25 Jun 2010 15:00:00 GMT"),
+            RobotsDirective::NoIndex,
+        ]))
+    )]
+    #[case(
+        "googlebot: noarchive, Unavailable_After: Friday, 25-Jun-10 15:00:00 GMT",
+        Some((Some("googlebot"), vec![
+            RobotsDirective::NoArchive,
+            RobotsDirective::UnavailableAfter("Friday, 25-Jun-10 15:00:00 GMT"),
+        ]))
+    )]
+    #[case(
+        "otherbot: max-snippet:-5, noai",
+        Some((Some("otherbot"), vec![
+            RobotsDirective::Other("max-snippet", Some("-5")),
+            RobotsDirective::Other("noai", None),
+        ]))
+    )]
+    #[case("", None)]
+    #[case("googlebot:", None)]
+    #[case(": noindex", None)]
+    #[case("noindex, :50", None)]
+    fn x_robots_tag_test(
+        #[case] input: &str,
+        #[case] expected: Option<(Option<&str>, Vec<RobotsDirective>)>,
+    ) {
+        let parsed = XRobotsTag::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|tag| (tag.user_agent(), tag.directives().collect()))
+        );
+    }
+
+    #[rstest]
+    #[case("none", true, true)]
+    #[case("noindex", true, false)]
+    #[case("all, nofollow", false, true)]
+    #[case("nosnippet", false, false)]
+    fn x_robots_tag_flags(#[case] input: &str, #[case] noindex: bool, #[case] nofollow: bool) {
+        let tag = XRobotsTag::parse_header_value(input).unwrap();
+        assert_eq!(noindex, tag.is_noindex());
+        assert_eq!(nofollow, tag.is_nofollow());
+    }
+
+    #[rstest]
+    #[case("max-snippet:50, max-snippet:20", Some(20))]
+    #[case("max-snippet:-1, max-snippet:20", Some(20))]
+    #[case("max-snippet:-1", Some(-1))]
+    #[case("noindex", None)]
+    fn x_robots_tag_max_snippet(#[case] input: &str, #[case] expected: Option<i64>) {
+        let tag = XRobotsTag::parse_header_value(input).unwrap();
+        assert_eq!(expected, tag.max_snippet());
+    }
+
+    #[test]
+    fn x_robots_tag_applies_to() {
+        let scoped = XRobotsTag::parse_header_value("GoogleBot: noindex").unwrap();
+        assert!(scoped.applies_to("googlebot"));
+        assert!(!scoped.applies_to("bingbot"));
+        let global = XRobotsTag::parse_header_value("noindex").unwrap();
+        assert!(global.applies_to("bingbot"));
+    }
+}
@@ -0,0 +1,310 @@
+use crate::from_header_value::{split_list, trim};
+use crate::structured::Item;
+use crate::FromHeaderValue;
+
+/// The `XContentTypeOptions` type is the value of an
+/// `X-Content-Type-Options` header, which only defines `nosniff`.
+///
+/// Like a user agent, only the first value of the list is considered and
+/// it is matched case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum XContentTypeOptions {
+    /// `nosniff`, the `Content-Type` must not be second-guessed
+    NoSniff,
+}
+
+impl XContentTypeOptions {
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            XContentTypeOptions::NoSniff => "nosniff",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for XContentTypeOptions {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let first = split_list(slice).map(trim).next()?;
+        first
+            .eq_ignore_ascii_case("nosniff")
+            .then_some(XContentTypeOptions::NoSniff)
+    }
+}
+
+/// The `XFrameOptions` type is the value of an `X-Frame-Options` header,
+/// whether the response may be rendered in a frame.
+///
+/// Values are matched case-insensitively. A list is accepted as long as
+/// all its values agree, and the obsolete `ALLOW-FROM` is rejected.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum XFrameOptions {
+    /// `DENY`, never in a frame
+    Deny,
+    /// `SAMEORIGIN`, only in a frame of the same origin
+    SameOrigin,
+}
+
+impl XFrameOptions {
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            XFrameOptions::Deny => "DENY",
+            XFrameOptions::SameOrigin => "SAMEORIGIN",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("deny") {
+            Some(XFrameOptions::Deny)
+        } else if value.eq_ignore_ascii_case("sameorigin") {
+            Some(XFrameOptions::SameOrigin)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for XFrameOptions {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut values = split_list(slice).map(trim).map(XFrameOptions::parse);
+        let first = values.next()??;
+        values.all(|value| value == Some(first)).then_some(first)
+    }
+}
+
+/// The `CrossOriginOpenerPolicy` type is the value of a
+/// `Cross-Origin-Opener-Policy` header, whether a document shares its
+/// browsing context group with cross-origin documents.
+///
+/// The header is a structured field token, parameters such as `report-to`
+/// are ignored.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum CrossOriginOpenerPolicy {
+    /// `unsafe-none`, the default
+    #[default]
+    UnsafeNone,
+    /// `same-origin-allow-popups`, keeps references to the popups it opens
+    SameOriginAllowPopups,
+    /// `same-origin`, isolated from cross-origin documents
+    SameOrigin,
+    /// `noopener-allow-popups`, isolated even from same-origin openers
+    NoopenerAllowPopups,
+}
+
+impl CrossOriginOpenerPolicy {
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrossOriginOpenerPolicy::UnsafeNone => "unsafe-none",
+            CrossOriginOpenerPolicy::SameOriginAllowPopups => "same-origin-allow-popups",
+            CrossOriginOpenerPolicy::SameOrigin => "same-origin",
+            CrossOriginOpenerPolicy::NoopenerAllowPopups => "noopener-allow-popups",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for CrossOriginOpenerPolicy {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match Item::parse_header_value(slice)?.bare().as_token()? {
+            "unsafe-none" => Some(CrossOriginOpenerPolicy::UnsafeNone),
+            "same-origin-allow-popups" => Some(CrossOriginOpenerPolicy::SameOriginAllowPopups),
+            "same-origin" => Some(CrossOriginOpenerPolicy::SameOrigin),
+            "noopener-allow-popups" => Some(CrossOriginOpenerPolicy::NoopenerAllowPopups),
+            _ => None,
+        }
+    }
+}
+
+/// The `CrossOriginEmbedderPolicy` type is the value of a
+/// `Cross-Origin-Embedder-Policy` header, which cross-origin resources a
+/// document may load.
+///
+/// The header is a structured field token, parameters such as `report-to`
+/// are ignored.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum CrossOriginEmbedderPolicy {
+    /// `unsafe-none`, the default
+    #[default]
+    UnsafeNone,
+    /// `require-corp`, only resources allowing it through CORS or a
+    /// `Cross-Origin-Resource-Policy`
+    RequireCorp,
+    /// `credentialless`, no-CORS requests are sent without credentials
+    Credentialless,
+}
+
+impl CrossOriginEmbedderPolicy {
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrossOriginEmbedderPolicy::UnsafeNone => "unsafe-none",
+            CrossOriginEmbedderPolicy::RequireCorp => "require-corp",
+            CrossOriginEmbedderPolicy::Credentialless => "credentialless",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for CrossOriginEmbedderPolicy {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match Item::parse_header_value(slice)?.bare().as_token()? {
+            "unsafe-none" => Some(CrossOriginEmbedderPolicy::UnsafeNone),
+            "require-corp" => Some(CrossOriginEmbedderPolicy::RequireCorp),
+            "credentialless" => Some(CrossOriginEmbedderPolicy::Credentialless),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if a document served with `coop` and `coep` is
+/// cross-origin isolated, which gives it access to features such as
+/// `SharedArrayBuffer`.
+pub fn is_cross_origin_isolated(
+    coop: Option<CrossOriginOpenerPolicy>,
+    coep: Option<CrossOriginEmbedderPolicy>,
+) -> bool {
+    coop == Some(CrossOriginOpenerPolicy::SameOrigin)
+        && matches!(
+            coep,
+            Some(
+                CrossOriginEmbedderPolicy::RequireCorp | CrossOriginEmbedderPolicy::Credentialless
+            )
+        )
+}
+
+/// The `CrossOriginResourcePolicy` type is the value of a
+/// `Cross-Origin-Resource-Policy` header, which origins may load the
+/// resource in no-CORS mode.
+///
+/// Values are matched case-sensitively, as browsers do.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CrossOriginResourcePolicy {
+    /// `same-site`
+    SameSite,
+    /// `same-origin`
+    SameOrigin,
+    /// `cross-origin`
+    CrossOrigin,
+}
+
+impl CrossOriginResourcePolicy {
+    /// Returns the value as sent in the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrossOriginResourcePolicy::SameSite => "same-site",
+            CrossOriginResourcePolicy::SameOrigin => "same-origin",
+            CrossOriginResourcePolicy::CrossOrigin => "cross-origin",
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for CrossOriginResourcePolicy {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match trim(slice) {
+            "same-site" => Some(CrossOriginResourcePolicy::SameSite),
+            "same-origin" => Some(CrossOriginResourcePolicy::SameOrigin),
+            "cross-origin" => Some(CrossOriginResourcePolicy::CrossOrigin),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("nosniff", Some(XContentTypeOptions::NoSniff))]
+    #[case(" NoSniff ", Some(XContentTypeOptions::NoSniff))]
+    #[case("nosniff, other", Some(XContentTypeOptions::NoSniff))]
+    #[case("other, nosniff", None)]
+    #[case("", None)]
+    fn x_content_type_options_test(
+        #[case] input: &str,
+        #[case] expected: Option<XContentTypeOptions>,
+    ) {
+        assert_eq!(expected, XContentTypeOptions::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("DENY", Some(XFrameOptions::Deny))]
+    #[case("sameorigin", Some(XFrameOptions::SameOrigin))]
+    #[case("SAMEORIGIN, sameorigin", Some(XFrameOptions::SameOrigin))]
+    #[case("DENY, SAMEORIGIN", None)]
+    #[case("ALLOW-FROM https://example.com", None)]
+    #[case("", None)]
+    fn x_frame_options_test(#[case] input: &str, #[case] expected: Option<XFrameOptions>) {
+        assert_eq!(expected, XFrameOptions::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("same-origin", Some(CrossOriginOpenerPolicy::SameOrigin))]
+    #[case(
+        "same-origin-allow-popups; report-to=\"coop\"",
+        Some(CrossOriginOpenerPolicy::SameOriginAllowPopups)
+    )]
+    #[case("unsafe-none", Some(CrossOriginOpenerPolicy::UnsafeNone))]
+    #[case(
+        "noopener-allow-popups",
+        Some(CrossOriginOpenerPolicy::NoopenerAllowPopups)
+    )]
+    #[case("\"same-origin\"", None)]
+    #[case("Same-Origin", None)]
+    fn coop_test(#[case] input: &str, #[case] expected: Option<CrossOriginOpenerPolicy>) {
+        assert_eq!(expected, CrossOriginOpenerPolicy::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("require-corp", Some(CrossOriginEmbedderPolicy::RequireCorp))]
+    #[case(
+        "credentialless; report-to=\"coep\"",
+        Some(CrossOriginEmbedderPolicy::Credentialless)
+    )]
+    #[case("unsafe-none", Some(CrossOriginEmbedderPolicy::UnsafeNone))]
+    #[case("require-corp, credentialless", None)]
+    fn coep_test(#[case] input: &str, #[case] expected: Option<CrossOriginEmbedderPolicy>) {
+        assert_eq!(
+            expected,
+            CrossOriginEmbedderPolicy::parse_header_value(input)
+        );
+    }
+
+    #[rstest]
+    #[case("same-site", Some(CrossOriginResourcePolicy::SameSite))]
+    #[case(" cross-origin ", Some(CrossOriginResourcePolicy::CrossOrigin))]
+    #[case("same-origin", Some(CrossOriginResourcePolicy::SameOrigin))]
+    #[case("Same-Origin", None)]
+    #[case("same-origin, same-site", None)]
+    fn corp_test(#[case] input: &str, #[case] expected: Option<CrossOriginResourcePolicy>) {
+        assert_eq!(
+            expected,
+            CrossOriginResourcePolicy::parse_header_value(input)
+        );
+    }
+
+    #[rstest]
+    #[case(
+        Some(CrossOriginOpenerPolicy::SameOrigin),
+        Some(CrossOriginEmbedderPolicy::RequireCorp),
+        true
+    )]
+    #[case(
+        Some(CrossOriginOpenerPolicy::SameOrigin),
+        Some(CrossOriginEmbedderPolicy::Credentialless),
+        true
+    )]
+    #[case(Some(CrossOriginOpenerPolicy::SameOrigin), None, false)]
+    #[case(
+        Some(CrossOriginOpenerPolicy::SameOriginAllowPopups),
+        Some(CrossOriginEmbedderPolicy::RequireCorp),
+        false
+    )]
+    #[case(None, Some(CrossOriginEmbedderPolicy::RequireCorp), false)]
+    fn cross_origin_isolated_test(
+        #[case] coop: Option<CrossOriginOpenerPolicy>,
+        #[case] coep: Option<CrossOriginEmbedderPolicy>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, is_cross_origin_isolated(coop, coep));
+    }
+}
@@ -0,0 +1,163 @@
+use super::{are_params_valid, split_param};
+use crate::from_header_value::{split_list, split_params, trim};
+use crate::token::is_token;
+use crate::FromHeaderValue;
+
+/// The `ServerTimingMetric` type is a single metric of a `Server-Timing`
+/// header, such as `db;dur=53.2;desc="Database"`.
+///
+/// As the specification requires, only the first `dur` and `desc` are used
+/// and a `dur` that isn't a number is ignored.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ServerTimingMetric<'a> {
+    name: &'a str,
+    params: &'a str,
+    dur: Option<f64>,
+    desc: Option<&'a str>,
+}
+
+impl<'a> ServerTimingMetric<'a> {
+    /// Returns the name of the metric.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the `dur` parameter, the duration of the metric in
+    /// milliseconds.
+    pub fn dur(&self) -> Option<f64> {
+        self.dur
+    }
+
+    /// Returns the unquoted `desc` parameter, a human readable description.
+    pub fn desc(&self) -> Option<&'a str> {
+        self.desc
+    }
+
+    /// Returns the unquoted value of the first parameter called `name`,
+    /// compared case-insensitively, including extension parameters.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        split_params(self.params)
+            .filter_map(split_param)
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+}
+
+fn parse_dur(value: &str) -> Option<f64> {
+    let valid = value.bytes().any(|b| b.is_ascii_digit())
+        && value.bytes().all(|b| b.is_ascii_digit() || b == b'.');
+    value.parse().ok().filter(|_| valid)
+}
+
+impl<'de> FromHeaderValue<'de> for ServerTimingMetric<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (name, params) = slice.split_once(';').unwrap_or((slice, ""));
+        let name = trim(name);
+        if !is_token(name) || !are_params_valid(params) {
+            return None;
+        }
+        let mut metric = ServerTimingMetric {
+            name,
+            params,
+            dur: None,
+            desc: None,
+        };
+        let mut seen_dur = false;
+        for (param, value) in split_params(params).filter_map(split_param) {
+            if param.eq_ignore_ascii_case("dur") && !seen_dur {
+                seen_dur = true;
+                metric.dur = parse_dur(value);
+            } else if param.eq_ignore_ascii_case("desc") && metric.desc.is_none() {
+                metric.desc = Some(value);
+            }
+        }
+        Some(metric)
+    }
+}
+
+/// The `ServerTiming` type is the value of a `Server-Timing` header, the
+/// metrics a server reports about handling a request such as
+/// `cache;desc="Cache Read";dur=23.2, db;dur=53, app;dur=47.2`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ServerTiming<'a>(&'a str);
+
+impl<'a> ServerTiming<'a> {
+    /// Returns an iterator over the metrics, in order.
+    pub fn metrics(&self) -> impl Iterator<Item = ServerTimingMetric<'a>> {
+        split_list(self.0)
+            .filter(|metric| !trim(metric).is_empty())
+            .filter_map(ServerTimingMetric::parse_header_value)
+    }
+
+    /// Returns the first metric called `name`, compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<ServerTimingMetric<'a>> {
+        self.metrics()
+            .find(|metric| metric.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for ServerTiming<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut metrics = split_list(slice).filter(|metric| !trim(metric).is_empty());
+        let mut count = 0;
+        let valid = metrics.all(|metric| {
+            count += 1;
+            ServerTimingMetric::parse_header_value(metric).is_some()
+        });
+        (valid && count > 0).then_some(ServerTiming(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Metric<'a> = (&'a str, Option<f64>, Option<&'a str>);
+
+    #[rstest]
+    #[case("missedCache", Some(("missedCache", None, None)))]
+    #[case("cpu;dur=2.4", Some(("cpu", Some(2.4), None)))]
+    #[case("cache;desc=\"Cache Read\";dur=23.2", Some(("cache", Some(23.2), Some("Cache Read"))))]
+    #[case("db; DUR=53 ; desc=query", Some(("db", Some(53.0), Some("query"))))]
+    #[case("db;dur=1;dur=2;desc=a;desc=b", Some(("db", Some(1.0), Some("a"))))]
+    #[case("db;dur=fast;dur=2", Some(("db", None, None)))]
+    #[case("db;dur=-1", Some(("db", None, None)))]
+    #[case("db;dur", None)]
+    #[case("d b", None)]
+    #[case("", None)]
+    fn metric_test(#[case] input: &str, #[case] expected: Option<Metric>) {
+        let parsed = ServerTimingMetric::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|metric| (metric.name(), metric.dur(), metric.desc()))
+        );
+    }
+
+    #[test]
+    fn metric_extension_param() {
+        let metric = ServerTimingMetric::parse_header_value("edge;region=\"eu-west\"").unwrap();
+        assert_eq!(metric.get("Region"), Some("eu-west"));
+        assert_eq!(metric.get("dur"), None);
+    }
+
+    #[rstest]
+    #[case("cache;desc=\"Cache, Read\";dur=23.2, db;dur=53, app;dur=47.2", Some(vec!["cache", "db", "app"]))]
+    #[case("total, ", Some(vec!["total"]))]
+    #[case("", None)]
+    #[case("db, ;dur=1", None)]
+    fn server_timing_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = ServerTiming::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|timing| timing.metrics().map(|metric| metric.name()).collect())
+        );
+    }
+
+    #[test]
+    fn server_timing_get() {
+        let timing = ServerTiming::parse_header_value("db;dur=53, app;dur=47.2").unwrap();
+        assert_eq!(timing.get("APP").and_then(|app| app.dur()), Some(47.2));
+        assert_eq!(timing.get("cdn"), None);
+    }
+}
@@ -0,0 +1,230 @@
+use super::cookie::split_cookie;
+use crate::from_header_value::{parse_digits, trim};
+use crate::token::is_token;
+use crate::{FromHeaderValue, HttpDate};
+
+/// The `SameSite` attribute of a `Set-Cookie` header, controlling whether
+/// the cookie is sent along with cross-site requests.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SameSite {
+    fn parse(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("strict") {
+            Some(SameSite::Strict)
+        } else if value.eq_ignore_ascii_case("lax") {
+            Some(SameSite::Lax)
+        } else if value.eq_ignore_ascii_case("none") {
+            Some(SameSite::None)
+        } else {
+            None
+        }
+    }
+}
+
+/// The `SetCookie` type is the value of a `Set-Cookie` response header,
+/// such as `id=a3fWa; Max-Age=2592000; Path=/; Secure; HttpOnly`.
+///
+/// The cookie needs a token name and a `=`, but its attributes are handled
+/// leniently like a user agent would: unknown attributes and attributes
+/// with an invalid value are ignored, and a repeated attribute overrides
+/// the earlier one. Since `Expires` contains a comma, collect repeated
+/// `Set-Cookie` headers with `#[noggin(no_split)]`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SetCookie<'a> {
+    name: &'a str,
+    value: &'a str,
+    expires: Option<HttpDate>,
+    max_age: Option<u64>,
+    domain: Option<&'a str>,
+    path: Option<&'a str>,
+    secure: bool,
+    http_only: bool,
+    partitioned: bool,
+    same_site: Option<SameSite>,
+}
+
+impl<'a> SetCookie<'a> {
+    /// Returns the name of the cookie.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the value of the cookie, without surrounding DQUOTEs.
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Returns the `Expires` date.
+    pub fn expires(&self) -> Option<HttpDate> {
+        self.expires
+    }
+
+    /// Returns the `Max-Age` in seconds, a negative `Max-Age` is returned
+    /// as `0` since both expire the cookie immediately.
+    pub fn max_age(&self) -> Option<u64> {
+        self.max_age
+    }
+
+    /// Returns the `Domain`, without a leading `.`.
+    pub fn domain(&self) -> Option<&'a str> {
+        self.domain
+    }
+
+    /// Returns the `Path`, which always starts with `/`.
+    pub fn path(&self) -> Option<&'a str> {
+        self.path
+    }
+
+    /// Returns `true` if the cookie may only be sent over secure channels.
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    /// Returns `true` if the cookie is hidden from scripts.
+    pub fn is_http_only(&self) -> bool {
+        self.http_only
+    }
+
+    /// Returns `true` if the cookie is stored per top-level site.
+    pub fn is_partitioned(&self) -> bool {
+        self.partitioned
+    }
+
+    /// Returns the `SameSite` attribute.
+    pub fn same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+
+    fn set_attribute(&mut self, name: &str, value: &'a str) {
+        if name.eq_ignore_ascii_case("expires") {
+            if let Some(date) = HttpDate::parse_header_value(value) {
+                self.expires = Some(date);
+            }
+        } else if name.eq_ignore_ascii_case("max-age") {
+            let max_age = match value.strip_prefix('-') {
+                Some(digits) => parse_digits(digits).map(|_| 0),
+                None => parse_digits(value),
+            };
+            if max_age.is_some() {
+                self.max_age = max_age;
+            }
+        } else if name.eq_ignore_ascii_case("domain") {
+            let domain = value.strip_prefix('.').unwrap_or(value);
+            if !domain.is_empty() {
+                self.domain = Some(domain);
+            }
+        } else if name.eq_ignore_ascii_case("path") {
+            if value.starts_with('/') {
+                self.path = Some(value);
+            }
+        } else if name.eq_ignore_ascii_case("secure") {
+            self.secure = true;
+        } else if name.eq_ignore_ascii_case("httponly") {
+            self.http_only = true;
+        } else if name.eq_ignore_ascii_case("partitioned") {
+            self.partitioned = true;
+        } else if name.eq_ignore_ascii_case("samesite") {
+            if let Some(same_site) = SameSite::parse(value) {
+                self.same_site = Some(same_site);
+            }
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for SetCookie<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut attributes = slice.split(';');
+        let (name, value) = split_cookie(attributes.next()?)?;
+        if !is_token(name) {
+            return None;
+        }
+        let mut cookie = SetCookie {
+            name,
+            value,
+            expires: None,
+            max_age: None,
+            domain: None,
+            path: None,
+            secure: false,
+            http_only: false,
+            partitioned: false,
+            same_site: None,
+        };
+        for attribute in attributes {
+            let (name, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            cookie.set_attribute(trim(name), trim(value));
+        }
+        Some(cookie)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Attributes<'a> = (
+        &'a str,
+        &'a str,
+        Option<u64>,
+        Option<&'a str>,
+        Option<&'a str>,
+        Option<SameSite>,
+    );
+
+    #[test]
+    fn set_cookie_attributes() {
+        let cookie = SetCookie::parse_header_value(
+            "id=a3fWa; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=2592000; \
+             Domain=.Example.com; Path=/docs; Secure; HttpOnly; Partitioned; SameSite=lax",
+        )
+        .unwrap();
+        assert_eq!(cookie.name(), "id");
+        assert_eq!(cookie.value(), "a3fWa");
+        assert_eq!(
+            cookie.expires(),
+            Some(HttpDate::from_unix_timestamp(1445412480))
+        );
+        assert_eq!(cookie.max_age(), Some(2592000));
+        assert_eq!(cookie.domain(), Some("Example.com"));
+        assert_eq!(cookie.path(), Some("/docs"));
+        assert!(cookie.is_secure());
+        assert!(cookie.is_http_only());
+        assert!(cookie.is_partitioned());
+        assert_eq!(cookie.same_site(), Some(SameSite::Lax));
+    }
+
+    #[rstest]
+    #[case("a=1", Some(("a", "1", None, None, None, None)))]
+    #[case("a=\"quoted\"; Path=/", Some(("a", "quoted", None, None, Some("/"), None)))]
+    #[case("a=; Max-Age=-1", Some(("a", "", Some(0), None, None, None)))]
+    #[case("a=1; Max-Age=10; Max-Age=soon", Some(("a", "1", Some(10), None, None, None)))]
+    #[case("a=1; Path=docs; Domain=", Some(("a", "1", None, None, None, None)))]
+    #[case("a=1; Expires=later; Foo=bar; Baz", Some(("a", "1", None, None, None, None)))]
+    #[case("a=1; SameSite=Strict; SameSite=bogus", Some(("a", "1", None, None, None, Some(SameSite::Strict))))]
+    #[case("a=1; Domain=a.com; Domain=b.com", Some(("a", "1", None, Some("b.com"), None, None)))]
+    #[case("a=1; Secure=no", Some(("a", "1", None, None, None, None)))]
+    #[case("flag", None)]
+    #[case("=1", None)]
+    #[case("a b=1", None)]
+    #[case("", None)]
+    fn set_cookie_test(#[case] input: &str, #[case] expected: Option<Attributes>) {
+        let parsed = SetCookie::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|c| (
+                c.name(),
+                c.value(),
+                c.max_age(),
+                c.domain(),
+                c.path(),
+                c.same_site()
+            ))
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::from_header_value::{split_list, trim};
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `Traceparent` type is the value of a W3C Trace Context `traceparent`
+/// header, such as
+/// `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+///
+/// Only lowercase hex is accepted, and all-zero trace and parent ids are
+/// rejected. Versions after `00` are parsed as `00`, ignoring any trailing
+/// fields, as the specification requires. It is displayed as version `00`,
+/// ready to be propagated.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Traceparent {
+    version: u8,
+    trace_id: u128,
+    parent_id: u64,
+    flags: u8,
+}
+
+impl Traceparent {
+    /// The flag set when the caller may have recorded the trace.
+    pub const SAMPLED: u8 = 0x01;
+
+    /// Creates a version `00` traceparent, returning `None` if either id is
+    /// zero.
+    pub fn new(trace_id: u128, parent_id: u64, flags: u8) -> Option<Self> {
+        let valid = trace_id != 0 && parent_id != 0;
+        valid.then_some(Traceparent {
+            version: 0,
+            trace_id,
+            parent_id,
+            flags,
+        })
+    }
+
+    /// Returns the version of the format the header was sent in.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the id of the whole trace.
+    pub fn trace_id(&self) -> u128 {
+        self.trace_id
+    }
+
+    /// Returns the id of the caller's span.
+    pub fn parent_id(&self) -> u64 {
+        self.parent_id
+    }
+
+    /// Returns the trace flags.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns `true` if the sampled flag is set.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & Self::SAMPLED != 0
+    }
+
+    /// Returns the traceparent to send downstream from the span
+    /// `parent_id`, keeping the trace id and flags. Returns `None` if
+    /// `parent_id` is zero.
+    pub fn child(&self, parent_id: u64) -> Option<Self> {
+        Traceparent::new(self.trace_id, parent_id, self.flags)
+    }
+}
+
+fn parse_lower_hex(digits: &str, len: usize) -> Option<&str> {
+    let valid = digits.len() == len
+        && digits
+            .bytes()
+            .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+    valid.then_some(digits)
+}
+
+impl<'de> FromHeaderValue<'de> for Traceparent {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let version = u8::from_str_radix(parse_lower_hex(value.get(..2)?, 2)?, 16).ok()?;
+        let (fields, rest) = value.split_at_checked(55).unwrap_or((value, ""));
+        let valid_rest = match version {
+            0xff => false,
+            0x00 => rest.is_empty(),
+            _ => rest.is_empty() || rest.starts_with('-'),
+        };
+        if !valid_rest {
+            return None;
+        }
+        let mut fields = fields.split('-').skip(1);
+        let trace_id = parse_lower_hex(fields.next()?, 32)?;
+        let parent_id = parse_lower_hex(fields.next()?, 16)?;
+        let flags = parse_lower_hex(fields.next()?, 2)?;
+        if fields.next().is_some() {
+            return None;
+        }
+        let mut traceparent = Traceparent::new(
+            u128::from_str_radix(trace_id, 16).ok()?,
+            u64::from_str_radix(parent_id, 16).ok()?,
+            u8::from_str_radix(flags, 16).ok()?,
+        )?;
+        traceparent.version = version;
+        Some(traceparent)
+    }
+}
+
+impl fmt::Display for Traceparent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+/// The `Tracestate` type is the value of a W3C Trace Context `tracestate`
+/// header, the vendor specific `key=value` members such as
+/// `congo=t61rcWkgMzE,rojo=00f067aa0ba902b7`, most recently updated first.
+///
+/// A header with more than [`Tracestate::MAX_MEMBERS`] members, a duplicate
+/// key, or a malformed member is rejected as a whole. An empty header is an
+/// empty list.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Tracestate<'a>(&'a str);
+
+impl<'a> Tracestate<'a> {
+    /// The most members a `tracestate` may carry.
+    pub const MAX_MEMBERS: usize = 32;
+
+    /// Returns an iterator over the keys and values, in order.
+    pub fn members(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        split_list(self.0).filter_map(split_member)
+    }
+
+    /// Returns the value of the member called `key`.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.members()
+            .find(|(member, _)| *member == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.members().count()
+    }
+
+    /// Returns `true` if there are no members.
+    pub fn is_empty(&self) -> bool {
+        self.members().next().is_none()
+    }
+}
+
+fn is_key_char(b: u8) -> bool {
+    b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'_' | b'-' | b'*' | b'/')
+}
+
+/// Returns `true` for a simple key such as `rojo`, or a multi-tenant key
+/// such as `fw529a3039@dt`.
+fn is_key(key: &str) -> bool {
+    match key.split_once('@') {
+        None => {
+            key.len() <= 256
+                && key.bytes().next().is_some_and(|b| b.is_ascii_lowercase())
+                && key.bytes().all(is_key_char)
+        }
+        Some((tenant, system)) => {
+            tenant.len() <= 241
+                && tenant
+                    .bytes()
+                    .next()
+                    .is_some_and(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+                && tenant.bytes().all(is_key_char)
+                && system.len() <= 14
+                && system
+                    .bytes()
+                    .next()
+                    .is_some_and(|b| b.is_ascii_lowercase())
+                && system.bytes().all(is_key_char)
+        }
+    }
+}
+
+fn is_value(value: &str) -> bool {
+    (1..=256).contains(&value.len())
+        && !value.ends_with(' ')
+        && value
+            .bytes()
+            .all(|b| matches!(b, b' '..=b'~') && b != b',' && b != b'=')
+}
+
+/// Splits a `key=value` member, yielding `None` for empty or malformed
+/// members.
+fn split_member(member: &str) -> Option<(&str, &str)> {
+    let (key, value) = trim(member).split_once('=')?;
+    (is_key(key) && is_value(value)).then_some((key, value))
+}
+
+impl<'de> FromHeaderValue<'de> for Tracestate<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let mut members = split_list(value).filter(|member| !trim(member).is_empty());
+        let mut count = 0;
+        let valid = members.all(|member| {
+            count += 1;
+            let Some((key, _)) = split_member(member) else {
+                return false;
+            };
+            let mut previous = split_list(value).filter_map(split_member).take(count - 1);
+            count <= Self::MAX_MEMBERS && previous.all(|(other, _)| other != key)
+        });
+        valid.then_some(Tracestate(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Parent = (u8, u128, u64, u8);
+
+    #[rstest]
+    #[case(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        Some((0, 0x4bf92f3577b34da6a3ce929d0e0e4736, 0x00f067aa0ba902b7, 1))
+    )]
+    #[case(
+        " 00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00 ",
+        Some((0, 0x4bf92f3577b34da6a3ce929d0e0e4736, 0x00f067aa0ba902b7, 0))
+    )]
+    #[case(
+        "cc-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-09-what-the-future-holds",
+        Some((0xcc, 0x4bf92f3577b34da6a3ce929d0e0e4736, 0x00f067aa0ba902b7, 9))
+    )]
+    #[case("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra", None)]
+    #[case("cc-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01extra", None)]
+    #[case("ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01", None)]
+    #[case("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01", None)]
+    #[case("00-00000000000000000000000000000000-00f067aa0ba902b7-01", None)]
+    #[case("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01", None)]
+    #[case("00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01", None)]
+    #[case("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-1", None)]
+    #[case("00_4bf92f3577b34da6a3ce929d0e0e4736_00f067aa0ba902b7_01", None)]
+    #[case("", None)]
+    fn traceparent_test(#[case] input: &str, #[case] expected: Option<Parent>) {
+        let parsed = Traceparent::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|traceparent| (
+                traceparent.version(),
+                traceparent.trace_id(),
+                traceparent.parent_id(),
+                traceparent.flags()
+            ))
+        );
+    }
+
+    #[test]
+    fn traceparent_child() {
+        let traceparent = Traceparent::parse_header_value(
+            "cc-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-future",
+        )
+        .unwrap();
+        assert!(traceparent.is_sampled());
+        let child = traceparent.child(0xb7ad6b7169203331).unwrap();
+        assert_eq!(
+            child.to_string(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-b7ad6b7169203331-01"
+        );
+        assert_eq!(traceparent.child(0), None);
+    }
+
+    #[rstest]
+    #[case("congo=t61rcWkgMzE,rojo=00f067aa0ba902b7", Some(vec![("congo", "t61rcWkgMzE"), ("rojo", "00f067aa0ba902b7")]))]
+    #[case("fw529a3039@dt=p0 , , 1a-2f@vendor*x=a b", Some(vec![("fw529a3039@dt", "p0"), ("1a-2f@vendor*x", "a b")]))]
+    #[case("", Some(vec![]))]
+    #[case("rojo=1,congo=2,rojo=3", None)]
+    #[case("Rojo=1", None)]
+    #[case("1rojo=1", None)]
+    #[case("rojo=", None)]
+    #[case("rojo=a=b", None)]
+    #[case("rojo", None)]
+    #[case("a@averyverylongsystem=1", None)]
+    fn tracestate_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, &str)>>) {
+        let parsed = Tracestate::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|state| state.members().collect()));
+    }
+
+    #[test]
+    fn tracestate_member_limit() {
+        let members: Vec<_> = (0..33).map(|i| format!("k{i}=v")).collect();
+        assert_eq!(
+            Tracestate::parse_header_value(&members[..32].join(",")).map(|state| state.len()),
+            Some(32)
+        );
+        assert_eq!(Tracestate::parse_header_value(&members.join(",")), None);
+    }
+
+    #[test]
+    fn tracestate_get() {
+        let state =
+            Tracestate::parse_header_value("congo=t61rcWkgMzE,rojo=00f067aa0ba902b7").unwrap();
+        assert_eq!(state.get("rojo"), Some("00f067aa0ba902b7"));
+        assert_eq!(state.get("zulu"), None);
+        assert!(!state.is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+use super::are_params_valid;
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::{FromHeaderValue, HttpVersion, Weighted};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// The `TransferCoding` type is a transfer coding as found in
+/// `Transfer-Encoding` and `TE`, compared case-insensitively.
+///
+/// `x-gzip` and `x-compress` are accepted as aliases. Parameters following
+/// the coding are validated and ignored. Unknown codings are kept as
+/// [`Other`](TransferCoding::Other), borrowed from the header.
+#[derive(Clone, Copy, Eq, Debug)]
+pub enum TransferCoding<'a> {
+    /// `chunked`
+    Chunked,
+    /// `compress` or `x-compress`
+    Compress,
+    /// `deflate`, zlib
+    Deflate,
+    /// `gzip` or `x-gzip`
+    Gzip,
+    /// Any other coding
+    Other(&'a str),
+}
+
+impl<'a> TransferCoding<'a> {
+    /// Returns the registered name of the coding.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            TransferCoding::Chunked => "chunked",
+            TransferCoding::Compress => "compress",
+            TransferCoding::Deflate => "deflate",
+            TransferCoding::Gzip => "gzip",
+            TransferCoding::Other(name) => name,
+        }
+    }
+
+    fn from_token(token: &'a str) -> Self {
+        const KNOWN: [TransferCoding<'static>; 4] = [
+            TransferCoding::Chunked,
+            TransferCoding::Compress,
+            TransferCoding::Deflate,
+            TransferCoding::Gzip,
+        ];
+        if token.eq_ignore_ascii_case("x-gzip") {
+            return TransferCoding::Gzip;
+        }
+        if token.eq_ignore_ascii_case("x-compress") {
+            return TransferCoding::Compress;
+        }
+        KNOWN
+            .into_iter()
+            .find(|coding| coding.as_str().eq_ignore_ascii_case(token))
+            .unwrap_or(TransferCoding::Other(token))
+    }
+
+    fn is_chunked(&self) -> bool {
+        *self == TransferCoding::Chunked
+    }
+}
+
+impl<'a, 'b> PartialEq<TransferCoding<'b>> for TransferCoding<'a> {
+    fn eq(&self, other: &TransferCoding<'b>) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl<'a> Hash for TransferCoding<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.as_str().bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for TransferCoding<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (coding, params) = slice.split_once(';').unwrap_or((slice, ""));
+        let coding = trim(coding);
+        (is_token(coding) && are_params_valid(params)).then(|| TransferCoding::from_token(coding))
+    }
+}
+
+impl<'a> fmt::Display for TransferCoding<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The `TransferEncoding` type is the value of a `Transfer-Encoding` header,
+/// the non-empty list of codings applied to the message body in order, such
+/// as `gzip, chunked`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TransferEncoding<'a>(&'a str);
+
+impl<'a> TransferEncoding<'a> {
+    /// Returns an iterator over the codings, in the order they were
+    /// applied.
+    pub fn codings(&self) -> impl Iterator<Item = TransferCoding<'a>> {
+        split_list(self.0)
+            .filter(|coding| !trim(coding).is_empty())
+            .filter_map(TransferCoding::parse_header_value)
+    }
+
+    /// Returns `true` if `chunked` is the final coding, which is what
+    /// delimits the body.
+    pub fn is_chunked_final(&self) -> bool {
+        self.codings()
+            .last()
+            .is_some_and(|coding| coding.is_chunked())
+    }
+
+    /// Returns `true` if a request may carry this header: only HTTP/1.1
+    /// requests can, and `chunked` must be the final coding and be applied
+    /// only once. Otherwise the body length can't be determined reliably
+    /// and the request should be rejected with `400 Bad Request`.
+    pub fn is_valid_in_request(&self, version: HttpVersion) -> bool {
+        version == HttpVersion::Http11
+            && self.is_chunked_final()
+            && self.codings().filter(TransferCoding::is_chunked).count() == 1
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for TransferEncoding<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut codings = split_list(slice).filter(|coding| !trim(coding).is_empty());
+        let mut count = 0;
+        let valid = codings.all(|coding| {
+            count += 1;
+            TransferCoding::parse_header_value(coding).is_some()
+        });
+        (valid && count > 0).then_some(TransferEncoding(slice))
+    }
+}
+
+/// The `Te` type is the value of a `TE` header, the transfer codings a
+/// client accepts in the response with their quality, such as
+/// `trailers, deflate;q=0.5`.
+///
+/// `chunked` is always acceptable and must not be listed. The `trailers`
+/// keyword isn't a coding and is only reported by
+/// [`accepts_trailers`](Te::accepts_trailers).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Te<'a>(&'a str);
+
+impl<'a> Te<'a> {
+    /// Returns an iterator over the codings and their quality, in the order
+    /// they were sent in.
+    pub fn codings(&self) -> impl Iterator<Item = Weighted<TransferCoding<'a>>> {
+        split_list(self.0)
+            .filter(|coding| !trim(coding).is_empty())
+            .filter_map(Weighted::<TransferCoding>::parse_header_value)
+            .filter(|coding| !coding.item.as_str().eq_ignore_ascii_case("trailers"))
+    }
+
+    /// Returns `true` if the client accepts trailer fields in a chunked
+    /// response.
+    pub fn accepts_trailers(&self) -> bool {
+        split_list(self.0).any(|coding| trim(coding).eq_ignore_ascii_case("trailers"))
+    }
+
+    /// Returns the quality of `coding`, `chunked` is always acceptable and
+    /// unlisted codings are not.
+    pub fn quality(&self, coding: &TransferCoding<'_>) -> f32 {
+        if coding.is_chunked() {
+            return 1.0;
+        }
+        self.codings()
+            .find(|listed| listed.item == *coding)
+            .map_or(0.0, |listed| listed.quality)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Te<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let valid = split_list(slice)
+            .filter(|coding| !trim(coding).is_empty())
+            .all(|coding| {
+                Weighted::<TransferCoding>::parse_header_value(coding)
+                    .is_some_and(|coding| !coding.item.is_chunked())
+            });
+        valid.then_some(Te(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use TransferCoding::*;
+
+    #[rstest]
+    #[case("chunked", Some(Chunked))]
+    #[case(" X-GZIP ", Some(Gzip))]
+    #[case("x-compress", Some(Compress))]
+    #[case("deflate", Some(Deflate))]
+    #[case("ext;level=2", Some(Other("ext")))]
+    #[case("ext;level", None)]
+    #[case("a b", None)]
+    fn transfer_coding_test(#[case] input: &str, #[case] expected: Option<TransferCoding>) {
+        assert_eq!(expected, TransferCoding::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case("chunked", Some(vec![Chunked]))]
+    #[case("gzip, chunked", Some(vec![Gzip, Chunked]))]
+    #[case("chunked, gzip", Some(vec![Chunked, Gzip]))]
+    #[case("", None)]
+    #[case(" , ", None)]
+    #[case("chunked, g zip", None)]
+    fn transfer_encoding_test(#[case] input: &str, #[case] expected: Option<Vec<TransferCoding>>) {
+        let parsed = TransferEncoding::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|te| te.codings().collect()));
+    }
+
+    #[rstest]
+    #[case("chunked", HttpVersion::Http11, true, true)]
+    #[case("gzip, Chunked", HttpVersion::Http11, true, true)]
+    #[case("chunked, gzip", HttpVersion::Http11, false, false)]
+    #[case("chunked, chunked", HttpVersion::Http11, true, false)]
+    #[case("gzip", HttpVersion::Http11, false, false)]
+    #[case("chunked", HttpVersion::Http10, true, false)]
+    #[case("chunked", HttpVersion::Http2, true, false)]
+    fn transfer_encoding_request(
+        #[case] input: &str,
+        #[case] version: HttpVersion,
+        #[case] chunked_final: bool,
+        #[case] valid: bool,
+    ) {
+        let parsed = TransferEncoding::parse_header_value(input).unwrap();
+        assert_eq!(chunked_final, parsed.is_chunked_final());
+        assert_eq!(valid, parsed.is_valid_in_request(version));
+    }
+
+    #[rstest]
+    #[case("trailers, deflate;q=0.5", true, Some(vec![(Deflate, 0.5)]))]
+    #[case("gzip", false, Some(vec![(Gzip, 1.0)]))]
+    #[case("", false, Some(vec![]))]
+    #[case("chunked", false, None)]
+    #[case("gzip;q=2", false, None)]
+    fn te_test(
+        #[case] input: &str,
+        #[case] trailers: bool,
+        #[case] expected: Option<Vec<(TransferCoding, f32)>>,
+    ) {
+        let parsed = Te::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|te| te.codings().map(|c| (c.item, c.quality)).collect())
+        );
+        assert_eq!(trailers, parsed.is_some_and(|te| te.accepts_trailers()));
+    }
+
+    #[test]
+    fn te_quality() {
+        let te = Te::parse_header_value("trailers, deflate;q=0.5").unwrap();
+        assert_eq!(te.quality(&Chunked), 1.0);
+        assert_eq!(te.quality(&Deflate), 0.5);
+        assert_eq!(te.quality(&Gzip), 0.0);
+    }
+}
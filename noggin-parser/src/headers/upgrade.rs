@@ -0,0 +1,150 @@
+use super::Connection;
+use crate::from_header_value::{split_list, trim};
+use crate::token::is_token;
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `Protocol` type is an element of an `Upgrade` header, a protocol
+/// name with an optional version such as `HTTP/2.0` or `websocket`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Protocol<'a> {
+    name: &'a str,
+    version: Option<&'a str>,
+}
+
+impl<'a> Protocol<'a> {
+    /// Returns the protocol name.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the protocol version, if one was given.
+    pub fn version(&self) -> Option<&'a str> {
+        self.version
+    }
+
+    /// Returns `true` if the protocol name is `name`, compared
+    /// case-insensitively.
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Protocol<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let (name, version) = match value.split_once('/') {
+            Some((name, version)) => (name, Some(version)),
+            None => (value, None),
+        };
+        (is_token(name) && version.is_none_or(is_token)).then_some(Protocol { name, version })
+    }
+}
+
+impl<'a> fmt::Display for Protocol<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)?;
+        if let Some(version) = self.version {
+            write!(f, "/{version}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The `Upgrade` type is the value of an `Upgrade` header, the protocols a
+/// client would like to switch to in order of preference, such as
+/// `websocket` or `h2c`.
+///
+/// The upgrade only applies if `Connection` lists the `upgrade` option, use
+/// [`is_websocket`](Upgrade::is_websocket) and [`is_h2c`](Upgrade::is_h2c)
+/// to check both headers at once.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Upgrade<'a>(&'a str);
+
+impl<'a> Upgrade<'a> {
+    /// Returns an iterator over the protocols, in order of preference.
+    pub fn protocols(&self) -> impl Iterator<Item = Protocol<'a>> {
+        split_list(self.0)
+            .filter(|protocol| !trim(protocol).is_empty())
+            .filter_map(Protocol::parse_header_value)
+    }
+
+    /// Returns `true` if the protocol called `name` is offered, whatever
+    /// its version.
+    pub fn offers(&self, name: &str) -> bool {
+        self.protocols().any(|protocol| protocol.is(name))
+    }
+
+    /// Returns `true` if this is a WebSocket opening handshake.
+    pub fn is_websocket(&self, connection: &Connection<'_>) -> bool {
+        connection.is_upgrade() && self.offers("websocket")
+    }
+
+    /// Returns `true` if this is an upgrade to HTTP/2 over cleartext, which
+    /// also needs the `HTTP2-Settings` connection option.
+    pub fn is_h2c(&self, connection: &Connection<'_>) -> bool {
+        connection.is_upgrade() && connection.contains("HTTP2-Settings") && self.offers("h2c")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Upgrade<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut protocols = split_list(slice).filter(|protocol| !trim(protocol).is_empty());
+        let mut count = 0;
+        let valid = protocols.all(|protocol| {
+            count += 1;
+            Protocol::parse_header_value(protocol).is_some()
+        });
+        (valid && count > 0).then_some(Upgrade(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("websocket", Some(vec![("websocket", None)]))]
+    #[case("HTTP/2.0, SHTTP/1.3, IRC/6.9", Some(vec![("HTTP", Some("2.0")), ("SHTTP", Some("1.3")), ("IRC", Some("6.9"))]))]
+    #[case("h2c,", Some(vec![("h2c", None)]))]
+    #[case("", None)]
+    #[case("HTTP/", None)]
+    #[case("HTTP/2/0", None)]
+    #[case("web socket", None)]
+    fn upgrade_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, Option<&str>)>>) {
+        let parsed = Upgrade::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|upgrade| upgrade
+                .protocols()
+                .map(|p| (p.name(), p.version()))
+                .collect())
+        );
+    }
+
+    #[rstest]
+    #[case("WebSocket", "Upgrade", true, false)]
+    #[case("websocket", "keep-alive, Upgrade", true, false)]
+    #[case("websocket", "keep-alive", false, false)]
+    #[case("h2c", "Upgrade, HTTP2-Settings", false, true)]
+    #[case("h2c", "Upgrade", false, false)]
+    #[case("HTTP/2.0", "Upgrade, HTTP2-Settings", false, false)]
+    fn upgrade_detection(
+        #[case] upgrade: &str,
+        #[case] connection: &str,
+        #[case] websocket: bool,
+        #[case] h2c: bool,
+    ) {
+        let upgrade = Upgrade::parse_header_value(upgrade).unwrap();
+        let connection = Connection::parse_header_value(connection).unwrap();
+        assert_eq!(websocket, upgrade.is_websocket(&connection));
+        assert_eq!(h2c, upgrade.is_h2c(&connection));
+    }
+
+    #[test]
+    fn protocol_display() {
+        let protocol = Protocol::parse_header_value(" HTTP/2.0 ").unwrap();
+        assert_eq!(protocol.to_string(), "HTTP/2.0");
+    }
+}
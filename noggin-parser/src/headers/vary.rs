@@ -0,0 +1,120 @@
+use crate::from_header_value::{split_list, trim};
+use crate::{FromHeaderValue, Token};
+use alloc::collections::BTreeSet;
+
+/// The `Vary` type is the value of a `Vary` header, either `*` or the set of
+/// request header names that select the response.
+///
+/// Names are deduplicated and compared case-insensitively, so the set can be
+/// used directly to compute a cache key. A `*` anywhere in the list makes the
+/// whole value `*`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Vary<'a> {
+    Any,
+    Fields(BTreeSet<Token<'a>>),
+}
+
+impl<'a> Vary<'a> {
+    /// Returns `true` for the `*` form.
+    pub fn is_any(&self) -> bool {
+        *self == Vary::Any
+    }
+
+    /// Returns an iterator over the header names in order, which is empty
+    /// for the `*` form.
+    pub fn fields(&self) -> impl Iterator<Item = &Token<'a>> {
+        match self {
+            Vary::Any => None,
+            Vary::Fields(fields) => Some(fields.iter()),
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// Returns `true` if the response varies on the request header `name`,
+    /// which is always the case for the `*` form.
+    pub fn covers(&self, name: &str) -> bool {
+        match self {
+            Vary::Any => true,
+            Vary::Fields(fields) => fields.iter().any(|field| *field == name),
+        }
+    }
+
+    /// Combines two values, such as the `Vary` of a response and the one
+    /// added by a middleware. The result is `*` if either of them is.
+    pub fn merge(self, other: Vary<'a>) -> Vary<'a> {
+        match (self, other) {
+            (Vary::Fields(mut fields), Vary::Fields(other)) => {
+                fields.extend(other);
+                Vary::Fields(fields)
+            }
+            _ => Vary::Any,
+        }
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Vary<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut fields = BTreeSet::new();
+        for field in split_list(slice)
+            .map(trim)
+            .filter(|field| !field.is_empty())
+        {
+            if field == "*" {
+                return split_list(slice)
+                    .map(trim)
+                    .all(|field| {
+                        field.is_empty()
+                            || field == "*"
+                            || Token::parse_header_value(field).is_some()
+                    })
+                    .then_some(Vary::Any);
+            }
+            fields.insert(Token::parse_header_value(field)?);
+        }
+        Some(Vary::Fields(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Accept-Encoding", Some(vec!["Accept-Encoding"]))]
+    #[case("accept, Accept-Language, ACCEPT", Some(vec!["accept", "Accept-Language"]))]
+    #[case("", Some(vec![]))]
+    #[case("Accept, bad name", None)]
+    #[case("*, bad name", None)]
+    fn vary_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = Vary::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|vary| vary.fields().map(|f| f.as_str()).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("*")]
+    #[case("Accept, *")]
+    fn vary_any(#[case] input: &str) {
+        let parsed = Vary::parse_header_value(input).unwrap();
+        assert!(parsed.is_any());
+        assert!(parsed.covers("Cookie"));
+        assert_eq!(parsed.fields().count(), 0);
+    }
+
+    #[test]
+    fn vary_merge() {
+        let vary = Vary::parse_header_value("Accept").unwrap();
+        assert!(vary.covers("accept"));
+        assert!(!vary.covers("Cookie"));
+        let merged = vary.merge(Vary::parse_header_value("ACCEPT, Cookie").unwrap());
+        assert_eq!(
+            merged.fields().map(|f| f.as_str()).collect::<Vec<_>>(),
+            vec!["Accept", "Cookie"]
+        );
+        assert!(merged.merge(Vary::Any).is_any());
+    }
+}
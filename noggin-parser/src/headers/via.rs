@@ -0,0 +1,159 @@
+use crate::from_header_value::{split_list, trim};
+use crate::token::{is_tchar, is_token};
+use crate::FromHeaderValue;
+
+/// A single hop of a `Via` header, such as `1.1 proxy.example.com (Squid)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ViaHop<'a> {
+    protocol_name: Option<&'a str>,
+    protocol_version: &'a str,
+    received_by: &'a str,
+    comment: Option<&'a str>,
+}
+
+impl<'a> ViaHop<'a> {
+    /// Returns the name of the protocol the message was received with,
+    /// `HTTP` when it was omitted.
+    pub fn protocol_name(&self) -> &'a str {
+        self.protocol_name.unwrap_or("HTTP")
+    }
+
+    /// Returns the version of the protocol the message was received with,
+    /// such as `1.1`.
+    pub fn protocol_version(&self) -> &'a str {
+        self.protocol_version
+    }
+
+    /// Returns the host and port, or the pseudonym, of the recipient.
+    pub fn received_by(&self) -> &'a str {
+        self.received_by
+    }
+
+    /// Returns the comment identifying the recipient's software, without its
+    /// parentheses.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.comment
+    }
+}
+
+fn is_received_by(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| is_tchar(b) || matches!(b, b':' | b'[' | b']'))
+}
+
+impl<'de> FromHeaderValue<'de> for ViaHop<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (protocol, rest) = trim(slice).split_once([' ', '\t'])?;
+        let (received_by, comment) = match trim(rest).split_once([' ', '\t']) {
+            Some((received_by, comment)) => (received_by, Some(trim(comment))),
+            None => (trim(rest), None),
+        };
+        let (protocol_name, protocol_version) = match protocol.split_once('/') {
+            Some((name, version)) => (Some(name), version),
+            None => (None, protocol),
+        };
+        let comment = match comment {
+            Some(comment) => Some(comment.strip_prefix('(')?.strip_suffix(')')?),
+            None => None,
+        };
+        let valid = protocol_name.is_none_or(is_token)
+            && is_token(protocol_version)
+            && is_received_by(received_by);
+        valid.then_some(ViaHop {
+            protocol_name,
+            protocol_version,
+            received_by,
+            comment,
+        })
+    }
+}
+
+/// The `Via` type is the value of a `Via` header, the intermediaries a
+/// message went through such as `1.0 fred, 1.1 p.example.net (Apache/1.1)`.
+///
+/// Hops are yielded in the order they were added, so the first one was
+/// added by the intermediary closest to the sender.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Via<'a>(&'a str);
+
+impl<'a> Via<'a> {
+    /// Returns an iterator over the hops, from the sender side to the
+    /// recipient side.
+    pub fn hops(&self) -> impl Iterator<Item = ViaHop<'a>> {
+        split_list(self.0)
+            .filter(|hop| !trim(hop).is_empty())
+            .filter_map(ViaHop::parse_header_value)
+    }
+
+    /// Returns `true` if a hop was received by `received_by`, compared
+    /// case-insensitively. A proxy finding its own name has forwarded the
+    /// message before, and is in a loop.
+    pub fn contains(&self, received_by: &str) -> bool {
+        self.hops()
+            .any(|hop| hop.received_by.eq_ignore_ascii_case(received_by))
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Via<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut hops = split_list(slice).filter(|hop| !trim(hop).is_empty());
+        let mut count = 0;
+        let valid = hops.all(|hop| {
+            count += 1;
+            ViaHop::parse_header_value(hop).is_some()
+        });
+        (valid && count > 0).then_some(Via(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    type Hop<'a> = (&'a str, &'a str, &'a str, Option<&'a str>);
+
+    #[rstest]
+    #[case("1.1 proxy.example.com", Some(("HTTP", "1.1", "proxy.example.com", None)))]
+    #[case("HTTP/2 edge:8080 (nginx)", Some(("HTTP", "2", "edge:8080", Some("nginx"))))]
+    #[case("SPDY/3 [2001:db8::1]:443", Some(("SPDY", "3", "[2001:db8::1]:443", None)))]
+    #[case(" 1.0  fred  (Acme (v2) proxy) ", Some(("HTTP", "1.0", "fred", Some("Acme (v2) proxy"))))]
+    #[case("1.1", None)]
+    #[case("1.1 proxy comment", None)]
+    #[case("/1.1 proxy", None)]
+    #[case("1.1 (proxy)", None)]
+    fn via_hop_test(#[case] input: &str, #[case] expected: Option<Hop>) {
+        let parsed = ViaHop::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|hop| (
+                hop.protocol_name(),
+                hop.protocol_version(),
+                hop.received_by(),
+                hop.comment()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("1.0 fred, 1.1 p.example.net (Apache/1.1, mod_proxy)", Some(vec!["fred", "p.example.net"]))]
+    #[case("1.1 a, , 2 b", Some(vec!["a", "b"]))]
+    #[case("", None)]
+    #[case("1.1 a, b", None)]
+    fn via_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = Via::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|via| via.hops().map(|hop| hop.received_by()).collect())
+        );
+    }
+
+    #[test]
+    fn via_loop_detection() {
+        let via = Via::parse_header_value("1.1 edge-1, 1.1 Gateway.Example (gw)").unwrap();
+        assert!(via.contains("gateway.example"));
+        assert!(!via.contains("edge-2"));
+    }
+}
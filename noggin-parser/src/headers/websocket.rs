@@ -0,0 +1,74 @@
+use base64::Engine;
+use core::fmt;
+use sha1::{Digest, Sha1};
+
+const GUID: &[u8] = b"258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// The `WebSocketAccept` type is the value of a `Sec-WebSocket-Accept`
+/// header, computed from the `Sec-WebSocket-Key` of the opening handshake.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct WebSocketAccept([u8; 28]);
+
+impl WebSocketAccept {
+    /// Computes the accept value answering `key`, the base64 encoded SHA-1
+    /// of the key followed by the WebSocket GUID.
+    pub fn from_key(key: &str) -> Self {
+        let digest = Sha1::new()
+            .chain_update(key.trim().as_bytes())
+            .chain_update(GUID)
+            .finalize();
+        let mut accept = [0; 28];
+        base64::engine::general_purpose::STANDARD
+            .encode_slice(digest, &mut accept)
+            .expect("a SHA-1 digest encodes to 28 bytes");
+        WebSocketAccept(accept)
+    }
+
+    /// Returns the accept value as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("base64 is ASCII")
+    }
+
+    /// Returns `true` if `accept`, as sent by a server, answers `key`.
+    pub fn verify(key: &str, accept: &str) -> bool {
+        WebSocketAccept::from_key(key).as_str() == accept.trim()
+    }
+}
+
+impl PartialEq<str> for WebSocketAccept {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for WebSocketAccept {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Display for WebSocketAccept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn websocket_accept() {
+        // the example of RFC 6455, section 1.3
+        let accept = WebSocketAccept::from_key("dGhlIHNhbXBsZSBub25jZQ==");
+        assert_eq!(accept, "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        assert!(WebSocketAccept::verify(
+            "dGhlIHNhbXBsZSBub25jZQ==",
+            " s3pPLMBiTxaQ9kYGzzhZRbK+xOo= "
+        ));
+        assert!(!WebSocketAccept::verify(
+            "dGhlIHNhbXBsZSBub25jZQ==",
+            "dGhlIHNhbXBsZSBub25jZQ=="
+        ));
+    }
+}
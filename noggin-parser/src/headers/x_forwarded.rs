@@ -0,0 +1,200 @@
+use super::Host;
+use crate::from_header_value::trim;
+use crate::{FromHeaderValue, HostPort, IpNet};
+use core::net::IpAddr;
+
+/// Parses an `X-Forwarded-For` entry as an IP address, which may carry a
+/// port (`192.0.2.1:80`, `[2001:db8::1]:80`) or be a bare IPv6 address.
+fn parse_ip(entry: &str) -> Option<IpAddr> {
+    if let Ok(ip) = entry.parse() {
+        return Some(ip);
+    }
+    HostPort::parse_header_value(entry)?.ip()
+}
+
+/// The `XForwardedFor` type is the value of an `X-Forwarded-For` header,
+/// the addresses of the client and of every proxy but the last one, such
+/// as `203.0.113.195, 198.51.100.178`.
+///
+/// Every proxy appends the address it received the request from, so only
+/// the entries added by trusted proxies, at the right end, can be relied
+/// upon. Use [`client_ip`](XForwardedFor::client_ip) rather than the first
+/// entry.
+///
+/// A proxy may also append its own `X-Forwarded-For` line instead of
+/// extending the existing one, so collect every line with
+/// `#[noggin(no_split)]` into a `Vec<XForwardedFor>` and resolve them
+/// together. A single field only keeps the first line, which the client
+/// controls.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct XForwardedFor<'a>(&'a str);
+
+impl<'a> XForwardedFor<'a> {
+    /// Returns an iterator over the entries, from the client side to the
+    /// server side. Entries are IP addresses, possibly with a port, or
+    /// identifiers such as `unknown`.
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &'a str> {
+        self.0
+            .split(',')
+            .map(trim)
+            .filter(|entry| !entry.is_empty())
+    }
+
+    /// Returns an iterator over the entries parsed as IP addresses, `None`
+    /// for entries that aren't one.
+    pub fn ips(&self) -> impl DoubleEndedIterator<Item = Option<IpAddr>> + 'a {
+        self.entries().map(parse_ip)
+    }
+
+    /// Resolves the address of the client that sent the request to `peer`,
+    /// the address the connection was accepted from, given every
+    /// `X-Forwarded-For` line of the request in order.
+    ///
+    /// The header is only considered if `peer` is a trusted proxy. The
+    /// lines are joined into one chain, which is walked from the right past
+    /// every trusted proxy, and the first untrusted address is the client.
+    /// If an entry isn't an IP address, the trusted proxy that added it is
+    /// returned, since nothing further left can be verified. If the whole
+    /// chain is trusted, the leftmost address is returned.
+    pub fn client_ip(
+        lines: &[XForwardedFor<'_>],
+        peer: IpAddr,
+        trusted_proxies: &[IpNet],
+    ) -> IpAddr {
+        let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+        let mut client = peer;
+        for ip in lines.iter().rev().flat_map(|line| line.ips().rev()) {
+            if !is_trusted(&client) {
+                break;
+            }
+            match ip {
+                Some(ip) => client = ip,
+                None => break,
+            }
+        }
+        client
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for XForwardedFor<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let forwarded = XForwardedFor(slice);
+        let valid = slice.split(',').map(trim).all(|entry| {
+            entry.is_empty()
+                || parse_ip(entry).is_some()
+                || HostPort::parse_header_value(entry).is_some()
+        });
+        (valid && forwarded.entries().next().is_some()).then_some(forwarded)
+    }
+}
+
+/// The `XForwardedProto` type is the value of an `X-Forwarded-Proto`
+/// header, the scheme a client used to contact the proxy, such as `https`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct XForwardedProto<'a>(&'a str);
+
+impl<'a> XForwardedProto<'a> {
+    /// Returns the scheme as it appeared in the header.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns `true` if the scheme is `https`, compared
+    /// case-insensitively.
+    pub fn is_https(&self) -> bool {
+        self.0.eq_ignore_ascii_case("https")
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for XForwardedProto<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let valid = value
+            .bytes()
+            .next()
+            .is_some_and(|b| b.is_ascii_alphabetic())
+            && value
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+        valid.then_some(XForwardedProto(value))
+    }
+}
+
+/// The `XForwardedHost` type is the value of an `X-Forwarded-Host` header,
+/// the `Host` a client sent to the proxy.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct XForwardedHost<'a>(pub Host<'a>);
+
+impl<'de> FromHeaderValue<'de> for XForwardedHost<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Host::parse_header_value(slice).map(XForwardedHost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("203.0.113.195", Some(vec!["203.0.113.195"]))]
+    #[case("203.0.113.195:41237, 2001:db8::1, [2001:db8::2]:80", Some(vec!["203.0.113.195:41237", "2001:db8::1", "[2001:db8::2]:80"]))]
+    #[case("unknown, 198.51.100.178", Some(vec!["unknown", "198.51.100.178"]))]
+    #[case(" , ", None)]
+    #[case("203.0.113.195 198.51.100.178", None)]
+    fn x_forwarded_for_test(#[case] input: &str, #[case] expected: Option<Vec<&str>>) {
+        let parsed = XForwardedFor::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|xff| xff.entries().collect()));
+    }
+
+    const PEER: &str = "10.0.0.2";
+
+    #[rstest]
+    #[case("203.0.113.195", PEER, "203.0.113.195")]
+    #[case("203.0.113.195, 10.0.0.1", PEER, "203.0.113.195")]
+    #[case("1.1.1.1, 203.0.113.195, 10.0.0.1", PEER, "203.0.113.195")]
+    #[case("203.0.113.195", "198.51.100.7", "198.51.100.7")]
+    #[case("unknown, 10.0.0.1", PEER, "10.0.0.1")]
+    #[case("10.0.0.3, 10.0.0.1", PEER, "10.0.0.3")]
+    #[case("[2001:db8::1]:443, 10.0.0.1:8080", PEER, "2001:db8::1")]
+    fn x_forwarded_for_client_ip(#[case] header: &str, #[case] peer: &str, #[case] expected: &str) {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let xff = XForwardedFor::parse_header_value(header).unwrap();
+        let client = XForwardedFor::client_ip(&[xff], peer.parse().unwrap(), &trusted);
+        assert_eq!(client, expected.parse::<IpAddr>().unwrap());
+    }
+
+    #[rstest]
+    #[case(vec!["6.6.6.6", "203.0.113.9"], "203.0.113.9")]
+    #[case(vec!["6.6.6.6", "203.0.113.9, 10.0.0.3"], "203.0.113.9")]
+    #[case(vec!["6.6.6.6, 10.0.0.4", "10.0.0.3"], "6.6.6.6")]
+    #[case(vec!["203.0.113.9", "unknown"], "10.0.0.1")]
+    #[case(vec![], "10.0.0.1")]
+    fn x_forwarded_for_client_ip_lines(#[case] lines: Vec<&str>, #[case] expected: &str) {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let lines: Vec<_> = lines
+            .into_iter()
+            .map(|line| XForwardedFor::parse_header_value(line).unwrap())
+            .collect();
+        let client = XForwardedFor::client_ip(&lines, "10.0.0.1".parse().unwrap(), &trusted);
+        assert_eq!(client, expected.parse::<IpAddr>().unwrap());
+    }
+
+    #[rstest]
+    #[case("https", Some(true))]
+    #[case(" HTTP ", Some(false))]
+    #[case("https, http", None)]
+    #[case("", None)]
+    fn x_forwarded_proto_test(#[case] input: &str, #[case] expected: Option<bool>) {
+        let parsed = XForwardedProto::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|proto| proto.is_https()));
+    }
+
+    #[test]
+    fn x_forwarded_host_test() {
+        let parsed = XForwardedHost::parse_header_value("Example.com:8443").unwrap();
+        assert!(parsed.0.is("example.com"));
+        assert_eq!(parsed.0.port(), Some(8443));
+        assert_eq!(XForwardedHost::parse_header_value("a.com, b.com"), None);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::ops::Deref;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The `HexInt` trait is implemented by the unsigned integer types that
+/// [`Hex`] can parse. It is sealed and can't be implemented outside of
+/// noggin.
+pub trait HexInt: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn from_hex(digits: &str) -> Option<Self>;
+}
+
+macro_rules! hex_int {
+    ($($int:ty),*) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl HexInt for $int {
+                fn from_hex(digits: &str) -> Option<Self> {
+                    <$int>::from_str_radix(digits, 16).ok()
+                }
+            }
+        )*
+    };
+}
+
+hex_int!(u8, u16, u32, u64, u128, usize);
+
+/// The `Hex` type is an unsigned integer given in hexadecimal, with an
+/// optional `0x` prefix, for custom checksums or chunk sizes surfaced in
+/// trailers.
+///
+/// Digits are accepted in either case, a sign is not accepted.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Hex<T>(pub T);
+
+impl<T> Deref for Hex<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'de, T: HexInt> FromHeaderValue<'de> for Hex<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let digits = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        T::from_hex(digits).map(Hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("ff", Some(0xff))]
+    #[case(" 0x1A2b ", Some(0x1a2b))]
+    #[case("0XFFFFFFFF", Some(u32::MAX))]
+    #[case("0x100000000", None)]
+    #[case("0x", None)]
+    #[case("+ff", None)]
+    #[case("-1", None)]
+    #[case("fg", None)]
+    fn hex_test(#[case] input: &str, #[case] expected: Option<u32>) {
+        assert_eq!(
+            expected,
+            Hex::<u32>::parse_header_value(input).map(|hex| hex.0)
+        );
+    }
+
+    #[test]
+    fn hex_u8_overflow() {
+        assert_eq!(Some(Hex(0xffu8)), Hex::parse_header_value("ff"));
+        assert_eq!(None, Hex::<u8>::parse_header_value("100"));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::FromHeaderValue;
+use core::fmt;
+use core::net::IpAddr;
+
+/// The `HostPort` type is a host with an optional port, as found in the
+/// `Host` header or the `for=` and `host=` parameters of `Forwarded`.
+///
+/// The host is either a registered name, an IPv4 address or a bracketed
+/// IPv6 address (`[2001:db8::1]:443`). The host is borrowed from the header
+/// without the brackets.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HostPort<'a> {
+    host: &'a str,
+    port: Option<u16>,
+}
+
+impl<'a> HostPort<'a> {
+    /// Returns the host, without the brackets of an IPv6 address.
+    pub fn host(&self) -> &'a str {
+        self.host
+    }
+
+    /// Returns the port, if one was given.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Returns the host as an IP address if it is one.
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.host.parse().ok()
+    }
+}
+
+fn is_reg_name(host: &str) -> bool {
+    !host.is_empty()
+        && host.bytes().all(|b| {
+            b.is_ascii_alphanumeric()
+                || matches!(
+                    b,
+                    b'-' | b'.'
+                        | b'_'
+                        | b'~'
+                        | b'%'
+                        | b'!'
+                        | b'$'
+                        | b'&'
+                        | b'\''
+                        | b'('
+                        | b')'
+                        | b'*'
+                        | b'+'
+                        | b';'
+                        | b'='
+                )
+        })
+}
+
+fn parse_port(port: &str) -> Option<u16> {
+    parse_digits(port)?.try_into().ok()
+}
+
+impl<'de> FromHeaderValue<'de> for HostPort<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let (host, port) = if let Some(bracketed) = value.strip_prefix('[') {
+            let (host, rest) = bracketed.split_once(']')?;
+            host.parse::<core::net::Ipv6Addr>().ok()?;
+            let port = match rest {
+                "" => None,
+                _ => Some(parse_port(rest.strip_prefix(':')?)?),
+            };
+            (host, port)
+        } else {
+            let (host, port) = match value.split_once(':') {
+                Some((host, port)) => (host, Some(parse_port(port)?)),
+                None => (value, None),
+            };
+            if !is_reg_name(host) {
+                return None;
+            }
+            (host, port)
+        };
+        Some(HostPort { host, port })
+    }
+}
+
+impl<'a> fmt::Display for HostPort<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "[{}]", self.host)?;
+        } else {
+            f.write_str(self.host)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("example.com", Some(("example.com", None)))]
+    #[case(" example.com:8080 ", Some(("example.com", Some(8080))))]
+    #[case("192.0.2.1:80", Some(("192.0.2.1", Some(80))))]
+    #[case("[2001:db8::1]:443", Some(("2001:db8::1", Some(443))))]
+    #[case("[::1]", Some(("::1", None)))]
+    #[case("_hidden", Some(("_hidden", None)))]
+    #[case("", None)]
+    #[case("example.com:", None)]
+    #[case("example.com:+80", None)]
+    #[case("example.com:70000", None)]
+    #[case("::1", None)]
+    #[case("[example.com]", None)]
+    #[case("[::1]443", None)]
+    #[case("exa mple.com", None)]
+    #[case("user@example.com", None)]
+    fn host_port_test(#[case] input: &str, #[case] expected: Option<(&str, Option<u16>)>) {
+        let parsed = HostPort::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|host_port| (host_port.host(), host_port.port()))
+        );
+    }
+
+    #[rstest]
+    #[case("example.com:8080")]
+    #[case("[2001:db8::1]:443")]
+    #[case("[::1]")]
+    fn host_port_display(#[case] input: &str) {
+        let parsed = HostPort::parse_header_value(input).unwrap();
+        assert_eq!(parsed.to_string(), input);
+    }
+
+    #[test]
+    fn host_port_ip() {
+        let parsed = HostPort::parse_header_value("[::1]:80").unwrap();
+        assert_eq!(
+            parsed.ip(),
+            Some(IpAddr::V6(core::net::Ipv6Addr::LOCALHOST))
+        );
+        let parsed = HostPort::parse_header_value("example.com").unwrap();
+        assert_eq!(parsed.ip(), None);
+    }
+}
@@ -0,0 +1,552 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const LONG_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The `HttpDate` type is a point in time with second precision as found in
+/// the `Date`, `Expires`, `Last-Modified` or `If-Modified-Since` headers.
+///
+/// All three formats of RFC 9110 are accepted: IMF-fixdate
+/// (`Sun, 06 Nov 1994 08:49:37 GMT`), the obsolete RFC 850 format
+/// (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime
+/// (`Sun Nov  6 08:49:37 1994`). Formatting always produces IMF-fixdate.
+///
+/// The two-digit year of an RFC 850 date is placed within 50 years of the
+/// current date, as RFC 9110 requires, see
+/// [`parse_relative_to`](HttpDate::parse_relative_to). Without the `std`
+/// feature there is no clock, and years are placed in 1970 to 2069
+/// instead.
+///
+/// With the `std` feature, `SystemTime` can be used as a header value
+/// directly and is parsed the same way.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HttpDate {
+    unix_timestamp: i64,
+}
+
+/// The error returned when converting an [`HttpDate`] into a date type that
+/// can't represent it.
+#[derive(thiserror::Error, Clone, Copy, PartialEq, Eq, Debug)]
+#[error("the http date is out of range")]
+pub struct DateOutOfRange;
+
+impl HttpDate {
+    /// The earliest date an HTTP-date can represent,
+    /// `Sat, 01 Jan 0000 00:00:00 GMT`.
+    pub const MIN: HttpDate = HttpDate::from_unix_timestamp(-62167219200);
+
+    /// The latest date an HTTP-date can represent,
+    /// `Fri, 31 Dec 9999 23:59:59 GMT`.
+    pub const MAX: HttpDate = HttpDate::from_unix_timestamp(253402300799);
+
+    /// Creates a date from the number of seconds since the Unix epoch.
+    pub const fn from_unix_timestamp(unix_timestamp: i64) -> Self {
+        HttpDate { unix_timestamp }
+    }
+
+    /// Returns the number of seconds since the Unix epoch.
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.unix_timestamp
+    }
+
+    /// Parses `value` as a header value, reading the two-digit year of an
+    /// RFC 850 date relative to `now`: the year with those last two digits
+    /// at most 50 years after `now`, or else the most recent past one.
+    pub fn parse_relative_to(value: &str, now: HttpDate) -> Option<Self> {
+        parse_http_date(value, || now)
+    }
+
+    fn from_parts(parts: DateParts) -> Option<Self> {
+        let DateParts {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } = parts;
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return None;
+        }
+        let days = days_from_civil(year, month, day);
+        let seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+        Some(HttpDate::from_unix_timestamp(days * 86400 + seconds))
+    }
+}
+
+struct DateParts {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days between 1970-01-01 and the given date of the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn parse_number(digits: &str, len: usize) -> Option<u32> {
+    if digits.len() != len || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn parse_month(month: &str) -> Option<u32> {
+    MONTHS
+        .iter()
+        .position(|name| *name == month)
+        .map(|index| index as u32 + 1)
+}
+
+fn parse_time(time: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = time.split(':');
+    let hour = parse_number(parts.next()?, 2)?;
+    let minute = parse_number(parts.next()?, 2)?;
+    let second = parse_number(parts.next()?, 2)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((hour, minute, second))
+}
+
+/// `Sun, 06 Nov 1994 08:49:37 GMT`
+fn parse_imf_fixdate(value: &str) -> Option<DateParts> {
+    let (weekday, rest) = value.split_once(", ")?;
+    if !WEEKDAYS.contains(&weekday) {
+        return None;
+    }
+    let mut parts = rest.split(' ');
+    let day = parse_number(parts.next()?, 2)?;
+    let month = parse_month(parts.next()?)?;
+    let year = parse_number(parts.next()?, 4)?;
+    let (hour, minute, second) = parse_time(parts.next()?)?;
+    if parts.next()? != "GMT" || parts.next().is_some() {
+        return None;
+    }
+    Some(DateParts {
+        year: i64::from(year),
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    })
+}
+
+/// `Sunday, 06-Nov-94 08:49:37 GMT`
+fn parse_rfc850_date(value: &str, now: impl FnOnce() -> HttpDate) -> Option<DateParts> {
+    let (weekday, rest) = value.split_once(", ")?;
+    if !LONG_WEEKDAYS.contains(&weekday) {
+        return None;
+    }
+    let mut parts = rest.split(' ');
+    let mut date = parts.next()?.split('-');
+    let day = parse_number(date.next()?, 2)?;
+    let month = parse_month(date.next()?)?;
+    let year = parse_number(date.next()?, 2)?;
+    if date.next().is_some() {
+        return None;
+    }
+    let (hour, minute, second) = parse_time(parts.next()?)?;
+    if parts.next()? != "GMT" || parts.next().is_some() {
+        return None;
+    }
+    // a year more than 50 years ahead is read as the most recent past one
+    let (current_year, _, _) = civil_from_days(now().unix_timestamp.div_euclid(86400));
+    let year = current_year - current_year.rem_euclid(100) + i64::from(year);
+    let year = if year > current_year + 50 {
+        year - 100
+    } else if year <= current_year - 50 {
+        year + 100
+    } else {
+        year
+    };
+    Some(DateParts {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    })
+}
+
+/// `Sun Nov  6 08:49:37 1994`
+fn parse_asctime_date(value: &str) -> Option<DateParts> {
+    if value.len() != 24 || !value.is_ascii() || value.as_bytes()[3] != b' ' {
+        return None;
+    }
+    if !WEEKDAYS.contains(&&value[..3]) || value.as_bytes()[7] != b' ' {
+        return None;
+    }
+    let month = parse_month(&value[4..7])?;
+    let day = match &value[8..10] {
+        day if day.starts_with(' ') => parse_number(&day[1..], 1)?,
+        day => parse_number(day, 2)?,
+    };
+    if value.as_bytes()[10] != b' ' || value.as_bytes()[19] != b' ' {
+        return None;
+    }
+    let (hour, minute, second) = parse_time(&value[11..19])?;
+    let year = parse_number(&value[20..], 4)?;
+    Some(DateParts {
+        year: i64::from(year),
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    })
+}
+
+fn parse_http_date(value: &str, now: impl FnOnce() -> HttpDate) -> Option<HttpDate> {
+    let value = trim(value);
+    let parts = parse_imf_fixdate(value)
+        .or_else(|| parse_rfc850_date(value, now))
+        .or_else(|| parse_asctime_date(value))?;
+    HttpDate::from_parts(parts)
+}
+
+/// The date two-digit years are read relative to, `2019-01-01` without a
+/// clock so they fall in 1970 to 2069.
+fn current_date() -> HttpDate {
+    #[cfg(feature = "std")]
+    return HttpDate::from(SystemTime::now());
+    #[cfg(not(feature = "std"))]
+    return HttpDate::from_unix_timestamp(1546300800);
+}
+
+impl<'de> FromHeaderValue<'de> for HttpDate {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        parse_http_date(slice, current_date)
+    }
+}
+
+impl fmt::Display for HttpDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.unix_timestamp.div_euclid(86400);
+        let seconds = self.unix_timestamp.rem_euclid(86400);
+        let (year, month, day) = civil_from_days(days);
+        // 1970-01-01 was a thursday
+        let weekday = WEEKDAYS[(days + 4).rem_euclid(7) as usize];
+        write!(
+            f,
+            "{weekday}, {day:02} {} {year:04} {:02}:{:02}:{:02} GMT",
+            MONTHS[month as usize - 1],
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<HttpDate> for SystemTime {
+    fn from(date: HttpDate) -> Self {
+        let offset = Duration::from_secs(date.unix_timestamp.unsigned_abs());
+        if date.unix_timestamp >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+}
+
+/// Truncates the time to whole seconds.
+#[cfg(feature = "std")]
+impl From<SystemTime> for HttpDate {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => HttpDate::from_unix_timestamp(since.as_secs() as i64),
+            Err(before) => {
+                let before = before.duration();
+                let seconds = before.as_secs() as i64 + i64::from(before.subsec_nanos() > 0);
+                HttpDate::from_unix_timestamp(-seconds)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> FromHeaderValue<'de> for SystemTime {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        HttpDate::parse_header_value(slice).map(SystemTime::from)
+    }
+}
+
+/// Fails for timestamps beyond the years chrono supports, which parsed
+/// HTTP-dates never are.
+#[cfg(feature = "chrono")]
+impl TryFrom<HttpDate> for chrono::DateTime<chrono::Utc> {
+    type Error = DateOutOfRange;
+
+    fn try_from(date: HttpDate) -> Result<Self, Self::Error> {
+        chrono::DateTime::from_timestamp(date.unix_timestamp, 0).ok_or(DateOutOfRange)
+    }
+}
+
+/// Parses an HTTP-date, or an RFC 3339 timestamp as used by some custom
+/// headers, converted to UTC.
+#[cfg(feature = "chrono")]
+impl<'de> FromHeaderValue<'de> for chrono::DateTime<chrono::Utc> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        chrono::DateTime::<chrono::FixedOffset>::parse_header_value(slice)
+            .map(|date| date.with_timezone(&chrono::Utc))
+    }
+}
+
+/// Parses an HTTP-date, which is always in GMT, or an RFC 3339 timestamp
+/// keeping its offset.
+#[cfg(feature = "chrono")]
+impl<'de> FromHeaderValue<'de> for chrono::DateTime<chrono::FixedOffset> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        match HttpDate::parse_header_value(slice) {
+            Some(date) => chrono::DateTime::<chrono::Utc>::try_from(date)
+                .ok()
+                .map(|date| date.fixed_offset()),
+            None => chrono::DateTime::parse_from_rfc3339(trim(slice)).ok(),
+        }
+    }
+}
+
+/// Fails for timestamps beyond the years time supports, which parsed
+/// HTTP-dates never are.
+#[cfg(feature = "time")]
+impl TryFrom<HttpDate> for time::OffsetDateTime {
+    type Error = DateOutOfRange;
+
+    fn try_from(date: HttpDate) -> Result<Self, Self::Error> {
+        time::OffsetDateTime::from_unix_timestamp(date.unix_timestamp).map_err(|_| DateOutOfRange)
+    }
+}
+
+/// Parses an HTTP-date, which is always in UTC.
+#[cfg(feature = "time")]
+impl<'de> FromHeaderValue<'de> for time::OffsetDateTime {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        HttpDate::parse_header_value(slice).and_then(|date| date.try_into().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Sun, 06 Nov 1994 08:49:37 GMT", Some(784111777))]
+    #[case("Sunday, 06-Nov-94 08:49:37 GMT", Some(784111777))]
+    #[case("Sun Nov  6 08:49:37 1994", Some(784111777))]
+    #[case(" Thu, 01 Jan 1970 00:00:00 GMT ", Some(0))]
+    #[case("Thu, 29 Feb 2024 23:59:59 GMT", Some(1709251199))]
+    #[case("Saturday, 01-Jan-00 00:00:00 GMT", Some(946684800))]
+    #[case("Wed Dec 31 23:59:59 1969", Some(-1))]
+    #[case("Thu, 29 Feb 2023 00:00:00 GMT", None)]
+    #[case("Sun, 06 Nov 1994 24:00:00 GMT", None)]
+    #[case("Sun, 06 Nov 1994 08:49:37 UTC", None)]
+    #[case("Sun, 6 Nov 1994 08:49:37 GMT", None)]
+    #[case("Sun, 06 Foo 1994 08:49:37 GMT", None)]
+    #[case("Sun Nov 6 08:49:37 1994", None)]
+    #[case("1994-11-06T08:49:37Z", None)]
+    #[case("Sun Nov  6 08:49:37 199é", None)]
+    fn http_date_test(#[case] input: &str, #[case] expected: Option<i64>) {
+        let parsed = HttpDate::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|date| date.unix_timestamp()));
+    }
+
+    #[rstest]
+    #[case(784111777, "Sun, 06 Nov 1994 08:49:37 GMT")]
+    #[case(0, "Thu, 01 Jan 1970 00:00:00 GMT")]
+    #[case(1709251199, "Thu, 29 Feb 2024 23:59:59 GMT")]
+    #[case(-1, "Wed, 31 Dec 1969 23:59:59 GMT")]
+    fn http_date_display(#[case] timestamp: i64, #[case] expected: &str) {
+        assert_eq!(
+            HttpDate::from_unix_timestamp(timestamp).to_string(),
+            expected
+        );
+    }
+
+    const NOW: &str = "Fri, 16 Oct 2026 00:00:00 GMT";
+
+    #[rstest]
+    #[case("Friday, 16-Oct-26 00:00:00 GMT", NOW, "Fri, 16 Oct 2026 00:00:00 GMT")]
+    #[case(
+        "Wednesday, 01-Jan-76 00:00:00 GMT",
+        NOW,
+        "Wed, 01 Jan 2076 00:00:00 GMT"
+    )]
+    #[case(
+        "Saturday, 01-Jan-77 00:00:00 GMT",
+        NOW,
+        "Sat, 01 Jan 1977 00:00:00 GMT"
+    )]
+    #[case("Sunday, 06-Nov-94 08:49:37 GMT", NOW, "Sun, 06 Nov 1994 08:49:37 GMT")]
+    #[case(
+        "Saturday, 01-Jan-00 00:00:00 GMT",
+        NOW,
+        "Sat, 01 Jan 2000 00:00:00 GMT"
+    )]
+    #[case(
+        "Friday, 01-Jan-40 00:00:00 GMT",
+        "Sun, 01 Jan 2090 00:00:00 GMT",
+        "Fri, 01 Jan 2140 00:00:00 GMT"
+    )]
+    #[case(
+        "Tuesday, 01-Jan-41 00:00:00 GMT",
+        "Sun, 01 Jan 2090 00:00:00 GMT",
+        "Tue, 01 Jan 2041 00:00:00 GMT"
+    )]
+    #[case(
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        "Sun, 01 Jan 2090 00:00:00 GMT",
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    )]
+    fn http_date_rfc850_year(#[case] input: &str, #[case] now: &str, #[case] expected: &str) {
+        let now = HttpDate::parse_header_value(now).unwrap();
+        let parsed = HttpDate::parse_relative_to(input, now);
+        assert_eq!(parsed.map(|date| date.to_string()), Some(expected.into()));
+    }
+
+    #[test]
+    fn http_date_bounds() {
+        assert_eq!(
+            HttpDate::parse_header_value(&HttpDate::MIN.to_string()),
+            Some(HttpDate::MIN)
+        );
+        assert_eq!(
+            HttpDate::parse_header_value(&HttpDate::MAX.to_string()),
+            Some(HttpDate::MAX)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case("Sun, 06 Nov 1994 08:49:37 GMT", Some((784111777, 0)))]
+    #[case("Sun Nov  6 08:49:37 1994", Some((784111777, 0)))]
+    #[case("1994-11-06T09:49:37+01:00", Some((784111777, 3600)))]
+    #[case("not a date", None)]
+    fn chrono_fixed_offset_test(#[case] input: &str, #[case] expected: Option<(i64, i32)>) {
+        let parsed = chrono::DateTime::<chrono::FixedOffset>::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|date| (date.timestamp(), date.offset().local_minus_utc()))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case("Sun, 06 Nov 1994 08:49:37 GMT", Some(784111777))]
+    #[case("1994-11-06T09:49:37+01:00", Some(784111777))]
+    #[case("Sun, 06 Nov 1994", None)]
+    fn chrono_utc_test(#[case] input: &str, #[case] expected: Option<i64>) {
+        let parsed = chrono::DateTime::<chrono::Utc>::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|date| date.timestamp()));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case(784111777, Ok(784111777))]
+    #[case(999999999999999, Err(DateOutOfRange))]
+    #[case(i64::MIN, Err(DateOutOfRange))]
+    fn chrono_try_from_test(#[case] timestamp: i64, #[case] expected: Result<i64, DateOutOfRange>) {
+        let date = HttpDate::from_unix_timestamp(timestamp);
+        let converted = chrono::DateTime::<chrono::Utc>::try_from(date);
+        assert_eq!(expected, converted.map(|date| date.timestamp()));
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case("Sun, 06 Nov 1994 08:49:37 GMT", Some(784111777))]
+    #[case("Sunday, 06-Nov-94 08:49:37 GMT", Some(784111777))]
+    #[case("Fri, 31 Dec 9999 23:59:59 GMT", Some(253402300799))]
+    #[case("1994-11-06T08:49:37Z", None)]
+    fn time_offset_date_time_test(#[case] input: &str, #[case] expected: Option<i64>) {
+        let parsed = time::OffsetDateTime::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|date| date.unix_timestamp()));
+        assert!(parsed.is_none_or(|date| date.offset().is_utc()));
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case(784111777, Ok(784111777))]
+    #[case(999999999999999, Err(DateOutOfRange))]
+    #[case(i64::MIN, Err(DateOutOfRange))]
+    fn time_try_from_test(#[case] timestamp: i64, #[case] expected: Result<i64, DateOutOfRange>) {
+        let date = HttpDate::from_unix_timestamp(timestamp);
+        let converted = time::OffsetDateTime::try_from(date);
+        assert_eq!(expected, converted.map(|date| date.unix_timestamp()));
+    }
+
+    #[test]
+    fn system_time_test() {
+        let parsed = SystemTime::parse_header_value("Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(parsed, Some(UNIX_EPOCH + Duration::from_secs(784111777)));
+        let before = UNIX_EPOCH - Duration::from_millis(500);
+        assert_eq!(HttpDate::from(before).unix_timestamp(), -1);
+    }
+}
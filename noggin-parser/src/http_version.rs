@@ -0,0 +1,84 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// The `HttpVersion` type is the protocol version of a message, as found in
+/// its start line (`HTTP/1.1`).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum HttpVersion {
+    Http10,
+    Http11,
+    Http2,
+    Http3,
+}
+
+impl HttpVersion {
+    /// Returns the version as it appears in a start line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpVersion::Http10 => "HTTP/1.0",
+            HttpVersion::Http11 => "HTTP/1.1",
+            HttpVersion::Http2 => "HTTP/2",
+            HttpVersion::Http3 => "HTTP/3",
+        }
+    }
+}
+
+/// The error returned when parsing an unknown [`HttpVersion`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnknownHttpVersion;
+
+impl fmt::Display for UnknownHttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown HTTP version")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownHttpVersion {}
+
+/// Parses the version of a start line, the `HTTP` name is case-sensitive.
+/// `HTTP/2.0` and `HTTP/3.0` are accepted as aliases.
+impl FromStr for HttpVersion {
+    type Err = UnknownHttpVersion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "HTTP/1.0" => Ok(HttpVersion::Http10),
+            "HTTP/1.1" => Ok(HttpVersion::Http11),
+            "HTTP/2" | "HTTP/2.0" => Ok(HttpVersion::Http2),
+            "HTTP/3" | "HTTP/3.0" => Ok(HttpVersion::Http3),
+            _ => Err(UnknownHttpVersion),
+        }
+    }
+}
+
+impl fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HTTP/1.0", Some(HttpVersion::Http10))]
+    #[case("HTTP/1.1", Some(HttpVersion::Http11))]
+    #[case("HTTP/2", Some(HttpVersion::Http2))]
+    #[case("HTTP/2.0", Some(HttpVersion::Http2))]
+    #[case("HTTP/3", Some(HttpVersion::Http3))]
+    #[case("http/1.1", None)]
+    #[case("HTTP/1.2", None)]
+    #[case("", None)]
+    fn http_version_test(#[case] input: &str, #[case] expected: Option<HttpVersion>) {
+        assert_eq!(expected, input.parse().ok());
+    }
+
+    #[test]
+    fn http_version_display() {
+        assert_eq!(HttpVersion::Http11.to_string(), "HTTP/1.1");
+        assert_eq!(HttpVersion::Http2.to_string(), "HTTP/2");
+    }
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+use core::net::IpAddr;
+use core::str::FromStr;
+
+/// The `IpNet` type is an IP network in CIDR notation, such as `10.0.0.0/8`
+/// or `2001:db8::/32`, used to describe trusted proxies.
+///
+/// The host bits of the address are kept as given, only the prefix takes
+/// part in [`contains`](IpNet::contains).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IpNet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNet {
+    /// Creates a network, returns `None` if `prefix_len` exceeds the length
+    /// of the address.
+    pub const fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
+        let max = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix_len > max {
+            return None;
+        }
+        Some(IpNet { addr, prefix_len })
+    }
+
+    /// Returns the address of the network.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the number of leading bits that identify the network.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns `true` if `ip` is within the network. IPv4 addresses never
+    /// belong to an IPv6 network and vice versa, IPv4-mapped IPv6
+    /// addresses are compared as IPv4.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<IpAddr> for IpNet {
+    /// Creates a network containing `addr` only.
+    fn from(addr: IpAddr) -> Self {
+        let prefix_len = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        IpNet { addr, prefix_len }
+    }
+}
+
+/// The error returned when parsing an invalid [`IpNet`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidIpNet;
+
+impl fmt::Display for InvalidIpNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid IP network")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidIpNet {}
+
+/// Parses `address/prefix`, a bare address is a network of its own.
+impl FromStr for IpNet {
+    type Err = InvalidIpNet;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((addr, prefix_len)) = s.split_once('/') else {
+            return s
+                .parse::<IpAddr>()
+                .map(IpNet::from)
+                .map_err(|_| InvalidIpNet);
+        };
+        let addr = addr.parse().map_err(|_| InvalidIpNet)?;
+        if !prefix_len.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InvalidIpNet);
+        }
+        let prefix_len = prefix_len.parse().map_err(|_| InvalidIpNet)?;
+        IpNet::new(addr, prefix_len).ok_or(InvalidIpNet)
+    }
+}
+
+impl fmt::Display for IpNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("10.0.0.0/8", "10.1.2.3", true)]
+    #[case("10.0.0.0/8", "11.0.0.1", false)]
+    #[case("192.0.2.1", "192.0.2.1", true)]
+    #[case("192.0.2.1", "192.0.2.2", false)]
+    #[case("0.0.0.0/0", "203.0.113.9", true)]
+    #[case("0.0.0.0/0", "::1", false)]
+    #[case("2001:db8::/32", "2001:db8:cafe::17", true)]
+    #[case("2001:db8::/32", "2001:db9::1", false)]
+    #[case("::/0", "::1", true)]
+    #[case("10.0.0.0/8", "::ffff:10.0.0.1", true)]
+    fn ip_net_contains(#[case] net: &str, #[case] ip: &str, #[case] expected: bool) {
+        let net: IpNet = net.parse().unwrap();
+        assert_eq!(expected, net.contains(&ip.parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case("10.0.0.0/8", Some("10.0.0.0/8"))]
+    #[case("::1", Some("::1/128"))]
+    #[case("10.0.0.0/33", None)]
+    #[case("10.0.0.0/+8", None)]
+    #[case("10.0.0.0/", None)]
+    #[case("example.com/8", None)]
+    fn ip_net_parse(#[case] input: &str, #[case] expected: Option<&str>) {
+        let parsed = input.parse::<IpNet>().ok();
+        assert_eq!(
+            expected.map(String::from),
+            parsed.map(|net| net.to_string())
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `LanguageTag` type is a BCP 47 language tag such as `en`, `en-US` or
+/// `zh-Hant-TW`, as used by `Content-Language` and `Accept-Language`.
+///
+/// The syntax of the tag is validated, but not whether its subtags are
+/// registered. The tag is borrowed from the header as it appeared, use
+/// [`matches`](LanguageTag::matches) to compare tags case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LanguageTag<'a>(&'a str);
+
+impl<'a> LanguageTag<'a> {
+    /// Returns the tag as it appeared in the header.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns the primary language subtag, such as `en` in `en-US`.
+    pub fn primary_language(&self) -> &'a str {
+        self.subtags().next().unwrap_or(self.0)
+    }
+
+    /// Returns an iterator over the subtags of the tag.
+    pub fn subtags(&self) -> impl Iterator<Item = &'a str> {
+        self.0.split('-')
+    }
+
+    /// Returns `true` if both tags are equal, compared case-insensitively.
+    pub fn matches(&self, other: &LanguageTag<'_>) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+
+    /// Returns `true` if `self` is `other` or one of its more specific
+    /// variants, for example `en-US` is within `en` but not within `en-GB`.
+    /// Every tag is within the `*` wildcard.
+    pub fn is_within(&self, other: &LanguageTag<'_>) -> bool {
+        let prefix = other.0.len();
+        other.0 == "*"
+            || self.0.len() >= prefix
+                && self.0[..prefix].eq_ignore_ascii_case(other.0)
+                && (self.0.len() == prefix || self.0.as_bytes()[prefix] == b'-')
+    }
+}
+
+fn is_alpha(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_digits(subtag: &str, len: usize) -> bool {
+    subtag.len() == len && subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_variant(subtag: &str) -> bool {
+    is_alphanumeric(subtag, 5, 8)
+        || (subtag.len() == 4
+            && subtag.as_bytes()[0].is_ascii_digit()
+            && is_alphanumeric(subtag, 4, 4))
+}
+
+fn is_singleton(subtag: &str) -> bool {
+    subtag.len() == 1 && subtag.as_bytes()[0].is_ascii_alphanumeric()
+}
+
+/// Validates the private use tag syntax `x-` followed by one or more subtags
+/// of 1 to 8 alphanumerics.
+fn is_private_use<'a>(mut subtags: impl Iterator<Item = &'a str>) -> bool {
+    let mut any = false;
+    for subtag in subtags.by_ref() {
+        if !is_alphanumeric(subtag, 1, 8) {
+            return false;
+        }
+        any = true;
+    }
+    any
+}
+
+/// Validates the `langtag` production of RFC 5646, private use tags and the
+/// `*` wildcard of `Accept-Language`. Grandfathered tags are only accepted
+/// when they also match the regular syntax.
+fn is_language_tag(tag: &str) -> bool {
+    if tag == "*" {
+        return true;
+    }
+    let mut subtags = tag.split('-').peekable();
+    let Some(first) = subtags.next() else {
+        return false;
+    };
+    if first.eq_ignore_ascii_case("x") {
+        return is_private_use(subtags);
+    }
+    if !is_alpha(first, 2, 8) {
+        return false;
+    }
+    // up to three extended language subtags after a 2 or 3 letter language
+    if first.len() <= 3 {
+        for _ in 0..3 {
+            match subtags.peek() {
+                Some(subtag) if is_alpha(subtag, 3, 3) => {
+                    subtags.next();
+                }
+                _ => break,
+            }
+        }
+    }
+    if let Some(subtag) = subtags.peek() {
+        if is_alpha(subtag, 4, 4) {
+            subtags.next();
+        }
+    }
+    if let Some(subtag) = subtags.peek() {
+        if is_alpha(subtag, 2, 2) || is_digits(subtag, 3) {
+            subtags.next();
+        }
+    }
+    while let Some(subtag) = subtags.peek() {
+        if !is_variant(subtag) {
+            break;
+        }
+        subtags.next();
+    }
+    while let Some(subtag) = subtags.next() {
+        if subtag.eq_ignore_ascii_case("x") {
+            return is_private_use(subtags);
+        }
+        if !is_singleton(subtag) {
+            return false;
+        }
+        let mut any = false;
+        while let Some(extension) = subtags.peek() {
+            if !is_alphanumeric(extension, 2, 8) {
+                break;
+            }
+            subtags.next();
+            any = true;
+        }
+        if !any {
+            return false;
+        }
+    }
+    true
+}
+
+impl<'de> FromHeaderValue<'de> for LanguageTag<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        is_language_tag(value).then_some(LanguageTag(value))
+    }
+}
+
+impl<'a> fmt::Display for LanguageTag<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("en", true)]
+    #[case(" en-US ", true)]
+    #[case("zh-Hant-TW", true)]
+    #[case("zh-yue-HK", true)]
+    #[case("es-419", true)]
+    #[case("sl-rozaj-biske", true)]
+    #[case("de-CH-1901", true)]
+    #[case("en-US-u-islamcal", true)]
+    #[case("en-a-bbb-x-a-ccc", true)]
+    #[case("x-whatever", true)]
+    #[case("*", true)]
+    #[case("", false)]
+    #[case("e", false)]
+    #[case("en-", false)]
+    #[case("en_US", false)]
+    #[case("toolonglang", false)]
+    #[case("en-a", false)]
+    #[case("en-a-x", false)]
+    #[case("x-", false)]
+    #[case("1en", false)]
+    fn language_tag_test(#[case] input: &str, #[case] valid: bool) {
+        assert_eq!(valid, LanguageTag::parse_header_value(input).is_some());
+    }
+
+    #[rstest]
+    #[case("en-US", "en", true)]
+    #[case("EN-us", "en-US", true)]
+    #[case("en", "en", true)]
+    #[case("en", "en-US", false)]
+    #[case("en-GB", "en-US", false)]
+    #[case("eng", "en", false)]
+    #[case("fr-CA", "*", true)]
+    fn language_tag_within(#[case] tag: &str, #[case] range: &str, #[case] expected: bool) {
+        let tag = LanguageTag::parse_header_value(tag).unwrap();
+        let range = LanguageTag::parse_header_value(range).unwrap();
+        assert_eq!(expected, tag.is_within(&range));
+    }
+
+    #[test]
+    fn language_tag_parts() {
+        let tag = LanguageTag::parse_header_value("zh-Hant-TW").unwrap();
+        assert_eq!(tag.primary_language(), "zh");
+        assert_eq!(tag.subtags().collect::<Vec<_>>(), vec!["zh", "Hant", "TW"]);
+        assert!(tag.matches(&LanguageTag::parse_header_value("ZH-hant-tw").unwrap()));
+        assert_eq!(tag.to_string(), "zh-Hant-TW");
+    }
+}
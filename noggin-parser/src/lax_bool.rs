@@ -0,0 +1,63 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::ops::Deref;
+
+/// The `LaxBool` type is a boolean that also accepts the spellings found in
+/// real-world flag headers such as `DNT: 1` or `Upgrade-Insecure-Requests: 1`.
+///
+/// `1`/`0`, `yes`/`no`, `on`/`off` and `true`/`false` are accepted, compared
+/// case-insensitively. A plain `bool` field only accepts `true` and `false`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct LaxBool(pub bool);
+
+impl Deref for LaxBool {
+    type Target = bool;
+
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+impl From<LaxBool> for bool {
+    fn from(lax: LaxBool) -> Self {
+        lax.0
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for LaxBool {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        const TRUE: [&str; 4] = ["1", "yes", "on", "true"];
+        const FALSE: [&str; 4] = ["0", "no", "off", "false"];
+        let value = trim(slice);
+        let matches = |spelling: &&str| spelling.eq_ignore_ascii_case(value);
+        if TRUE.iter().any(matches) {
+            Some(LaxBool(true))
+        } else if FALSE.iter().any(matches) {
+            Some(LaxBool(false))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1", Some(true))]
+    #[case("0", Some(false))]
+    #[case(" yes ", Some(true))]
+    #[case("No", Some(false))]
+    #[case("ON", Some(true))]
+    #[case("off", Some(false))]
+    #[case("TRUE", Some(true))]
+    #[case("False", Some(false))]
+    #[case("2", None)]
+    #[case("y", None)]
+    #[case("", None)]
+    fn lax_bool_test(#[case] input: &str, #[case] expected: Option<bool>) {
+        assert_eq!(expected, LaxBool::parse_header_value(input).map(bool::from));
+    }
+}
@@ -1,9 +1,86 @@
 //! Do not import or use this crate directly, import and use `noggin` instead.
 //! See: [noggin](https://docs.rs/noggin/latest/noggin/)
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "base64")]
+mod base64_value;
+#[cfg(feature = "bumpalo")]
+mod bump_head_parser;
+#[cfg(feature = "alloc")]
+mod ext_value;
+mod field_parser;
 mod from_header_value;
+mod header_lines;
+mod header_name;
 mod header_parser;
+pub mod headers;
+mod hex;
+mod host_port;
+mod http_date;
+mod http_version;
+mod ip_net;
+mod language_tag;
+mod lax_bool;
+mod method;
+mod millis;
+mod non_empty;
+mod obs_text;
+#[cfg(feature = "alloc")]
+mod quoted_string;
+#[cfg(feature = "alloc")]
+mod raw_headers;
+mod rejected_value;
+mod repeated_header_value;
+#[doc(hidden)]
+pub mod runtime;
+pub mod structured;
+mod token;
+mod uri_reference;
+mod weighted;
+#[cfg(feature = "alloc")]
+mod without_comments;
 
+#[cfg(feature = "base64")]
+pub use base64_value::Base64;
+#[cfg(all(feature = "base64", feature = "alloc"))]
+pub use base64_value::Base64Bytes;
+#[cfg(feature = "bumpalo")]
+pub use bump_head_parser::{BumpHeadParser, BumpVec};
+#[cfg(feature = "bumpalo")]
+pub use bumpalo;
+#[cfg(feature = "alloc")]
+pub use ext_value::ExtValue;
+pub use field_parser::FieldParser;
 pub use from_header_value::FromHeaderValue;
+pub use header_lines::{HeaderLines, ObsTextLines};
+pub use header_name::HeaderName;
 pub use header_parser::Error;
 pub use header_parser::HeadParser;
+pub use hex::{Hex, HexInt};
+pub use host_port::HostPort;
+pub use http_date::{DateOutOfRange, HttpDate};
+pub use http_version::{HttpVersion, UnknownHttpVersion};
+pub use ip_net::{InvalidIpNet, IpNet};
+pub use language_tag::LanguageTag;
+pub use lax_bool::LaxBool;
+pub use method::Method;
+pub use millis::Millis;
+pub use non_empty::NonEmpty;
+#[cfg(feature = "alloc")]
+pub use non_empty::NonEmptyString;
+pub use obs_text::ObsText;
+#[cfg(feature = "alloc")]
+pub use quoted_string::QuotedString;
+#[cfg(feature = "alloc")]
+pub use raw_headers::RawHeaders;
+pub use rejected_value::RejectedValue;
+pub use repeated_header_value::RepeatedHeaderValue;
+pub use token::Token;
+pub use uri_reference::UriReference;
+pub use weighted::Weighted;
+#[cfg(feature = "alloc")]
+pub use without_comments::WithoutComments;
@@ -0,0 +1,134 @@
+use crate::from_header_value::trim;
+use crate::token::is_token;
+use crate::FromHeaderValue;
+use core::fmt;
+
+/// The `Method` type is an HTTP request method, as found in a request line
+/// or in headers like `Allow` and `Access-Control-Request-Method`.
+///
+/// Methods are case-sensitive, so `get` is an extension method rather than
+/// `GET`. Parsing maps the standard methods to their variant, so
+/// `Extension` never holds one of them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Method<'a> {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Connect,
+    Options,
+    Trace,
+    Patch,
+    Extension(&'a str),
+}
+
+impl<'a> Method<'a> {
+    /// Parses a method token, returning `None` if it isn't a token.
+    pub fn from_token(token: &'a str) -> Option<Self> {
+        let method = match token {
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "DELETE" => Method::Delete,
+            "CONNECT" => Method::Connect,
+            "OPTIONS" => Method::Options,
+            "TRACE" => Method::Trace,
+            "PATCH" => Method::Patch,
+            _ if is_token(token) => Method::Extension(token),
+            _ => return None,
+        };
+        Some(method)
+    }
+
+    /// Returns the method as it appears in a request line.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+            Method::Connect => "CONNECT",
+            Method::Options => "OPTIONS",
+            Method::Trace => "TRACE",
+            Method::Patch => "PATCH",
+            Method::Extension(method) => method,
+        }
+    }
+
+    /// Returns `true` for the methods that are read-only by definition,
+    /// `GET`, `HEAD`, `OPTIONS` and `TRACE`.
+    pub fn is_safe(&self) -> bool {
+        matches!(
+            self,
+            Method::Get | Method::Head | Method::Options | Method::Trace
+        )
+    }
+
+    /// Returns `true` for the methods that can be retried without changing
+    /// the outcome, the safe ones plus `PUT` and `DELETE`.
+    pub fn is_idempotent(&self) -> bool {
+        self.is_safe() || matches!(self, Method::Put | Method::Delete)
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Method<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Method::from_token(trim(slice))
+    }
+}
+
+impl PartialEq<str> for Method<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Method<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Display for Method<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("GET", Some(Method::Get))]
+    #[case(" PATCH ", Some(Method::Patch))]
+    #[case("PROPFIND", Some(Method::Extension("PROPFIND")))]
+    #[case("get", Some(Method::Extension("get")))]
+    #[case("G ET", None)]
+    #[case("", None)]
+    fn method_test(#[case] input: &str, #[case] expected: Option<Method>) {
+        assert_eq!(expected, Method::parse_header_value(input));
+    }
+
+    #[rstest]
+    #[case(Method::Get, true, true)]
+    #[case(Method::Put, false, true)]
+    #[case(Method::Post, false, false)]
+    #[case(Method::Extension("PROPFIND"), false, false)]
+    fn method_properties(#[case] method: Method, #[case] safe: bool, #[case] idempotent: bool) {
+        assert_eq!(
+            (safe, idempotent),
+            (method.is_safe(), method.is_idempotent())
+        );
+    }
+
+    #[test]
+    fn method_display() {
+        assert_eq!(Method::Options.to_string(), "OPTIONS");
+        assert_eq!(Method::Extension("MKCOL"), "MKCOL");
+    }
+}
@@ -0,0 +1,51 @@
+use crate::from_header_value::{parse_digits, trim};
+use crate::FromHeaderValue;
+use core::ops::Deref;
+use core::time::Duration;
+
+/// The `Millis` type is a [`Duration`] given as a non-negative integer
+/// number of milliseconds, for custom headers that don't use the
+/// delta-seconds of standard headers.
+///
+/// A plain `Duration` field parses seconds, wrap it in `Millis` to select
+/// milliseconds instead.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Millis(pub Duration);
+
+impl Deref for Millis {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl From<Millis> for Duration {
+    fn from(millis: Millis) -> Self {
+        millis.0
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Millis {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        parse_digits(trim(slice)).map(|millis| Millis(Duration::from_millis(millis)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1500", Some(Duration::from_millis(1500)))]
+    #[case(" 0 ", Some(Duration::ZERO))]
+    #[case("-1", None)]
+    #[case("1.5", None)]
+    fn millis_test(#[case] input: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(
+            expected,
+            Millis::parse_header_value(input).map(Duration::from)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::ops::Deref;
+
+/// The `NonEmpty` type rejects a header that is present but blank, so
+/// `X-Request-Id:` with nothing but whitespace after it is reported as an
+/// invalid header value instead of parsing to `""`.
+///
+/// Any `T` can be wrapped, the value is only checked for blankness before
+/// it is handed to `T`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NonEmpty<T>(pub T);
+
+/// An owned header value that can't be blank.
+#[cfg(feature = "alloc")]
+pub type NonEmptyString = NonEmpty<String>;
+
+impl<T> Deref for NonEmpty<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> NonEmpty<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for NonEmpty<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        if slice.bytes().all(|b| matches!(b, b' ' | b'\t')) {
+            return None;
+        }
+        T::parse_header_value(slice).map(NonEmpty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("abc", Some("abc"))]
+    #[case(" abc ", Some("abc"))]
+    #[case("", None)]
+    #[case("  ", None)]
+    #[case(" \t ", None)]
+    fn non_empty_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            expected,
+            NonEmpty::<&str>::parse_header_value(input).map(NonEmpty::into_inner)
+        );
+    }
+
+    #[test]
+    fn non_empty_string() {
+        let parsed = NonEmptyString::parse_header_value(" id ").unwrap();
+        assert_eq!(parsed.as_str(), "id");
+        assert_eq!(None, NonEmptyString::parse_header_value(" "));
+    }
+}
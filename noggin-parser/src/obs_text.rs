@@ -0,0 +1,87 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The `ObsText` type is a header value that may contain obs-text, the
+/// bytes above `0x7f` that RFC 9110 still allows in field values for
+/// compatibility with legacy clients.
+///
+/// The value is kept as the raw bytes borrowed from the head. Such bytes
+/// are almost always ISO-8859-1, which is what
+/// [`decode_latin1`](ObsText::decode_latin1) assumes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ObsText<'a>(&'a [u8]);
+
+impl<'a> ObsText<'a> {
+    /// Wraps the raw bytes of a header value.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        ObsText(bytes)
+    }
+
+    /// Returns the raw bytes of the value.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the value as a string slice if it doesn't contain obs-text.
+    pub fn as_str(&self) -> Option<&'a str> {
+        // this is safe because ascii is a strict subset of utf-8
+        self.is_ascii()
+            .then(|| unsafe { core::str::from_utf8_unchecked(self.0) })
+    }
+
+    /// Returns `true` if the value doesn't contain obs-text.
+    pub fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+
+    /// Returns the value without leading and trailing spaces and tabs.
+    pub fn trim(&self) -> ObsText<'a> {
+        let start = self.0.iter().position(|b| !matches!(b, b' ' | b'\t'));
+        let end = self.0.iter().rposition(|b| !matches!(b, b' ' | b'\t'));
+        match (start, end) {
+            (Some(start), Some(end)) => ObsText(&self.0[start..=end]),
+            _ => ObsText(&[]),
+        }
+    }
+
+    /// Decodes the value as ISO-8859-1, borrowing it when it is plain ASCII
+    /// and allocating only when obs-text has to be converted to UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn decode_latin1(&self) -> Cow<'a, str> {
+        match self.as_str() {
+            Some(value) => Cow::Borrowed(value),
+            None => Cow::Owned(self.0.iter().map(|&b| char::from(b)).collect::<String>()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(b"plain", Some("plain"), "plain")]
+    #[case(b"caf\xe9", None, "café")]
+    #[case(b"\xa3100", None, "£100")]
+    #[case(b"", Some(""), "")]
+    fn decode_obs_text(#[case] bytes: &[u8], #[case] as_str: Option<&str>, #[case] decoded: &str) {
+        let value = ObsText::new(bytes);
+        assert_eq!(value.as_str(), as_str);
+        assert_eq!(value.decode_latin1(), decoded);
+        assert_eq!(
+            matches!(value.decode_latin1(), Cow::Borrowed(_)),
+            as_str.is_some()
+        );
+    }
+
+    #[rstest]
+    #[case(b" \tvalue\t ", b"value")]
+    #[case(b"a b", b"a b")]
+    #[case(b" \t ", b"")]
+    fn trim_obs_text(#[case] bytes: &[u8], #[case] expected: &[u8]) {
+        assert_eq!(ObsText::new(bytes).trim().as_bytes(), expected);
+    }
+}
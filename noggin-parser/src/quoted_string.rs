@@ -0,0 +1,94 @@
+use crate::from_header_value::{trim, unquote};
+use crate::FromHeaderValue;
+use alloc::borrow::Cow;
+use core::fmt;
+use core::ops::Deref;
+
+/// The `QuotedString` type is a header value given as an RFC 9110
+/// quoted-string, such as an ETag, a `filename` or a `realm`.
+///
+/// The surrounding DQUOTEs are stripped and `\"` / `\\` quoted-pairs are
+/// unescaped. The contents are borrowed from the header unless they contain
+/// escapes. Unlike `Cow<str>`, which also accepts bare tokens, a value that
+/// isn't quoted is rejected.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct QuotedString<'a>(Cow<'a, str>);
+
+impl<'a> QuotedString<'a> {
+    /// Returns the unescaped contents of the quoted-string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the unescaped contents, borrowed unless they had escapes.
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+}
+
+impl<'a> Deref for QuotedString<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for QuotedString<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        if !value.starts_with('"') {
+            return None;
+        }
+        unquote(value).map(QuotedString)
+    }
+}
+
+impl<'a> fmt::Display for QuotedString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.0.chars() {
+            if matches!(c, '"' | '\\') {
+                f.write_str("\\")?;
+            }
+            write!(f, "{c}")?;
+        }
+        f.write_str("\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(r#""xyzzy""#, Some("xyzzy"), true)]
+    #[case(r#" "annual report.pdf" "#, Some("annual report.pdf"), true)]
+    #[case(r#""a \"quoted\" word""#, Some(r#"a "quoted" word"#), false)]
+    #[case(r#""C:\\temp""#, Some(r"C:\temp"), false)]
+    #[case("xyzzy", None, false)]
+    #[case(r#""open"#, None, false)]
+    #[case(r#""a"b""#, None, false)]
+    fn quoted_string_test(
+        #[case] input: &str,
+        #[case] expected: Option<&str>,
+        #[case] borrowed: bool,
+    ) {
+        let parsed = QuotedString::parse_header_value(input);
+        assert_eq!(expected, parsed.as_ref().map(QuotedString::as_str));
+        assert_eq!(
+            borrowed,
+            matches!(parsed.map(QuotedString::into_inner), Some(Cow::Borrowed(_)))
+        );
+    }
+
+    #[rstest]
+    #[case(r#""xyzzy""#)]
+    #[case(r#""a \"quoted\" word""#)]
+    #[case(r#""C:\\temp""#)]
+    fn quoted_string_display(#[case] input: &str) {
+        let parsed = QuotedString::parse_header_value(input).unwrap();
+        assert_eq!(parsed.to_string(), input);
+    }
+}
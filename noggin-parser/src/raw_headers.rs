@@ -0,0 +1,94 @@
+use crate::{Error, HeadParser, HeaderLines};
+use alloc::vec::Vec;
+
+/// The `RawHeaders` type captures every header of an HTTP head section as
+/// untyped `(name, value)` pairs, in the order they appeared.
+///
+/// This is useful when the set of headers isn't known up front, for example
+/// in proxies or logging code. Names keep their original casing, values are
+/// borrowed from the head with surrounding whitespace removed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RawHeaders<'a> {
+    headers: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> RawHeaders<'a> {
+    /// Returns the value of the first header matching `name`, compared
+    /// case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.get_all(name).next()
+    }
+
+    /// Returns the values of every header matching `name`, compared
+    /// case-insensitively, in the order they appeared.
+    pub fn get_all<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'a str> + 's {
+        self.headers
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns an iterator over all `(name, value)` pairs in their original
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.headers.iter().copied()
+    }
+
+    /// Returns the number of header lines captured.
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Returns `true` if the head contained no headers.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+}
+
+impl<'de> HeadParser<'de> for RawHeaders<'de> {
+    fn parse_head_section(head: &'de str) -> Result<Self, Error<'de>> {
+        let headers = HeaderLines::new(head)
+            .map(|header| header.map(|(key, value)| (key, value.trim_matches([' ', '\t']))))
+            .collect::<Result<_, _>>()?;
+        Ok(RawHeaders { headers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_raw_headers() {
+        let head = "Host: example.com\r\nACCEPT: text/html\r\nX-Empty:\r\naccept:\t*/*";
+        let headers = RawHeaders::parse_head_section(head).unwrap();
+
+        assert_eq!(headers.len(), 4);
+        assert_eq!(headers.get("host"), Some("example.com"));
+        assert_eq!(headers.get("x-empty"), Some(""));
+        assert_eq!(headers.get("missing"), None);
+        assert_eq!(
+            headers.get_all("Accept").collect::<Vec<_>>(),
+            vec!["text/html", "*/*"]
+        );
+        assert_eq!(
+            headers.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            vec!["Host", "ACCEPT", "X-Empty", "accept"]
+        );
+    }
+
+    #[test]
+    fn parse_raw_headers_with_body() {
+        let (headers, body) = RawHeaders::parse_headers(b"Host: a\r\n\r\nbody").unwrap();
+
+        assert_eq!(headers.get("host"), Some("a"));
+        assert_eq!(body, b"body");
+    }
+
+    #[test]
+    fn error_on_malformed_raw_header() {
+        let result = RawHeaders::parse_head_section("Host: a\r\nbroken");
+
+        assert_eq!(result, Err(Error::MalformedHeader { line: 2, offset: 9 }));
+    }
+}
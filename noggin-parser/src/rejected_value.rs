@@ -0,0 +1,123 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// The `RejectedValue` type is the header value reported by an
+/// `InvalidHeaderValue` [`Error`](crate::Error).
+///
+/// Like a `Cow<'a, str>`, the value is borrowed from the parsed head, and
+/// with the `alloc` feature it can be owned so the error outlives the head,
+/// see [`Error::into_static`](crate::Error::into_static). Both forms compare
+/// equal when they contain the same text.
+#[derive(Clone)]
+pub struct RejectedValue<'a>(Repr<'a>);
+
+#[derive(Clone)]
+enum Repr<'a> {
+    Borrowed(&'a str),
+    #[cfg(feature = "alloc")]
+    Owned(String),
+}
+
+impl<'a> RejectedValue<'a> {
+    /// Returns the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Borrowed(value) => value,
+            #[cfg(feature = "alloc")]
+            Repr::Owned(value) => value,
+        }
+    }
+
+    /// Detaches the value from the parsed head by copying it. Without the
+    /// `alloc` feature the value can't be copied and is emptied instead.
+    pub fn into_owned(self) -> RejectedValue<'static> {
+        match self.0 {
+            #[cfg(feature = "alloc")]
+            Repr::Borrowed(value) => RejectedValue(Repr::Owned(value.into())),
+            #[cfg(not(feature = "alloc"))]
+            Repr::Borrowed(_) => RejectedValue(Repr::Borrowed("")),
+            #[cfg(feature = "alloc")]
+            Repr::Owned(value) => RejectedValue(Repr::Owned(value)),
+        }
+    }
+}
+
+impl<'a> Deref for RejectedValue<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a str> for RejectedValue<'a> {
+    fn from(value: &'a str) -> Self {
+        RejectedValue(Repr::Borrowed(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for RejectedValue<'static> {
+    fn from(value: String) -> Self {
+        RejectedValue(Repr::Owned(value))
+    }
+}
+
+impl<'a, 'b> PartialEq<RejectedValue<'b>> for RejectedValue<'a> {
+    fn eq(&self, other: &RejectedValue<'b>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a> Eq for RejectedValue<'a> {}
+
+impl<'a> PartialEq<str> for RejectedValue<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&str> for RejectedValue<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'a> Hash for RejectedValue<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<'a> fmt::Debug for RejectedValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<'a> fmt::Display for RejectedValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed_and_owned_values_are_equal() {
+        let value = String::from("abc");
+        let borrowed = RejectedValue::from(value.as_str());
+        let owned = borrowed.clone().into_owned();
+        drop(value);
+
+        assert_eq!(owned, "abc");
+        assert_eq!(owned, RejectedValue::from(String::from("abc")));
+        assert_eq!(owned.len(), 3);
+        assert_eq!(format!("{owned:?}"), "\"abc\"");
+    }
+}
@@ -0,0 +1,211 @@
+use crate::from_header_value::split_list;
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "smallvec"))]
+use crate::FromHeaderValue;
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeSet, vec::Vec};
+#[cfg(feature = "std")]
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, Hash},
+};
+
+/// The `RepeatedHeaderValue` trait is implemented by the containers that can
+/// back a repeated header field.
+///
+/// A repeated header may occur on several lines and each line may carry a
+/// comma separated list of elements. The `noggin::Noggin` derive starts from
+/// an empty container and calls `extend_header_value` once per matching
+/// line, so every element from every line ends up in the container in order.
+/// Fields marked `#[noggin(no_split)]` call `push_header_value` with the
+/// whole line instead.
+pub trait RepeatedHeaderValue<'de> {
+    /// Parses a single element and appends it.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: A string slice representing the raw value of one element.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<()>`: Returns `None` if the element failed to parse.
+    fn push_header_value(&mut self, value: &'de str) -> Option<()>;
+
+    /// Parses the elements of a single header line and appends them.
+    ///
+    /// # Parameters
+    ///
+    /// * `slice`: A string slice representing the raw value of one header line.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<()>`: Returns `None` if any element failed to parse.
+    fn extend_header_value(&mut self, slice: &'de str) -> Option<()> {
+        split_list(slice).try_for_each(|value| self.push_header_value(value))
+    }
+
+    /// Returns `true` if no elements have been collected.
+    fn is_empty(&self) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: FromHeaderValue<'de>> RepeatedHeaderValue<'de> for Vec<T> {
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.push(T::parse_header_value(value)?);
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Elements are deduplicated, so `Vary: Accept, Accept` yields one element.
+#[cfg(feature = "std")]
+impl<'de, T, S> RepeatedHeaderValue<'de> for HashSet<T, S>
+where
+    T: FromHeaderValue<'de> + Eq + Hash,
+    S: BuildHasher,
+{
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.insert(T::parse_header_value(value)?);
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Elements are deduplicated and kept in order.
+#[cfg(feature = "alloc")]
+impl<'de, T: FromHeaderValue<'de> + Ord> RepeatedHeaderValue<'de> for BTreeSet<T> {
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.insert(T::parse_header_value(value)?);
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'de, T: FromHeaderValue<'de>, const N: usize> RepeatedHeaderValue<'de>
+    for heapless::Vec<T, N>
+{
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.push(T::parse_header_value(value)?).ok()?;
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<'de, A> RepeatedHeaderValue<'de> for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: FromHeaderValue<'de>,
+{
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.push(A::Item::parse_header_value(value)?);
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+impl<'bump, 'de, T: FromHeaderValue<'de>> RepeatedHeaderValue<'de>
+    for bumpalo::collections::Vec<'bump, T>
+{
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.push(T::parse_header_value(value)?);
+        Some(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec!["1"], Some(vec![1]))]
+    #[case(vec!["1, 2", "3"], Some(vec![1, 2, 3]))]
+    #[case(vec!["1", "idk"], None)]
+    fn vec_test(#[case] lines: Vec<&str>, #[case] expected: Option<Vec<u8>>) {
+        let mut values = Vec::new();
+        let result: Option<()> = lines
+            .into_iter()
+            .try_for_each(|line| values.extend_header_value(line));
+        assert_eq!(expected, result.map(|_| values));
+    }
+
+    #[rstest]
+    #[case(vec!["2, 1"], Some(vec![1, 2]))]
+    #[case(vec!["1, 2", "2, 1"], Some(vec![1, 2]))]
+    #[case(vec!["1", "idk"], None)]
+    fn set_test(#[case] lines: Vec<&str>, #[case] expected: Option<Vec<u8>>) {
+        let mut btree = BTreeSet::new();
+        let result: Option<()> = lines
+            .iter()
+            .try_for_each(|line| btree.extend_header_value(line));
+        assert_eq!(expected, result.map(|_| btree.into_iter().collect()));
+        let mut hash = HashSet::<u8>::new();
+        let result: Option<()> = lines
+            .iter()
+            .try_for_each(|line| hash.extend_header_value(line));
+        let expected = expected.map(|values| values.into_iter().collect());
+        assert_eq!(expected, result.map(|_| hash));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[rstest]
+    #[case(vec!["1"], Some(vec![1]))]
+    #[case(vec!["1, 2"], Some(vec![1, 2]))]
+    #[case(vec!["1, 2", "3"], None)]
+    #[case(vec!["1", "idk"], None)]
+    fn heapless_vec_test(#[case] lines: Vec<&str>, #[case] expected: Option<Vec<u8>>) {
+        let mut values = heapless::Vec::<u8, 2>::new();
+        let result: Option<()> = lines
+            .into_iter()
+            .try_for_each(|line| values.extend_header_value(line));
+        assert_eq!(expected, result.map(|_| values.to_vec()));
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[rstest]
+    #[case(vec!["1"], Some(vec![1]))]
+    #[case(vec!["1, 2", "3"], Some(vec![1, 2, 3]))]
+    #[case(vec!["1", "idk"], None)]
+    fn smallvec_test(#[case] lines: Vec<&str>, #[case] expected: Option<Vec<u8>>) {
+        let mut values = smallvec::SmallVec::<[u8; 2]>::new();
+        let result: Option<()> = lines
+            .into_iter()
+            .try_for_each(|line| values.extend_header_value(line));
+        assert_eq!(expected, result.map(|_| values.to_vec()));
+    }
+
+    #[cfg(feature = "bumpalo")]
+    #[rstest]
+    #[case(vec!["1"], Some(vec![1]))]
+    #[case(vec!["1, 2", "3"], Some(vec![1, 2, 3]))]
+    #[case(vec!["1", "idk"], None)]
+    fn bump_vec_test(#[case] lines: Vec<&str>, #[case] expected: Option<Vec<u8>>) {
+        let bump = bumpalo::Bump::new();
+        let mut values = bumpalo::collections::Vec::new_in(&bump);
+        let result: Option<()> = lines
+            .into_iter()
+            .try_for_each(|line| values.extend_header_value(line));
+        assert_eq!(expected, result.map(|_| values.to_vec()));
+    }
+}
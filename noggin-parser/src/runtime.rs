@@ -0,0 +1,484 @@
+//! Support functions called by the code generated by the `noggin::Noggin`
+//! derive macro.
+//!
+//! Keeping this machinery here, rather than inlining it into every derived
+//! struct, means the derive only has to emit a table of header names, a
+//! lookup dispatching on their lengths and a small closure per struct.
+//! Nothing in this module is covered by semver guarantees, do not call it
+//! directly.
+
+use crate::from_header_value::split_list;
+use crate::{
+    Error, FromHeaderValue, HeaderLines, HeaderName, ObsText, ObsTextLines, RejectedValue,
+    RepeatedHeaderValue,
+};
+use memchr::memmem;
+
+/// Re-exported for the parsers of `#[noggin(obs_text)]` structs, which
+/// don't require the head to be ASCII.
+pub use crate::header_parser::{split_head_bytes, strip_head_terminator};
+
+/// Walks the header `lines` and calls `on_header` with the index of
+/// the matching entry in `names` and the raw value for every known header.
+/// `find` returns that index for a header name, matched case-insensitively.
+/// `on_header` returns `None` when the value could not be parsed, which is
+/// reported as an invalid value for that header.
+///
+/// `names` must be lowercase.
+pub fn parse_head<'de>(
+    lines: HeaderLines<'de>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    on_header: impl FnMut(usize, &'de str) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    parse_head_ordered(lines, names, find, &mut Discard, on_header)
+}
+
+/// Like [`parse_head`], but also records the 0-based position among the
+/// header lines and the original name of every known header in `order`.
+pub fn parse_head_ordered<'de: 'o, 'o>(
+    mut lines: HeaderLines<'de>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    order: &mut impl Extend<(usize, &'o str)>,
+    mut on_header: impl FnMut(usize, &'de str) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    while let Some(header) = lines.next() {
+        let (key, value) = header?;
+        if let Some(index) = find(key) {
+            order.extend([(lines.line() - 1, key)]);
+            on_header(index, value).ok_or(Error::InvalidHeaderValue {
+                name: HeaderName::from_static(names[index]),
+                value: value.trim_matches([' ', '\t']).into(),
+                line: lines.line(),
+                offset: lines.offset(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Like [`parse_head`], but walks [`ObsTextLines`] for `#[noggin(obs_text)]`
+/// structs, so `on_header` receives values that may contain obs-text.
+pub fn parse_obs_text_head<'de>(
+    lines: ObsTextLines<'de>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    on_header: impl FnMut(usize, ObsText<'de>) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    parse_obs_text_head_ordered(lines, names, find, &mut Discard, on_header)
+}
+
+/// Like [`parse_head_ordered`], but walks [`ObsTextLines`], see
+/// [`parse_obs_text_head`].
+pub fn parse_obs_text_head_ordered<'de: 'o, 'o>(
+    mut lines: ObsTextLines<'de>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    order: &mut impl Extend<(usize, &'o str)>,
+    mut on_header: impl FnMut(usize, ObsText<'de>) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    while let Some(header) = lines.next() {
+        let (key, value) = header?;
+        if let Some(index) = find(key) {
+            order.extend([(lines.line() - 1, key)]);
+            on_header(index, value).ok_or_else(|| Error::InvalidHeaderValue {
+                name: HeaderName::from_static(names[index]),
+                value: rejected_obs_text(value.trim()),
+                line: lines.line(),
+                offset: lines.offset(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Borrows a rejected value that is ASCII, obs-text is decoded as
+/// ISO-8859-1 or, without the `alloc` feature, left out.
+fn rejected_obs_text(value: ObsText<'_>) -> RejectedValue<'_> {
+    match value.as_str() {
+        Some(value) => value.into(),
+        #[cfg(feature = "alloc")]
+        None => value.decode_latin1().into_owned().into(),
+        #[cfg(not(feature = "alloc"))]
+        None => "".into(),
+    }
+}
+
+/// Walks the `(name, value)` pairs of header `fields` for
+/// [`FieldParser`](crate::FieldParser) like [`parse_head_ordered`] walks
+/// header lines. Fields that aren't declared are skipped, the value of a
+/// declared field must be ASCII and free of control characters unless
+/// `allow_control_characters` is set.
+pub fn parse_fields<'de: 'o, 'o>(
+    fields: impl IntoIterator<Item = (&'de str, &'de [u8])>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    allow_control_characters: bool,
+    order: &mut impl Extend<(usize, &'o str)>,
+    mut on_header: impl FnMut(usize, &'de str) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    walk_fields(
+        fields,
+        names,
+        find,
+        allow_control_characters,
+        false,
+        order,
+        |index, value| on_header(index, value.as_str()?),
+    )
+}
+
+/// Like [`parse_fields`], but for `#[noggin(obs_text)]` structs, so values
+/// may contain obs-text.
+pub fn parse_obs_text_fields<'de: 'o, 'o>(
+    fields: impl IntoIterator<Item = (&'de str, &'de [u8])>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    allow_control_characters: bool,
+    order: &mut impl Extend<(usize, &'o str)>,
+    on_header: impl FnMut(usize, ObsText<'de>) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    walk_fields(
+        fields,
+        names,
+        find,
+        allow_control_characters,
+        true,
+        order,
+        on_header,
+    )
+}
+
+fn walk_fields<'de: 'o, 'o>(
+    fields: impl IntoIterator<Item = (&'de str, &'de [u8])>,
+    names: &[&'static str],
+    find: impl Fn(&str) -> Option<usize>,
+    allow_control_characters: bool,
+    obs_text: bool,
+    order: &mut impl Extend<(usize, &'o str)>,
+    mut on_header: impl FnMut(usize, ObsText<'de>) -> Option<()>,
+) -> Result<(), Error<'de>> {
+    for (position, (key, value)) in fields.into_iter().enumerate() {
+        let Some(index) = find(key) else {
+            continue;
+        };
+        let line = position + 1;
+        if !obs_text && !value.is_ascii() {
+            return Err(Error::NonAscii);
+        }
+        let control = value
+            .iter()
+            .position(|&b| b.is_ascii_control() && b != b'\t');
+        if let (Some(offset), false) = (control, allow_control_characters) {
+            return Err(Error::ControlCharacter { line, offset });
+        }
+        order.extend([(position, key)]);
+        let value = ObsText::new(value);
+        on_header(index, value).ok_or_else(|| Error::InvalidHeaderValue {
+            name: HeaderName::from_static(names[index]),
+            value: rejected_obs_text(value.trim()),
+            line,
+            offset: 0,
+        })?;
+    }
+    Ok(())
+}
+
+/// Discards the header order when it isn't captured.
+pub struct Discard;
+
+impl<T> Extend<T> for Discard {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, _: I) {}
+}
+
+/// Stores the parsed `value` in `slot` unless an earlier header already
+/// filled it.
+pub fn single<'de, T: FromHeaderValue<'de>>(slot: &mut Option<T>, value: &'de str) -> Option<()> {
+    if slot.is_none() {
+        *slot = Some(T::parse_header_value(value)?);
+    }
+    Some(())
+}
+
+/// Stores the trimmed `value` of an `ObsText` field in `slot` unless an
+/// earlier header already filled it.
+pub fn single_obs_text<'de>(slot: &mut Option<ObsText<'de>>, value: ObsText<'de>) -> Option<()> {
+    if slot.is_none() {
+        *slot = Some(value.trim());
+    }
+    Some(())
+}
+
+/// Appends every element of the parsed `value` to `slot`.
+pub fn repeated<'de, C: RepeatedHeaderValue<'de>>(slot: &mut C, value: &'de str) -> Option<()> {
+    slot.extend_header_value(value)
+}
+
+/// Appends the whole `value` to `slot` as a single element, for
+/// `#[noggin(no_split)]` fields whose elements may contain commas.
+pub fn repeated_unsplit<'de, C: RepeatedHeaderValue<'de>>(
+    slot: &mut C,
+    value: &'de str,
+) -> Option<()> {
+    slot.push_header_value(value)
+}
+
+/// Unwraps a required single header, or reports it as missing.
+pub fn required<T>(slot: Option<T>, name: &'static str) -> Result<T, Error<'static>> {
+    slot.ok_or(Error::MissingHeader(HeaderName::from_static(name)))
+}
+
+/// Checks that a required repeated header occurred at least once, or reports
+/// it as missing.
+pub fn required_repeated<'de, C: RepeatedHeaderValue<'de>>(
+    slot: C,
+    name: &'static str,
+) -> Result<C, Error<'static>> {
+    if slot.is_empty() {
+        return Err(Error::MissingHeader(HeaderName::from_static(name)));
+    }
+    Ok(slot)
+}
+
+/// Converts an optional repeated header into `None` if it never occurred.
+pub fn optional_repeated<'de, C: RepeatedHeaderValue<'de>>(slot: C) -> Option<C> {
+    (!slot.is_empty()).then_some(slot)
+}
+
+/// Collects the elements of a fixed-size array header field, which must end
+/// up with exactly `N` elements. The address and length of the last header
+/// line are kept so a field with too few elements can be reported like any
+/// other invalid value, without tying the collector to the head's lifetime.
+pub struct ArrayCollector<T, const N: usize> {
+    items: [Option<T>; N],
+    len: usize,
+    last: (usize, usize),
+}
+
+impl<T, const N: usize> Default for ArrayCollector<T, N> {
+    fn default() -> Self {
+        ArrayCollector {
+            items: [(); N].map(|_| None),
+            len: 0,
+            last: (0, 0),
+        }
+    }
+}
+
+impl<T, const N: usize> ArrayCollector<T, N> {
+    fn push<'de>(&mut self, value: &'de str) -> Option<()>
+    where
+        T: FromHeaderValue<'de>,
+    {
+        let item = self.items.get_mut(self.len)?;
+        *item = Some(T::parse_header_value(value)?);
+        self.len += 1;
+        Some(())
+    }
+
+    fn finish<'de>(self, name: &'static str, head: &'de [u8]) -> Result<[T; N], Error<'de>> {
+        if self.len != N {
+            let (address, len) = self.last;
+            let start = address.saturating_sub(head.as_ptr() as usize);
+            let value = head.get(start..start + len).unwrap_or_default();
+            let before = head.get(..start).unwrap_or_default();
+            return Err(Error::InvalidHeaderValue {
+                name: HeaderName::from_static(name),
+                // the value was passed in as a string slice of the head
+                value: core::str::from_utf8(value)
+                    .unwrap_or_default()
+                    .trim_matches([' ', '\t'])
+                    .into(),
+                line: memmem::find_iter(before, b"\r\n").count() + 1,
+                offset: memmem::rfind(before, b"\r\n").map_or(0, |end| end + 2),
+            });
+        }
+        Ok(self.items.map(|item| item.unwrap()))
+    }
+}
+
+impl<'de, T: FromHeaderValue<'de>, const N: usize> RepeatedHeaderValue<'de>
+    for ArrayCollector<T, N>
+{
+    fn push_header_value(&mut self, value: &'de str) -> Option<()> {
+        self.last = (value.as_ptr() as usize, value.len());
+        self.push(value)
+    }
+
+    fn extend_header_value(&mut self, slice: &'de str) -> Option<()> {
+        self.last = (slice.as_ptr() as usize, slice.len());
+        split_list(slice).try_for_each(|value| self.push(value))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Converts a required array header into its array, or reports it as missing
+/// or as invalid if it didn't have exactly `N` elements. `head` is the head
+/// the header lines were parsed from.
+pub fn required_array<'de, T, const N: usize>(
+    slot: ArrayCollector<T, N>,
+    name: &'static str,
+    head: &'de [u8],
+) -> Result<[T; N], Error<'de>> {
+    if slot.len == 0 {
+        return Err(Error::MissingHeader(HeaderName::from_static(name)));
+    }
+    slot.finish(name, head)
+}
+
+/// Converts an optional array header into `None` if it never occurred, or
+/// reports it as invalid if it didn't have exactly `N` elements. `head` is
+/// the head the header lines were parsed from.
+pub fn optional_array<'de, T, const N: usize>(
+    slot: ArrayCollector<T, N>,
+    name: &'static str,
+    head: &'de [u8],
+) -> Result<Option<[T; N]>, Error<'de>> {
+    if slot.len == 0 {
+        return Ok(None);
+    }
+    slot.finish(name, head).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn find_host_accept(key: &str) -> Option<usize> {
+        ["host", "accept"]
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(key))
+    }
+
+    #[test]
+    fn parse_head_collects_fields() {
+        let mut host: Option<&str> = None;
+        let mut accept: Vec<&str> = vec![];
+        let head = "Host: a\r\nAccept: x, y\r\nHost: b\r\nPragma: z\r\nAccept: w";
+        parse_head(
+            HeaderLines::new(head),
+            &["host", "accept"],
+            find_host_accept,
+            |index, value| match index {
+                0 => single(&mut host, value),
+                1 => repeated(&mut accept, value),
+                _ => Some(()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(host, Some("a"));
+        assert_eq!(accept, vec!["x", "y", "w"]);
+    }
+
+    #[test]
+    fn parse_head_records_order() {
+        let mut order = vec![];
+        let head = "Pragma: z\r\nACCEPT: x\r\nHost: a";
+        parse_head_ordered(
+            HeaderLines::new(head),
+            &["host", "accept"],
+            find_host_accept,
+            &mut order,
+            |_, _| Some(()),
+        )
+        .unwrap();
+
+        assert_eq!(order, vec![(1, "ACCEPT"), (2, "Host")]);
+    }
+
+    #[test]
+    fn parse_head_reports_invalid_values() {
+        let mut length: Option<u32> = None;
+        let result = parse_head(
+            HeaderLines::new("Content-Length: abc"),
+            &["content-length"],
+            |key| key.eq_ignore_ascii_case("content-length").then_some(0),
+            |_, value| single(&mut length, value),
+        );
+
+        assert_eq!(
+            result,
+            Err(Error::InvalidHeaderValue {
+                name: "content-length".into(),
+                value: "abc".into(),
+                line: 1,
+                offset: 0
+            })
+        );
+    }
+
+    fn collect_array(head: &str) -> ArrayCollector<u8, 2> {
+        let mut slot = ArrayCollector::default();
+        parse_head(
+            HeaderLines::new(head),
+            &["a"],
+            |key| key.eq_ignore_ascii_case("a").then_some(0),
+            |_, value| repeated(&mut slot, value),
+        )
+        .unwrap();
+        slot
+    }
+
+    #[rstest]
+    #[case("A: 1, 2", Ok([1, 2]))]
+    #[case("A: 1\r\nA: 2", Ok([1, 2]))]
+    #[case("B: 1", Err(Error::MissingHeader("a".into())))]
+    #[case(
+        "B: 1\r\nA: 1 ",
+        Err(Error::InvalidHeaderValue { name: "a".into(), value: "1".into(), line: 2, offset: 6 })
+    )]
+    fn required_array_test(#[case] head: &str, #[case] expected: Result<[u8; 2], Error>) {
+        assert_eq!(
+            expected,
+            required_array(collect_array(head), "a", head.as_bytes())
+        );
+    }
+
+    #[rstest]
+    #[case("A: 1, 2", Ok(Some([1, 2])))]
+    #[case("B: 1", Ok(None))]
+    #[case(
+        "A: 1",
+        Err(Error::InvalidHeaderValue { name: "a".into(), value: "1".into(), line: 1, offset: 0 })
+    )]
+    fn optional_array_test(#[case] head: &str, #[case] expected: Result<Option<[u8; 2]>, Error>) {
+        assert_eq!(
+            expected,
+            optional_array(collect_array(head), "a", head.as_bytes())
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["1, 2, 3"])]
+    #[case(vec!["1, 2", "3"])]
+    #[case(vec!["1, x"])]
+    fn array_collector_rejects(#[case] lines: Vec<&str>) {
+        let mut slot = ArrayCollector::<u8, 2>::default();
+        let result = lines
+            .into_iter()
+            .try_for_each(|line| repeated(&mut slot, line));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn required_helpers() {
+        assert_eq!(required(Some(1), "a"), Ok(1));
+        assert_eq!(
+            required::<u8>(None, "a"),
+            Err(Error::MissingHeader("a".into()))
+        );
+        assert_eq!(required_repeated(vec![1], "a"), Ok(vec![1]));
+        assert_eq!(
+            required_repeated(Vec::<u8>::new(), "a"),
+            Err(Error::MissingHeader("a".into()))
+        );
+        assert_eq!(optional_repeated(vec![1]), Some(vec![1]));
+        assert_eq!(optional_repeated(Vec::<u8>::new()), None);
+    }
+}
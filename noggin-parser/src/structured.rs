@@ -0,0 +1,638 @@
+//! Structured field values (RFC 8941, with the dates and display strings of
+//! RFC 9651), the syntax of most recently specified headers.
+//!
+//! [`Item`], [`List`] and [`Dictionary`] are header values themselves. They
+//! are validated when parsed, borrow the header and parse their members
+//! lazily on iteration, so none of them allocate.
+
+use crate::from_header_value::trim;
+use crate::token::is_tchar;
+use crate::FromHeaderValue;
+
+/// The `BareItem` type is the value of an [`Item`] or of a parameter.
+///
+/// Strings, byte sequences and display strings are borrowed from the header
+/// without their delimiters and without being decoded.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BareItem<'a> {
+    /// An integer of at most 15 digits, such as `42`.
+    Integer(i64),
+    /// A decimal with at most 12 integer and 3 fractional digits, such as
+    /// `4.5`.
+    Decimal(f64),
+    /// A string such as `"hello"`, with its `\"` and `\\` escapes kept.
+    String(&'a str),
+    /// A token such as `gzip` or `text/html`.
+    Token(&'a str),
+    /// A byte sequence such as `:aGVsbG8=:`, still base64 encoded.
+    ByteSequence(&'a str),
+    /// A boolean, `?1` or `?0`.
+    Boolean(bool),
+    /// A date such as `@1659578233`, in seconds since the Unix epoch.
+    Date(i64),
+    /// A display string such as `%"caf%c3%a9"`, still percent-encoded.
+    DisplayString(&'a str),
+}
+
+impl<'a> BareItem<'a> {
+    /// Returns the value of an integer.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            BareItem::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a decimal or an integer.
+    pub fn as_decimal(&self) -> Option<f64> {
+        match self {
+            BareItem::Decimal(value) => Some(*value),
+            BareItem::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw content of a string.
+    pub fn as_string(&self) -> Option<&'a str> {
+        match self {
+            BareItem::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a token.
+    pub fn as_token(&self) -> Option<&'a str> {
+        match self {
+            BareItem::Token(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the base64 content of a byte sequence.
+    pub fn as_byte_sequence(&self) -> Option<&'a str> {
+        match self {
+            BareItem::ByteSequence(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            BareItem::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp of a date.
+    pub fn as_date(&self) -> Option<i64> {
+        match self {
+            BareItem::Date(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// A cursor over a structured field, implementing the parsing algorithms of
+/// RFC 8941 section 4.2.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    fn expect(&mut self, b: u8) -> Option<()> {
+        (self.peek()? == b).then(|| self.pos += 1)
+    }
+
+    fn skip_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn skip_sp(&mut self) {
+        self.skip_while(|b| b == b' ');
+    }
+
+    fn skip_ows(&mut self) {
+        self.skip_while(|b| b == b' ' || b == b'\t');
+    }
+
+    fn parse_key(&mut self) -> Option<&'a str> {
+        if !self
+            .peek()
+            .is_some_and(|b| b.is_ascii_lowercase() || b == b'*')
+        {
+            return None;
+        }
+        Some(self.skip_while(|b| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'_' | b'-' | b'.' | b'*')
+        }))
+    }
+
+    fn parse_bare_item(&mut self) -> Option<BareItem<'a>> {
+        match self.peek()? {
+            b'-' | b'0'..=b'9' => self.parse_number(),
+            b'"' => self.parse_string().map(BareItem::String),
+            b'*' | b'A'..=b'Z' | b'a'..=b'z' => Some(BareItem::Token(
+                self.skip_while(|b| is_tchar(b) || b == b':' || b == b'/'),
+            )),
+            b':' => {
+                self.pos += 1;
+                let bytes = self
+                    .skip_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'='));
+                self.expect(b':')?;
+                Some(BareItem::ByteSequence(bytes))
+            }
+            b'?' => {
+                self.pos += 1;
+                let value = match self.peek()? {
+                    b'0' => false,
+                    b'1' => true,
+                    _ => return None,
+                };
+                self.pos += 1;
+                Some(BareItem::Boolean(value))
+            }
+            b'@' => {
+                self.pos += 1;
+                match self.parse_number()? {
+                    BareItem::Integer(value) => Some(BareItem::Date(value)),
+                    _ => None,
+                }
+            }
+            b'%' => {
+                self.pos += 1;
+                self.expect(b'"')?;
+                let start = self.pos;
+                loop {
+                    match self.peek()? {
+                        b'"' => break,
+                        b'%' => {
+                            let hex = self.input.as_bytes().get(self.pos + 1..self.pos + 3)?;
+                            if !hex.iter().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+                                return None;
+                            }
+                            self.pos += 3;
+                        }
+                        b' '..=b'~' => self.pos += 1,
+                        _ => return None,
+                    }
+                }
+                let value = &self.input[start..self.pos];
+                self.pos += 1;
+                Some(BareItem::DisplayString(value))
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_number(&mut self) -> Option<BareItem<'a>> {
+        let start = self.pos;
+        let negative = self.expect(b'-').is_some();
+        let int = self.skip_while(|b| b.is_ascii_digit());
+        if int.is_empty() {
+            return None;
+        }
+        if self.expect(b'.').is_none() {
+            if int.len() > 15 {
+                return None;
+            }
+            let value: i64 = int.parse().ok()?;
+            return Some(BareItem::Integer(if negative { -value } else { value }));
+        }
+        let fraction = self.skip_while(|b| b.is_ascii_digit());
+        if int.len() > 12 || fraction.is_empty() || fraction.len() > 3 {
+            return None;
+        }
+        Some(BareItem::Decimal(self.input[start..self.pos].parse().ok()?))
+    }
+
+    fn parse_string(&mut self) -> Option<&'a str> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        loop {
+            match self.peek()? {
+                b'"' => break,
+                b'\\' => {
+                    self.pos += 1;
+                    if !matches!(self.peek()?, b'"' | b'\\') {
+                        return None;
+                    }
+                    self.pos += 1;
+                }
+                b' '..=b'~' => self.pos += 1,
+                _ => return None,
+            }
+        }
+        let value = &self.input[start..self.pos];
+        self.pos += 1;
+        Some(value)
+    }
+
+    fn parse_parameters(&mut self) -> Option<Parameters<'a>> {
+        let start = self.pos;
+        while self.expect(b';').is_some() {
+            self.skip_sp();
+            self.parse_key()?;
+            if self.expect(b'=').is_some() {
+                self.parse_bare_item()?;
+            }
+        }
+        Some(Parameters(&self.input[start..self.pos]))
+    }
+
+    fn parse_item(&mut self) -> Option<Item<'a>> {
+        let bare = self.parse_bare_item()?;
+        let params = self.parse_parameters()?;
+        Some(Item { bare, params })
+    }
+
+    fn parse_member(&mut self) -> Option<ListMember<'a>> {
+        if self.expect(b'(').is_none() {
+            return self.parse_item().map(ListMember::Item);
+        }
+        let start = self.pos;
+        loop {
+            self.skip_sp();
+            if self.peek()? == b')' {
+                break;
+            }
+            self.parse_item()?;
+            if !matches!(self.peek()?, b' ' | b')') {
+                return None;
+            }
+        }
+        let items = &self.input[start..self.pos];
+        self.pos += 1;
+        let params = self.parse_parameters()?;
+        Some(ListMember::InnerList(InnerList { items, params }))
+    }
+
+    /// Consumes the separator after a list or dictionary member, returns
+    /// `false` at the end of the input and `None` if the separator is
+    /// missing or trailing.
+    fn parse_separator(&mut self) -> Option<bool> {
+        self.skip_ows();
+        if self.is_done() {
+            return Some(false);
+        }
+        self.expect(b',')?;
+        self.skip_ows();
+        (!self.is_done()).then_some(true)
+    }
+}
+
+/// The `Parameters` type is the `;key=value` parameters of an [`Item`] or
+/// an [`InnerList`], iterated in order.
+///
+/// A parameter without a value is a boolean `true`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Parameters<'a>(&'a str);
+
+impl<'a> Parameters<'a> {
+    /// Returns the value of the parameter called `key`. If the key is
+    /// repeated, the last value wins.
+    pub fn get(&self, key: &str) -> Option<BareItem<'a>> {
+        self.filter(|(param, _)| *param == key)
+            .last()
+            .map(|(_, value)| value)
+    }
+
+    /// Returns `true` if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> Iterator for Parameters<'a> {
+    type Item = (&'a str, BareItem<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut parser = Parser::new(self.0);
+        parser.expect(b';')?;
+        parser.skip_sp();
+        let key = parser.parse_key()?;
+        let value = match parser.expect(b'=') {
+            Some(()) => parser.parse_bare_item()?,
+            None => BareItem::Boolean(true),
+        };
+        self.0 = &self.0[parser.pos..];
+        Some((key, value))
+    }
+}
+
+/// The `Item` type is a structured field item, a [`BareItem`] with
+/// [`Parameters`], such as `"text"; lang=en`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Item<'a> {
+    bare: BareItem<'a>,
+    params: Parameters<'a>,
+}
+
+impl<'a> Item<'a> {
+    /// Returns the value of the item.
+    pub fn bare(&self) -> BareItem<'a> {
+        self.bare
+    }
+
+    /// Returns the parameters of the item.
+    pub fn params(&self) -> Parameters<'a> {
+        self.params
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for Item<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let mut parser = Parser::new(trim(slice));
+        let item = parser.parse_item()?;
+        parser.is_done().then_some(item)
+    }
+}
+
+/// The `InnerList` type is a parenthesized list of items with
+/// [`Parameters`], such as `("foo" "bar");lvl=5`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct InnerList<'a> {
+    items: &'a str,
+    params: Parameters<'a>,
+}
+
+impl<'a> InnerList<'a> {
+    /// Returns an iterator over the items.
+    pub fn items(&self) -> impl Iterator<Item = Item<'a>> {
+        let mut parser = Parser::new(self.items);
+        core::iter::from_fn(move || {
+            parser.skip_sp();
+            parser.parse_item()
+        })
+    }
+
+    /// Returns the parameters of the list.
+    pub fn params(&self) -> Parameters<'a> {
+        self.params
+    }
+}
+
+/// A member of a [`List`] or a [`Dictionary`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ListMember<'a> {
+    Item(Item<'a>),
+    InnerList(InnerList<'a>),
+}
+
+impl<'a> ListMember<'a> {
+    /// Returns the member if it is an item.
+    pub fn as_item(&self) -> Option<Item<'a>> {
+        match self {
+            ListMember::Item(item) => Some(*item),
+            ListMember::InnerList(_) => None,
+        }
+    }
+
+    /// Returns the member if it is an inner list.
+    pub fn as_inner_list(&self) -> Option<InnerList<'a>> {
+        match self {
+            ListMember::Item(_) => None,
+            ListMember::InnerList(list) => Some(*list),
+        }
+    }
+
+    /// Returns the parameters of the member.
+    pub fn params(&self) -> Parameters<'a> {
+        match self {
+            ListMember::Item(item) => item.params,
+            ListMember::InnerList(list) => list.params,
+        }
+    }
+}
+
+/// The `List` type is a structured field list, such as `sugar, tea, rum`.
+/// An empty value is an empty list.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct List<'a>(&'a str);
+
+impl<'a> List<'a> {
+    /// Returns an iterator over the members, in order.
+    pub fn members(&self) -> impl Iterator<Item = ListMember<'a>> {
+        let mut parser = Parser::new(self.0);
+        let mut more = !self.0.is_empty();
+        core::iter::from_fn(move || {
+            if !more {
+                return None;
+            }
+            let member = parser.parse_member()?;
+            more = parser.parse_separator()?;
+            Some(member)
+        })
+    }
+}
+
+impl<'de> FromHeaderValue<'de> for List<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let mut parser = Parser::new(value);
+        let mut more = !value.is_empty();
+        while more {
+            parser.parse_member()?;
+            more = parser.parse_separator()?;
+        }
+        Some(List(value))
+    }
+}
+
+/// The `Dictionary` type is a structured field dictionary, such as
+/// `u=1, i`. A key without a value is a boolean `true`, and an empty value
+/// is an empty dictionary.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Dictionary<'a>(&'a str);
+
+impl<'a> Dictionary<'a> {
+    /// Returns an iterator over the keys and members, in order. A repeated
+    /// key is yielded every time it appears.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, ListMember<'a>)> {
+        let mut parser = Parser::new(self.0);
+        let mut more = !self.0.is_empty();
+        core::iter::from_fn(move || {
+            if !more {
+                return None;
+            }
+            let entry = parse_dictionary_member(&mut parser)?;
+            more = parser.parse_separator()?;
+            Some(entry)
+        })
+    }
+
+    /// Returns the member called `key`. If the key is repeated, the last
+    /// member wins.
+    pub fn get(&self, key: &str) -> Option<ListMember<'a>> {
+        self.iter()
+            .filter(|(member, _)| *member == key)
+            .last()
+            .map(|(_, member)| member)
+    }
+}
+
+fn parse_dictionary_member<'a>(parser: &mut Parser<'a>) -> Option<(&'a str, ListMember<'a>)> {
+    let key = parser.parse_key()?;
+    let member = match parser.expect(b'=') {
+        Some(()) => parser.parse_member()?,
+        None => ListMember::Item(Item {
+            bare: BareItem::Boolean(true),
+            params: parser.parse_parameters()?,
+        }),
+    };
+    Some((key, member))
+}
+
+impl<'de> FromHeaderValue<'de> for Dictionary<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let mut parser = Parser::new(value);
+        let mut more = !value.is_empty();
+        while more {
+            parse_dictionary_member(&mut parser)?;
+            more = parser.parse_separator()?;
+        }
+        Some(Dictionary(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use BareItem::*;
+
+    #[rstest]
+    #[case("42", Some(Integer(42)))]
+    #[case("-999999999999999", Some(Integer(-999999999999999)))]
+    #[case("1000000000000000", None)]
+    #[case("4.5", Some(Decimal(4.5)))]
+    #[case("-0.125", Some(Decimal(-0.125)))]
+    #[case("1.2345", None)]
+    #[case("1.", None)]
+    #[case("1234567890123.0", None)]
+    #[case("\"hello \\\"world\\\"\"", Some(String("hello \\\"world\\\"")))]
+    #[case("\"bad \\n\"", None)]
+    #[case("\"unterminated", None)]
+    #[case("text/html", Some(Token("text/html")))]
+    #[case("*foo", Some(Token("*foo")))]
+    #[case(":aGVsbG8=:", Some(ByteSequence("aGVsbG8=")))]
+    #[case(":aGVsbG8=", None)]
+    #[case("?1", Some(Boolean(true)))]
+    #[case("?0", Some(Boolean(false)))]
+    #[case("?2", None)]
+    #[case("@1659578233", Some(Date(1659578233)))]
+    #[case("@1.5", None)]
+    #[case("%\"caf%c3%a9\"", Some(DisplayString("caf%c3%a9")))]
+    #[case("%\"caf%C3%A9\"", None)]
+    #[case("", None)]
+    #[case("1 2", None)]
+    fn item_test(#[case] input: &str, #[case] expected: Option<BareItem>) {
+        let parsed = Item::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|item| item.bare()));
+    }
+
+    #[test]
+    fn item_params() {
+        let item = Item::parse_header_value("abc;a=1;b=2; cde_456;a=\"x\"").unwrap();
+        let params: Vec<_> = item.params().collect();
+        assert_eq!(
+            params,
+            vec![
+                ("a", Integer(1)),
+                ("b", Integer(2)),
+                ("cde_456", Boolean(true)),
+                ("a", String("x")),
+            ]
+        );
+        assert_eq!(item.params().get("a"), Some(String("x")));
+        assert_eq!(item.params().get("z"), None);
+        assert_eq!(Item::parse_header_value("abc;A=1"), None);
+    }
+
+    #[rstest]
+    #[case("sugar, tea, rum", Some(3))]
+    #[case("a;x, (b c);y=?0, ()", Some(3))]
+    #[case("", Some(0))]
+    #[case("a,\tb", Some(2))]
+    #[case("a,", None)]
+    #[case("a b", None)]
+    #[case("(a b", None)]
+    #[case("(a,b)", None)]
+    fn list_test(#[case] input: &str, #[case] expected: Option<usize>) {
+        let parsed = List::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|list| list.members().count()));
+    }
+
+    #[test]
+    fn list_inner_list() {
+        let list = List::parse_header_value("(\"foo\" \"bar\");lvl=5, baz").unwrap();
+        let members: Vec<_> = list.members().collect();
+        let inner = members[0].as_inner_list().unwrap();
+        let items: Vec<_> = inner.items().map(|item| item.bare()).collect();
+        assert_eq!(items, vec![String("foo"), String("bar")]);
+        assert_eq!(inner.params().get("lvl"), Some(Integer(5)));
+        assert_eq!(members[1].as_item().unwrap().bare(), Token("baz"));
+    }
+
+    #[rstest]
+    #[case("a=?0, b, c;foo=bar", Some(vec![("a", Boolean(false)), ("b", Boolean(true)), ("c", Boolean(true))]))]
+    #[case("u=1, i", Some(vec![("u", Integer(1)), ("i", Boolean(true))]))]
+    #[case("", Some(vec![]))]
+    #[case("A=1", None)]
+    #[case("a=1,", None)]
+    #[case("a=1 b=2", None)]
+    fn dictionary_test(#[case] input: &str, #[case] expected: Option<Vec<(&str, BareItem)>>) {
+        let parsed = Dictionary::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|dictionary| dictionary
+                .iter()
+                .map(|(key, member)| (key, member.as_item().unwrap().bare()))
+                .collect())
+        );
+    }
+
+    #[test]
+    fn dictionary_get() {
+        let dictionary = Dictionary::parse_header_value("a=1, b=(1 2), a=3").unwrap();
+        assert_eq!(
+            dictionary
+                .get("a")
+                .and_then(|a| a.as_item())
+                .map(|a| a.bare()),
+            Some(Integer(3))
+        );
+        assert_eq!(
+            dictionary
+                .get("b")
+                .and_then(|b| b.as_inner_list())
+                .map(|b| b.items().count()),
+            Some(2)
+        );
+        let c = dictionary.get("c");
+        assert_eq!(c, None);
+        let c = Dictionary::parse_header_value("c;x=1")
+            .unwrap()
+            .get("c")
+            .unwrap();
+        assert_eq!(c.params().get("x"), Some(Integer(1)));
+    }
+}
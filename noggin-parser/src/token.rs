@@ -0,0 +1,135 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// The `Token` type is an RFC 9110 token such as `keep-alive` or `gzip`,
+/// compared and hashed case-insensitively so `Keep-Alive` and `keep-alive`
+/// are the same element of a set or key of a map.
+///
+/// The value is borrowed from the header as it appeared and has to consist
+/// of token characters only.
+#[derive(Clone, Copy, Debug)]
+pub struct Token<'a>(&'a str);
+
+impl<'a> Token<'a> {
+    /// Returns the token as it appeared in the header.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+pub(crate) fn is_tchar(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+pub(crate) fn is_token(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(is_tchar)
+}
+
+impl<'de> FromHeaderValue<'de> for Token<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        is_token(value).then_some(Token(value))
+    }
+}
+
+impl<'a, 'b> PartialEq<Token<'b>> for Token<'a> {
+    fn eq(&self, other: &Token<'b>) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+}
+
+impl<'a> PartialEq<str> for Token<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for Token<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+impl<'a> Eq for Token<'a> {}
+
+impl<'a> Hash for Token<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.0.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl<'a> Ord for Token<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.0.bytes().map(|b| b.to_ascii_lowercase());
+        let rhs = other.0.bytes().map(|b| b.to_ascii_lowercase());
+        lhs.cmp(rhs)
+    }
+}
+
+impl<'a> PartialOrd for Token<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> fmt::Display for Token<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[rstest]
+    #[case("keep-alive", Some("keep-alive"))]
+    #[case(" Upgrade ", Some("Upgrade"))]
+    #[case("x!#$%&'*+.^_`|~1", Some("x!#$%&'*+.^_`|~1"))]
+    #[case("", None)]
+    #[case("two words", None)]
+    #[case("a,b", None)]
+    #[case("\"quoted\"", None)]
+    fn token_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            expected,
+            Token::parse_header_value(input).map(|t| t.as_str())
+        );
+    }
+
+    #[test]
+    fn token_case_insensitive() {
+        let upper = Token::parse_header_value("Keep-Alive").unwrap();
+        let lower = Token::parse_header_value("keep-alive").unwrap();
+        assert_eq!(upper, lower);
+        assert_eq!(upper, "KEEP-ALIVE");
+        assert_eq!(HashSet::from([upper, lower]).len(), 1);
+        assert_eq!(BTreeSet::from([upper, lower]).len(), 1);
+        assert_eq!(upper.to_string(), "Keep-Alive");
+    }
+}
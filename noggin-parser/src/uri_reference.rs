@@ -0,0 +1,160 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use core::fmt;
+#[cfg(feature = "url")]
+use url::Url;
+
+/// Parses an absolute URL, relative references are rejected. Use
+/// [`UriReference`] for headers that allow them.
+#[cfg(feature = "url")]
+impl<'de> FromHeaderValue<'de> for Url {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        Url::parse(trim(slice)).ok()
+    }
+}
+
+/// The `UriReference` type is the value of headers such as `Location`,
+/// `Content-Location` or `Referer` that carry either an absolute URI or a
+/// reference relative to the URI of the request, such as `/path?query` or
+/// `../other`.
+///
+/// Only the characters and percent-encodings allowed by RFC 3986 are
+/// checked, and a reference whose first segment looks like a scheme has to
+/// be a valid one. The reference is borrowed from the header as it
+/// appeared, with the `url` feature it can be
+/// [resolved](UriReference::resolve_against) to an absolute `Url`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UriReference<'a>(&'a str);
+
+impl<'a> UriReference<'a> {
+    /// Returns the reference as it appeared in the header.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns the scheme of an absolute reference, such as `https`.
+    pub fn scheme(&self) -> Option<&'a str> {
+        let end = self.0.find([':', '/', '?', '#'])?;
+        let (scheme, rest) = self.0.split_at(end);
+        rest.starts_with(':').then_some(scheme)
+    }
+
+    /// Returns `true` if the reference has a scheme, `false` if it is
+    /// relative to the URI of the request.
+    pub fn is_absolute(&self) -> bool {
+        self.scheme().is_some()
+    }
+
+    /// Resolves the reference against `base`, returning absolute references
+    /// as they are once normalized. Returns `None` if the result isn't a
+    /// valid `Url`.
+    #[cfg(feature = "url")]
+    pub fn resolve_against(&self, base: &Url) -> Option<Url> {
+        base.join(self.0).ok()
+    }
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    scheme
+        .bytes()
+        .next()
+        .is_some_and(|b| b.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+fn is_uri_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&b)
+}
+
+fn is_uri_reference(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let encoded = bytes.get(i + 1..i + 3);
+                if !encoded.is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+                    return false;
+                }
+                i += 3;
+            }
+            b if is_uri_char(b) => i += 1,
+            _ => return false,
+        }
+    }
+    value.bytes().filter(|b| *b == b'#').count() <= 1
+}
+
+impl<'de> FromHeaderValue<'de> for UriReference<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        let reference = UriReference(value);
+        let valid = is_uri_reference(value) && reference.scheme().is_none_or(is_scheme);
+        valid.then_some(reference)
+    }
+}
+
+impl<'a> fmt::Display for UriReference<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[cfg(feature = "url")]
+    #[rstest]
+    #[case("https://example.com/a?b", Some("https://example.com/a?b"))]
+    #[case(" https://example.com ", Some("https://example.com/"))]
+    #[case("/relative", None)]
+    #[case("https://exa mple.com", None)]
+    fn url_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        let parsed = Url::parse_header_value(input);
+        assert_eq!(expected, parsed.as_ref().map(Url::as_str));
+    }
+
+    #[rstest]
+    #[case("https://example.com/a", Some((Some("https"), "https://example.com/a")))]
+    #[case(" urn:isbn:0451450523 ", Some((Some("urn"), "urn:isbn:0451450523")))]
+    #[case("/b?c#d", Some((None, "/b?c#d")))]
+    #[case("../d", Some((None, "../d")))]
+    #[case("e/f:g", Some((None, "e/f:g")))]
+    #[case("/caf%C3%A9", Some((None, "/caf%C3%A9")))]
+    #[case("", Some((None, "")))]
+    #[case("/with space", None)]
+    #[case("/caf\u{e9}", None)]
+    #[case("/100%", None)]
+    #[case("/%zz", None)]
+    #[case("/a#b#c", None)]
+    #[case("1http://example.com", None)]
+    #[case(":no-scheme", None)]
+    fn uri_reference_test(#[case] input: &str, #[case] expected: Option<(Option<&str>, &str)>) {
+        let parsed = UriReference::parse_header_value(input);
+        assert_eq!(
+            expected,
+            parsed.map(|reference| (reference.scheme(), reference.as_str()))
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[rstest]
+    #[case("https://example.com/a", Some("https://example.com/a"))]
+    #[case("HTTPS://Example.com", Some("https://example.com/"))]
+    #[case("/b?c", Some("https://example.org/b?c"))]
+    #[case("../d", Some("https://example.org/d"))]
+    #[case("", Some("https://example.org/x/y"))]
+    #[case("http://[::1", None)]
+    fn uri_reference_resolve_against(#[case] input: &str, #[case] expected: Option<&str>) {
+        let base = Url::parse("https://example.org/x/y").unwrap();
+        let reference = UriReference::parse_header_value(input).unwrap();
+        assert_eq!(
+            expected,
+            reference.resolve_against(&base).as_ref().map(Url::as_str)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::from_header_value::{split_params, trim};
+use crate::FromHeaderValue;
+
+/// The `Weighted` type is an element of an `Accept`-style list with its
+/// quality value, such as `text/html;level=1;q=0.8`.
+///
+/// The `q` parameter and everything after it is removed before the item is
+/// parsed, so `T` sees `text/html;level=1`. Without a `q` parameter the
+/// quality is `1.0`. A quality outside of `0..=1` or with more than three
+/// decimals is rejected.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Weighted<T> {
+    /// The element without its `q` parameter.
+    pub item: T,
+    /// The quality, from `0.0` to `1.0`.
+    pub quality: f32,
+}
+
+impl<T> Weighted<T> {
+    /// Returns `true` if the quality is `0`, which marks the item as not
+    /// acceptable.
+    pub fn is_rejected(&self) -> bool {
+        self.quality == 0.0
+    }
+
+    /// Sorts the elements by descending quality. The sort is stable, so
+    /// elements with the same quality keep the order they were sent in.
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_quality(values: &mut [Weighted<T>]) {
+        values.sort_by(|a, b| b.quality.total_cmp(&a.quality));
+    }
+}
+
+fn parse_quality(value: &str) -> Option<f32> {
+    let (int, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let valid = fraction.len() <= 3
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+        && match int {
+            "0" => true,
+            "1" => fraction.bytes().all(|b| b == b'0'),
+            _ => false,
+        };
+    valid.then(|| value.parse().ok()).flatten()
+}
+
+/// Splits `item;q=value` into the item and the raw quality value.
+fn split_quality(slice: &str) -> (&str, Option<&str>) {
+    for param in split_params(slice).skip(1) {
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        if trim(key).eq_ignore_ascii_case("q") {
+            // the parameter is preceded by its `;` separator
+            let start = param.as_ptr() as usize - slice.as_ptr() as usize - 1;
+            return (&slice[..start], Some(trim(value)));
+        }
+    }
+    (slice, None)
+}
+
+impl<'de, T: FromHeaderValue<'de>> FromHeaderValue<'de> for Weighted<T> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let (item, quality) = split_quality(slice);
+        let quality = match quality {
+            Some(quality) => parse_quality(quality)?,
+            None => 1.0,
+        };
+        Some(Weighted {
+            item: T::parse_header_value(item)?,
+            quality,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("text/html", Some(("text/html", 1.0)))]
+    #[case("text/html;q=0.8", Some(("text/html", 0.8)))]
+    #[case("text/html;level=1;q=0.5;ext=1", Some(("text/html;level=1", 0.5)))]
+    #[case(" gzip ; Q=0 ", Some(("gzip", 0.0)))]
+    #[case("*;q=1.000", Some(("*", 1.0)))]
+    #[case(r#"a;title="q=0.1";q=0.3"#, Some((r#"a;title="q=0.1""#, 0.3)))]
+    #[case("gzip;q=1.1", None)]
+    #[case("gzip;q=0.1234", None)]
+    #[case("gzip;q=-0", None)]
+    #[case("gzip;q=", None)]
+    #[case("gzip;q=.5", None)]
+    fn weighted_test(#[case] input: &str, #[case] expected: Option<(&str, f32)>) {
+        let parsed = Weighted::<&str>::parse_header_value(input);
+        assert_eq!(expected, parsed.map(|w| (w.item, w.quality)));
+    }
+
+    #[test]
+    fn weighted_sort_by_quality() {
+        let mut values: Vec<Weighted<&str>> = ["a;q=0.5", "b", "c;q=0.5", "d;q=0", "e"]
+            .into_iter()
+            .map(|value| Weighted::parse_header_value(value).unwrap())
+            .collect();
+        Weighted::sort_by_quality(&mut values);
+        let items: Vec<_> = values.iter().map(|w| w.item).collect();
+        assert_eq!(items, ["b", "e", "a", "c", "d"]);
+        assert!(values[4].is_rejected());
+    }
+}
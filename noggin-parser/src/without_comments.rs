@@ -0,0 +1,117 @@
+use crate::from_header_value::trim;
+use crate::FromHeaderValue;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+
+/// The `WithoutComments` type is a header value with its RFC 9110
+/// `(comment)` sections removed, for `User-Agent`, `Server` and `Via` values
+/// where comments get in the way of the product tokens.
+///
+/// Comments may nest and contain quoted-pairs, parentheses inside
+/// quoted-strings are left alone. The value is borrowed from the header
+/// when it doesn't contain any comments. An unterminated comment is
+/// rejected.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WithoutComments<'a>(Cow<'a, str>);
+
+impl<'a> WithoutComments<'a> {
+    /// Returns the value without its comments.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the value without its comments, borrowed if it had none.
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+}
+
+impl<'a> Deref for WithoutComments<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn strip_comments(value: &str) -> Option<String> {
+    let mut stripped = String::with_capacity(value.len());
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut comments = 0usize;
+    for c in value.chars() {
+        if comments > 0 {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '(' => comments += 1,
+                ')' => comments -= 1,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '(' if !quoted => {
+                comments = 1;
+                stripped.truncate(stripped.trim_end_matches([' ', '\t']).len());
+                continue;
+            }
+            _ => {}
+        }
+        stripped.push(c);
+    }
+    (comments == 0).then_some(stripped)
+}
+
+impl<'de> FromHeaderValue<'de> for WithoutComments<'de> {
+    fn parse_header_value(slice: &'de str) -> Option<Self> {
+        let value = trim(slice);
+        if !value.contains('(') {
+            return Some(WithoutComments(Cow::Borrowed(value)));
+        }
+        let stripped = strip_comments(value)?;
+        Some(WithoutComments(Cow::Owned(trim(&stripped).into())))
+    }
+}
+
+impl<'a> fmt::Display for WithoutComments<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("curl/8.4.0", Some("curl/8.4.0"))]
+    #[case(
+        "Mozilla/5.0 (X11; Linux x86_64) Gecko/20100101 Firefox/119.0",
+        Some("Mozilla/5.0 Gecko/20100101 Firefox/119.0")
+    )]
+    #[case("1.0 fred (proxy (nested) \\) more)", Some("1.0 fred"))]
+    #[case("(leading) Apache/2.4.1", Some("Apache/2.4.1"))]
+    #[case(r#"a "(not a comment)" b"#, Some(r#"a "(not a comment)" b"#))]
+    #[case(
+        r#"a "esc\" (still quoted)" (gone)"#,
+        Some(r#"a "esc\" (still quoted)""#)
+    )]
+    #[case("Apache (unterminated", None)]
+    fn without_comments_test(#[case] input: &str, #[case] expected: Option<&str>) {
+        let parsed = WithoutComments::parse_header_value(input);
+        assert_eq!(expected, parsed.as_ref().map(WithoutComments::as_str));
+    }
+
+    #[test]
+    fn without_comments_borrows() {
+        let parsed = WithoutComments::parse_header_value(" curl/8.4.0 ").unwrap();
+        assert!(matches!(parsed.into_inner(), Cow::Borrowed("curl/8.4.0")));
+    }
+}
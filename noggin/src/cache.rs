@@ -0,0 +1,149 @@
+//! A ready-made header struct for the caching metadata of a response, and a
+//! [`CachePolicy`] applying the storage and freshness rules of RFC 9111.
+
+use crate::headers::{Age, CacheControl, Expires, Vary};
+use crate::{HttpDate, Noggin};
+use core::time::Duration;
+
+/// The headers of a response that decide whether, and for how long, it may
+/// be cached.
+#[derive(PartialEq, Debug, Default, Noggin)]
+pub struct CacheResponseHeaders<'a> {
+    pub cache_control: Option<CacheControl<'a>>,
+    pub expires: Option<Expires>,
+    pub date: Option<HttpDate>,
+    pub age: Option<Age>,
+    pub vary: Option<Vary<'a>>,
+    pub last_modified: Option<HttpDate>,
+}
+
+/// The status codes a response may be cached for without explicit
+/// freshness information, using a heuristic lifetime.
+const HEURISTICALLY_CACHEABLE: [u16; 12] =
+    [200, 203, 204, 206, 300, 301, 308, 404, 405, 410, 414, 501];
+
+/// The `CachePolicy` type evaluates responses for a private cache, such as
+/// a browser's, or a shared one, such as a proxy or CDN.
+///
+/// Responses are assumed to answer a `GET` or `HEAD` request without an
+/// `Authorization` header. A shared cache doesn't store any `private`
+/// response, even one that only lists private fields.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct CachePolicy {
+    pub shared: bool,
+}
+
+impl CachePolicy {
+    /// Returns `true` if a response with `status` and `headers` may be
+    /// stored.
+    ///
+    /// A response carrying `Vary: *` can never be reused, so it isn't
+    /// considered storable either.
+    pub fn is_storable(&self, status: u16, headers: &CacheResponseHeaders<'_>) -> bool {
+        let cc = headers.cache_control.unwrap_or_default();
+        if !(200..600).contains(&status)
+            || cc.no_store()
+            || (self.shared && cc.private())
+            || headers.vary.as_ref().is_some_and(Vary::is_any)
+        {
+            return false;
+        }
+        cc.max_age().is_some()
+            || (self.shared && cc.s_maxage().is_some())
+            || headers.expires.is_some()
+            || cc.public()
+            || (!self.shared && cc.private())
+            || HEURISTICALLY_CACHEABLE.contains(&status)
+    }
+
+    /// Returns how long a response received at `response_time` stays fresh
+    /// after it was generated, from `s-maxage` in a shared cache, then
+    /// `max-age`, then `Expires`, and finally 10% of the time since
+    /// `Last-Modified` for heuristically cacheable statuses. `no-cache`
+    /// responses are never fresh.
+    ///
+    /// `response_time` stands in for a missing `Date` header. Lifetimes are
+    /// capped to 2147483648 seconds, like [`Age`].
+    ///
+    /// With the `std` feature, `response_time` can also be a `SystemTime`.
+    pub fn freshness_lifetime(
+        &self,
+        status: u16,
+        headers: &CacheResponseHeaders<'_>,
+        response_time: impl Into<HttpDate>,
+    ) -> Duration {
+        let cc = headers.cache_control.unwrap_or_default();
+        if cc.no_cache() {
+            return Duration::ZERO;
+        }
+        let date = headers.date.unwrap_or(response_time.into());
+        let s_maxage = cc.s_maxage().filter(|_| self.shared);
+        let explicit = s_maxage
+            .or(cc.max_age())
+            .or_else(|| match headers.expires? {
+                Expires::At(expires) => Some(seconds_between(date, expires)),
+                Expires::Past => Some(Duration::ZERO),
+            });
+        let lifetime = match (explicit, headers.last_modified) {
+            (Some(lifetime), _) => lifetime,
+            (None, Some(last_modified)) if HEURISTICALLY_CACHEABLE.contains(&status) => {
+                seconds_between(last_modified, date) / 10
+            }
+            _ => Duration::ZERO,
+        };
+        lifetime.min(Age::MAX.0)
+    }
+
+    /// Returns the age of a response when it was received at
+    /// `response_time`, the larger of its `Age` header and the time since
+    /// its `Date`.
+    ///
+    /// With the `std` feature, `response_time` can also be a `SystemTime`.
+    pub fn initial_age(
+        &self,
+        headers: &CacheResponseHeaders<'_>,
+        response_time: impl Into<HttpDate>,
+    ) -> Duration {
+        let response_time = response_time.into();
+        let apparent_age = headers
+            .date
+            .map(|date| seconds_between(date, response_time))
+            .unwrap_or_default();
+        let age = headers.age.unwrap_or_default().0;
+        apparent_age.max(age)
+    }
+
+    /// Answers whether a response received at `response_time` may be
+    /// cached, and until when it is fresh. Returns `None` if it may not be
+    /// stored, and a date at or before `response_time` if it may be stored
+    /// but has to be revalidated before every use.
+    ///
+    /// With the `std` feature, `response_time` can also be a `SystemTime`.
+    pub fn fresh_until(
+        &self,
+        status: u16,
+        headers: &CacheResponseHeaders<'_>,
+        response_time: impl Into<HttpDate>,
+    ) -> Option<HttpDate> {
+        if !self.is_storable(status, headers) {
+            return None;
+        }
+        let response_time = response_time.into();
+        let lifetime = self.freshness_lifetime(status, headers, response_time);
+        let age = self.initial_age(headers, response_time);
+        // the lifetime is capped, so the remaining seconds always fit
+        let remaining = lifetime.saturating_sub(age).as_secs() as i64;
+        Some(HttpDate::from_unix_timestamp(
+            response_time.unix_timestamp().saturating_add(remaining),
+        ))
+    }
+}
+
+/// Returns the time from `earlier` to `later`, or zero if `later` is
+/// before `earlier`.
+fn seconds_between(earlier: HttpDate, later: HttpDate) -> Duration {
+    let seconds = later
+        .unix_timestamp()
+        .saturating_sub(earlier.unix_timestamp());
+    Duration::from_secs(seconds.try_into().unwrap_or(0))
+}
@@ -0,0 +1,128 @@
+//! Ready-made header structs for Cross-Origin Resource Sharing, and a
+//! [`CorsPolicy`] answering preflight and actual requests from an allow-list.
+
+use crate::headers::Vary;
+use crate::{FromHeaderValue, Noggin, Token};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The CORS headers of a request. A preflight is an `OPTIONS` request
+/// carrying both `Origin` and `Access-Control-Request-Method`.
+#[derive(PartialEq, Debug, Noggin)]
+pub struct CorsRequestHeaders<'a> {
+    pub origin: Option<&'a str>,
+    pub access_control_request_method: Option<Token<'a>>,
+    pub access_control_request_headers: Option<Vec<Token<'a>>>,
+}
+
+/// The CORS headers of a response, either answering a preflight or
+/// attached to the response of an actual request.
+///
+/// `vary` is only set by [`CorsPolicy`], to `Origin` whenever the allowed
+/// origin depends on the request, and should be merged into the `Vary` of
+/// the response.
+#[derive(PartialEq, Debug, Noggin)]
+pub struct CorsResponseHeaders<'a> {
+    pub access_control_allow_origin: Option<&'a str>,
+    pub access_control_allow_credentials: Option<bool>,
+    pub access_control_allow_methods: Option<Vec<Token<'a>>>,
+    pub access_control_allow_headers: Option<Vec<Token<'a>>>,
+    pub access_control_expose_headers: Option<Vec<Token<'a>>>,
+    pub access_control_max_age: Option<u64>,
+    pub vary: Option<Vary<'a>>,
+}
+
+/// The `CorsPolicy` type is an allow-list of origins, methods and headers.
+///
+/// A `*` entry in `allowed_origins` or `allowed_headers` allows any origin
+/// or header, except `Authorization`, as long as `allow_credentials` isn't
+/// set. Credentialed requests are only allowed from the origins and with
+/// the headers listed explicitly, as the Fetch standard requires. Header
+/// names are compared case-insensitively, origins and methods
+/// case-sensitively. The CORS-safelisted methods `GET`, `HEAD` and `POST`
+/// are always allowed.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct CorsPolicy<'p> {
+    pub allowed_origins: &'p [&'p str],
+    pub allowed_methods: &'p [&'p str],
+    pub allowed_headers: &'p [&'p str],
+    pub exposed_headers: &'p [&'p str],
+    pub allow_credentials: bool,
+    pub max_age: Option<u64>,
+}
+
+impl<'p> CorsPolicy<'p> {
+    /// Returns `true` if requests from `origin` are allowed.
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|allowed| (*allowed == "*" && !self.allow_credentials) || *allowed == origin)
+    }
+
+    /// Returns `true` if `method` is allowed.
+    pub fn allows_method(&self, method: &str) -> bool {
+        matches!(method, "GET" | "HEAD" | "POST") || self.allowed_methods.contains(&method)
+    }
+
+    /// Returns `true` if the request header `name` is allowed.
+    pub fn allows_header(&self, name: &str) -> bool {
+        let wildcard = !self.allow_credentials && !name.eq_ignore_ascii_case("authorization");
+        self.allowed_headers
+            .iter()
+            .any(|allowed| (*allowed == "*" && wildcard) || allowed.eq_ignore_ascii_case(name))
+    }
+
+    /// Answers a preflight request, echoing the requested method and
+    /// headers. Returns `None` if the request isn't a preflight or anything
+    /// it asks for isn't allowed, in which case the response should carry
+    /// no CORS headers.
+    pub fn preflight<'a>(&self, request: &CorsRequestHeaders<'a>) -> Option<CorsResponseHeaders<'a>>
+    where
+        'p: 'a,
+    {
+        let method = request.access_control_request_method?;
+        let headers = request
+            .access_control_request_headers
+            .clone()
+            .unwrap_or_default();
+        if !self.allows_method(method.as_str())
+            || !headers.iter().all(|name| self.allows_header(name.as_str()))
+        {
+            return None;
+        }
+        let mut response = self.response(request.origin?)?;
+        response.access_control_expose_headers = None;
+        response.access_control_allow_methods = Some(Vec::from([method]));
+        response.access_control_allow_headers = (!headers.is_empty()).then_some(headers);
+        response.access_control_max_age = self.max_age;
+        Some(response)
+    }
+
+    /// Returns the headers to attach to the response of an actual request
+    /// from `origin`, or `None` if the origin isn't allowed.
+    pub fn response<'a>(&self, origin: &'a str) -> Option<CorsResponseHeaders<'a>>
+    where
+        'p: 'a,
+    {
+        if !self.allows_origin(origin) {
+            return None;
+        }
+        let wildcard = !self.allow_credentials && self.allowed_origins.contains(&"*");
+        let exposed: Vec<_> = self
+            .exposed_headers
+            .iter()
+            .filter_map(|name| Token::parse_header_value(name))
+            .collect();
+        let vary = (!wildcard)
+            .then(|| Vary::Fields(Token::parse_header_value("Origin").into_iter().collect()));
+        Some(CorsResponseHeaders {
+            access_control_allow_origin: Some(if wildcard { "*" } else { origin }),
+            access_control_allow_credentials: self.allow_credentials.then_some(true),
+            access_control_allow_methods: None,
+            access_control_allow_headers: None,
+            access_control_expose_headers: (!exposed.is_empty()).then_some(exposed),
+            access_control_max_age: None,
+            vary,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{Error, FieldParser};
+use http::HeaderMap;
+
+/// Parses `headers` into `H`, borrowing the values from the map.
+pub fn parse_header_map<'a, H: FieldParser<'a>>(headers: &'a HeaderMap) -> Result<H, Error<'a>> {
+    H::parse_fields(
+        headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes())),
+    )
+}
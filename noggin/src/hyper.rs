@@ -0,0 +1,95 @@
+//! Adapters between the `http` types used by hyper 1.x, such as
+//! `hyper::Request<B>` or `http::request::Parts`, and `Noggin` structs.
+//!
+//! The parsed struct borrows its values straight from the `HeaderMap`
+//! through [`FieldParser`], without writing a head section first. Header
+//! fields the struct doesn't declare are skipped, so a non-ASCII value
+//! only fails parsing when the struct reads it.
+//!
+//! [`Request`] and [`Response`] are typed heads pairing the parsed headers
+//! with the rest of the head. They are created from borrowed `Parts` with
+//! `TryFrom`, and turn back into `Parts` with `From` when their headers
+//! convert into a `HeaderMap`.
+
+pub use crate::header_map::parse_header_map;
+use crate::{Error, FieldParser};
+use http::{request, response, HeaderMap, Method, StatusCode, Uri, Version};
+
+/// Parses the headers of `request` into `H`, see [`parse_header_map`].
+pub fn parse_request<'a, H: FieldParser<'a>, B>(
+    request: &'a http::Request<B>,
+) -> Result<H, Error<'a>> {
+    parse_header_map(request.headers())
+}
+
+/// Parses the headers of `response` into `H`, see [`parse_header_map`].
+pub fn parse_response<'a, H: FieldParser<'a>, B>(
+    response: &'a http::Response<B>,
+) -> Result<H, Error<'a>> {
+    parse_header_map(response.headers())
+}
+
+/// The `Request` type is the head of a request with its headers parsed
+/// into `H`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Request<H> {
+    pub method: Method,
+    pub uri: Uri,
+    pub version: Version,
+    pub headers: H,
+}
+
+impl<'a, H: FieldParser<'a>> TryFrom<&'a request::Parts> for Request<H> {
+    type Error = Error<'a>;
+
+    fn try_from(parts: &'a request::Parts) -> Result<Self, Error<'a>> {
+        Ok(Request {
+            method: parts.method.clone(),
+            uri: parts.uri.clone(),
+            version: parts.version,
+            headers: parse_header_map(&parts.headers)?,
+        })
+    }
+}
+
+impl<H: Into<HeaderMap>> From<Request<H>> for request::Parts {
+    fn from(head: Request<H>) -> Self {
+        let (mut parts, ()) = http::Request::new(()).into_parts();
+        parts.method = head.method;
+        parts.uri = head.uri;
+        parts.version = head.version;
+        parts.headers = head.headers.into();
+        parts
+    }
+}
+
+/// The `Response` type is the head of a response with its headers parsed
+/// into `H`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Response<H> {
+    pub status: StatusCode,
+    pub version: Version,
+    pub headers: H,
+}
+
+impl<'a, H: FieldParser<'a>> TryFrom<&'a response::Parts> for Response<H> {
+    type Error = Error<'a>;
+
+    fn try_from(parts: &'a response::Parts) -> Result<Self, Error<'a>> {
+        Ok(Response {
+            status: parts.status,
+            version: parts.version,
+            headers: parse_header_map(&parts.headers)?,
+        })
+    }
+}
+
+impl<H: Into<HeaderMap>> From<Response<H>> for response::Parts {
+    fn from(head: Response<H>) -> Self {
+        let (mut parts, ()) = http::Response::new(()).into_parts();
+        parts.status = head.status;
+        parts.version = head.version;
+        parts.headers = head.headers.into();
+        parts
+    }
+}
@@ -41,6 +41,126 @@
 //! assert_eq!(parsed_headers.pragma.unwrap(), vec!["no-cache", "public"]);
 //! assert_eq!(body, b"hello world!");
 //! ```
+//!
+//! Repeated headers such as `accept` above may also be collected into a
+//! `HashSet` or `BTreeSet`, so tokens like those of `Vary` come back
+//! deduplicated.
+//!
+//! The `cors` module ships ready-made structs for the CORS request and
+//! response headers, and a `CorsPolicy` that answers preflights from an
+//! allow-list. Likewise, the `websocket` module covers the headers of the
+//! WebSocket opening handshake, the `cache` module decides whether and for
+//! how long a response may be cached, following RFC 9111, and the
+//! `negotiation` module picks the representation to send from the `Accept`
+//! headers of a request. All four require the `alloc` feature.
+//!
+//! # Attributes
+//!
+//! The derive can be configured with `#[noggin(...)]` on the struct.
+//!
+//! - `allow_control_characters`: Accept header values containing control
+//!   characters such as NUL or a bare CR. By default they are rejected with
+//!   `Error::ControlCharacter`, only opt out for trusted input.
+//! - `obs_text`: Accept obs-text, the ISO-8859-1 bytes legacy clients send in
+//!   header values, in `parse_headers` and `parse_head_bytes` instead of
+//!   rejecting the head with `Error::NonAscii`. Header names must still be
+//!   ASCII. Values with obs-text can only be stored in `ObsText` fields, other
+//!   fields report them as invalid values.
+//! - `partial`: Also emit a `<Name>Partial` struct where every field is an
+//!   `Option`, so it can be parsed from an incomplete head, for example to route
+//!   on `Host` before the rest arrives. `<Name>Partial::finish` converts it into
+//!   the complete struct once every required header is present.
+//! - `builder`: Also emit a `<Name>Builder`, returned by `<Name>::builder()`, with a
+//!   setter per field. `build` reports required headers that weren't set, or
+//!   required lists that are empty, as `Error::MissingHeader`.
+//! - `merge`: Also emit `merge(self, other)`, where the headers present in
+//!   `other` win, and `diff(&self, &other)`, which lists the names of the headers
+//!   that differ. `diff` requires the `alloc` feature and `PartialEq` fields.
+//!
+//! Fields can be configured with `#[noggin(...)]` too.
+//!
+//! - `order`: Instead of parsing a header, capture the 0-based line position and
+//!   original name of every matched header, in the order they arrived, so a proxy
+//!   can re-emit them unchanged. The field can be any `Default` type that
+//!   implements `Extend<(usize, &str)>`, such as `Vec<(usize, &'a str)>`.
+//!   `parse_into` appends the headers of each new head, with positions that
+//!   restart at 0 since they are relative to that head.
+//! - `no_split`: Collect every line of a repeated header as a single element
+//!   instead of splitting it on commas, for headers like `Set-Cookie` whose
+//!   values contain commas.
+//!
+//! # Cargo features
+//!
+//! All optional integrations are disabled by default.
+//!
+//! - `std` (enabled by default): Use the standard library. Without it derived
+//!   header structs work in `#![no_std]` crates.
+//! - `alloc` (enabled by `std`): Support heap allocated values such as `String`
+//!   and `Vec`. Without it `noggin` never allocates.
+//! - `base64`: Allow `Base64<[u8; N]>` and, with `alloc`, `Base64Bytes` as
+//!   header values, decoded from standard or URL-safe base64.
+//! - `bumpalo`: Allow `BumpVec` as the container of repeated header fields. Structs
+//!   with such fields implement `BumpHeadParser` and allocate their lists in a
+//!   caller provided `bumpalo::Bump`.
+//! - `chrono`: Allow `chrono::DateTime<Utc>` and `chrono::DateTime<FixedOffset>` as
+//!   header values, parsed as an HTTP-date or, for custom headers, RFC 3339.
+//! - `heapless`: Allow `heapless::Vec<T, N>` as the container of repeated header
+//!   fields and `heapless::String<N>` as a header value, for allocation free
+//!   targets. Exceeding the capacity is reported as an invalid header value.
+//! - `hyper`: Parse derived structs out of the `http` types of hyper 1.x, such
+//!   as `hyper::Request<B>`, without copying the values, and convert between
+//!   `http::request::Parts` or `response::Parts` and the typed heads of the
+//!   `hyper` module. Implies `std`.
+//! - `indexmap`: Allow `indexmap::IndexMap<&str, &str>` as a header value for
+//!   `;` separated `key=value` pairs, keeping the order they appeared in.
+//!   Implies `alloc`.
+//! - `mime`: Allow `mime::Mime` as a header value, including its parameters.
+//!   Implies `std`.
+//! - `semver`: Allow `semver::Version` and `semver::VersionReq` as header
+//!   values, for API versioning headers. Implies `alloc`.
+//! - `serde_json`: Parse the JSON values of the legacy `Report-To` and `NEL`
+//!   headers with `ReportTo` and `Nel`. Implies `alloc`.
+//! - `sha1`: Compute and verify `Sec-WebSocket-Accept` with `WebSocketAccept`.
+//!   Implies `base64`.
+//! - `sha2`: Verify `Content-Digest` and `Repr-Digest` values against a body with
+//!   `ContentDigest::verify`. Implies `base64`.
+//! - `smallvec`: Allow `smallvec::SmallVec<[T; N]>` as the container of repeated
+//!   header fields, so short lists don't allocate.
+//! - `time`: Allow `time::OffsetDateTime` as a header value, parsed as an
+//!   HTTP-date.
+//! - `tower`: Validate request headers in a `tower` middleware with
+//!   `tower::NogginLayer`, which stores the parsed struct in the request
+//!   extensions. Implies `std`.
+//! - `url`: Allow `url::Url` as a header value for absolute URLs, and resolve a
+//!   `UriReference`, such as a relative `Location`, against a base with
+//!   `UriReference::resolve_against`. Implies `std`.
+//! - `uuid`: Allow `uuid::Uuid` as a header value, in the hyphenated or simple
+//!   format.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// The `alloc` crate, re-exported so the `diff` method generated by
+/// `#[noggin(merge)]` can return a `Vec` in `no_std` crates.
+#[cfg(feature = "alloc")]
+pub extern crate alloc;
+
+// lets the derive's `noggin::` paths resolve inside this crate
+extern crate self as noggin;
+
+#[cfg(feature = "alloc")]
+pub mod cache;
+#[cfg(feature = "alloc")]
+pub mod cors;
+#[cfg(any(feature = "hyper", feature = "tower"))]
+mod header_map;
+#[cfg(feature = "hyper")]
+pub mod hyper;
+#[cfg(feature = "alloc")]
+pub mod negotiation;
+#[cfg(feature = "tower")]
+pub mod tower;
+#[cfg(feature = "alloc")]
+pub mod websocket;
 
 pub use noggin_derive::*;
 pub use noggin_parser::*;
@@ -0,0 +1,165 @@
+//! A ready-made header struct for the `Accept` family of request headers,
+//! and a [`Negotiator`] picking the representation to send from them.
+
+use crate::headers::{
+    Accept, AcceptCharset, AcceptEncoding, AcceptLanguage, ContentCoding, ContentType, Vary,
+};
+use crate::{FromHeaderValue, LanguageTag, Noggin, Token};
+
+/// The headers of a request used for proactive content negotiation.
+#[derive(PartialEq, Debug, Default, Noggin)]
+pub struct NegotiationHeaders<'a> {
+    pub accept: Option<Accept<'a>>,
+    pub accept_encoding: Option<AcceptEncoding<'a>>,
+    pub accept_language: Option<AcceptLanguage<'a>>,
+    pub accept_charset: Option<AcceptCharset<'a>>,
+}
+
+/// A representation the server is able to send, such as an HTML page in
+/// French. The content coding is negotiated separately, since any coding
+/// can usually be applied to any representation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Representation<'p> {
+    /// The media type, such as `text/html`.
+    pub media_type: &'p str,
+    /// The language tag, if the representation has one.
+    pub language: Option<&'p str>,
+    /// The charset, if the representation has one.
+    pub charset: Option<&'p str>,
+}
+
+impl<'p> Representation<'p> {
+    /// Creates a representation of `media_type` without a language or
+    /// charset.
+    pub fn new(media_type: &'p str) -> Self {
+        Representation {
+            media_type,
+            language: None,
+            charset: None,
+        }
+    }
+}
+
+/// The outcome of [`Negotiator::negotiate`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Negotiated<'p> {
+    pub representation: Representation<'p>,
+    pub encoding: ContentCoding<'p>,
+}
+
+/// The `Negotiator` type picks a representation and a content coding
+/// together from the `Accept`, `Accept-Language`, `Accept-Charset` and
+/// `Accept-Encoding` headers of a request.
+///
+/// Each representation gets the product of its media type, language and
+/// charset qualities, a missing header or an unset property counting as
+/// `1.0`. The highest non-zero product wins, ties go to the earlier entry
+/// of `representations`. The coding is the preferred one of `encodings`,
+/// falling back to `identity`, which is always available, unless the
+/// request's `Accept-Encoding` excludes it.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Negotiator<'p> {
+    pub representations: &'p [Representation<'p>],
+    pub encodings: &'p [ContentCoding<'p>],
+}
+
+impl<'p> Negotiator<'p> {
+    /// Picks the representation and coding to send, or returns `None` if
+    /// none is acceptable, in which case the server may answer with
+    /// `406 Not Acceptable`.
+    pub fn negotiate(&self, headers: &NegotiationHeaders<'_>) -> Option<Negotiated<'p>> {
+        let encoding = match headers.accept_encoding {
+            Some(accept) => accept.preferred(self.encodings).or_else(|| {
+                let identity = ContentCoding::Identity;
+                (accept.quality(&identity) > 0.0).then_some(identity)
+            })?,
+            None => ContentCoding::Identity,
+        };
+        let mut best: Option<(Representation<'p>, f32)> = None;
+        for &representation in self.representations {
+            let quality = quality(headers, &representation);
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((representation, quality));
+            }
+        }
+        best.map(|(representation, _)| Negotiated {
+            representation,
+            encoding,
+        })
+    }
+
+    /// Returns the `Vary` value to send with every response negotiated by
+    /// this negotiator, listing the request headers for which it has more
+    /// than one option.
+    pub fn vary(&self) -> Vary<'static> {
+        let representations = self.representations;
+        let fields = [
+            (
+                "Accept",
+                varies(representations.iter().map(|r| Some(r.media_type))),
+            ),
+            (
+                "Accept-Language",
+                varies(representations.iter().map(|r| r.language)),
+            ),
+            (
+                "Accept-Charset",
+                varies(representations.iter().map(|r| r.charset)),
+            ),
+            (
+                "Accept-Encoding",
+                varies(
+                    self.encodings
+                        .iter()
+                        .map(|c| Some(c.as_str()))
+                        .chain([Some("identity")]),
+                ),
+            ),
+        ];
+        Vary::Fields(
+            fields
+                .into_iter()
+                .filter(|(_, varies)| *varies)
+                .filter_map(|(name, _)| Token::parse_header_value(name))
+                .collect(),
+        )
+    }
+}
+
+/// Returns `true` if `options` aren't all the same, compared
+/// case-insensitively.
+fn varies<'a>(mut options: impl Iterator<Item = Option<&'a str>>) -> bool {
+    let Some(first) = options.next() else {
+        return false;
+    };
+    options.any(|option| match (option, first) {
+        (Some(option), Some(first)) => !option.eq_ignore_ascii_case(first),
+        (option, first) => option != first,
+    })
+}
+
+/// Returns the combined quality of `representation`, `0.0` if it can't be
+/// parsed.
+fn quality(headers: &NegotiationHeaders<'_>, representation: &Representation<'_>) -> f32 {
+    let Some(content_type) = ContentType::parse_header_value(representation.media_type) else {
+        return 0.0;
+    };
+    let media_type = match headers.accept {
+        Some(accept) if accept.ranges().next().is_some() => accept.quality(&content_type),
+        _ => 1.0,
+    };
+    let language = match (headers.accept_language, representation.language) {
+        (Some(accept), Some(language)) if accept.ranges().next().is_some() => {
+            match LanguageTag::parse_header_value(language) {
+                Some(tag) => accept.quality(&tag),
+                None => 0.0,
+            }
+        }
+        _ => 1.0,
+    };
+    let charset = match (headers.accept_charset, representation.charset) {
+        (Some(accept), Some(charset)) => accept.quality(charset),
+        _ => 1.0,
+    };
+    media_type * language * charset
+}
@@ -0,0 +1,156 @@
+//! A tower middleware validating the headers of incoming requests with a
+//! `Noggin` struct before they reach the inner service.
+
+use crate::header_map::parse_header_map;
+use crate::{Error, FieldParser};
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use http::{Request, Response, StatusCode};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Returns the status to reject a request with when its headers fail to
+/// parse: `422 Unprocessable Content` for a well-formed header with an
+/// invalid value, `400 Bad Request` for anything else.
+pub fn rejection_status(error: &Error<'_>) -> StatusCode {
+    match error {
+        Error::InvalidHeaderValue { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// The `NogginLayer` type wraps services with a [`NogginService`] parsing
+/// the request headers into `H`.
+///
+/// Since the parsed struct is stored in the request extensions, it can't
+/// borrow from the headers: `H` has to own its values, for example with
+/// `String` fields instead of `&str`.
+pub struct NogginLayer<H> {
+    headers: PhantomData<fn() -> H>,
+}
+
+impl<H> NogginLayer<H> {
+    /// Creates a layer parsing the request headers into `H`.
+    pub fn new() -> Self {
+        NogginLayer {
+            headers: PhantomData,
+        }
+    }
+}
+
+impl<H> Default for NogginLayer<H> {
+    fn default() -> Self {
+        NogginLayer::new()
+    }
+}
+
+impl<H> Clone for NogginLayer<H> {
+    fn clone(&self) -> Self {
+        NogginLayer::new()
+    }
+}
+
+impl<S, H> Layer<S> for NogginLayer<H> {
+    type Service = NogginService<S, H>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        NogginService {
+            inner,
+            headers: PhantomData,
+        }
+    }
+}
+
+/// The `NogginService` type parses the headers of every request into `H`
+/// and stores the result in the request extensions, so the inner service
+/// can take it out with `request.extensions().get::<H>()`.
+///
+/// Requests whose headers fail to parse are answered right away with the
+/// [`rejection_status`] and an empty body, without calling the inner
+/// service. The headers are parsed with [`FieldParser`], so a field the
+/// struct doesn't declare is skipped and a stray UTF-8 `Referer` doesn't
+/// reject requests for a struct that never reads it. A declared field with
+/// a value that isn't visible ASCII is rejected as `Error::NonAscii`,
+/// whether it is required or optional, unless the struct is `obs_text`.
+pub struct NogginService<S, H> {
+    inner: S,
+    headers: PhantomData<fn() -> H>,
+}
+
+impl<S: Clone, H> Clone for NogginService<S, H> {
+    fn clone(&self) -> Self {
+        NogginService {
+            inner: self.inner.clone(),
+            headers: PhantomData,
+        }
+    }
+}
+
+impl<S, H, ReqBody, ResBody> Service<Request<ReqBody>> for NogginService<S, H>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    H: for<'a> FieldParser<'a> + Clone + Send + Sync + 'static,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future, ResBody>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let parsed =
+            parse_header_map::<H>(request.headers()).map_err(|error| rejection_status(&error));
+        match parsed {
+            Ok(headers) => {
+                request.extensions_mut().insert(headers);
+                ResponseFuture::Inner {
+                    future: self.inner.call(request),
+                }
+            }
+            Err(status) => {
+                let mut response = Response::new(ResBody::default());
+                *response.status_mut() = status;
+                ResponseFuture::Rejected {
+                    response: Some(response),
+                }
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// The future returned by [`NogginService`], either the inner service's
+    /// or an immediate rejection.
+    #[project = ResponseFutureProj]
+    pub enum ResponseFuture<F, B> {
+        Inner {
+            #[pin]
+            future: F,
+        },
+        Rejected {
+            response: Option<Response<B>>,
+        },
+    }
+}
+
+impl<F, B, E> Future for ResponseFuture<F, B>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+{
+    type Output = Result<Response<B>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            ResponseFutureProj::Inner { future } => future.poll(cx),
+            ResponseFutureProj::Rejected { response } => {
+                Poll::Ready(Ok(response.take().expect("polled after completion")))
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Ready-made header structs for the WebSocket opening handshake of
+//! RFC 6455. With the `sha1` feature, `Sec-WebSocket-Accept` can be
+//! computed and verified.
+
+use crate::headers::{Connection, Upgrade};
+use crate::{Noggin, Token};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The handshake headers of a client's upgrade request.
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SecWebSocketRequest<'a> {
+    pub connection: Connection<'a>,
+    pub upgrade: Upgrade<'a>,
+    pub sec_websocket_key: &'a str,
+    pub sec_websocket_version: u8,
+    pub sec_websocket_protocol: Option<Vec<Token<'a>>>,
+    pub sec_websocket_extensions: Option<Vec<&'a str>>,
+}
+
+impl<'a> SecWebSocketRequest<'a> {
+    /// Returns `true` if the request is a well-formed version 13 handshake:
+    /// it upgrades to `websocket` and its key is 16 bytes encoded in base64.
+    pub fn is_valid(&self) -> bool {
+        let key = self.sec_websocket_key.as_bytes();
+        self.upgrade.is_websocket(&self.connection)
+            && self.sec_websocket_version == 13
+            && key.len() == 24
+            && key.ends_with(b"==")
+            && key[..22]
+                .iter()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/'))
+    }
+
+    /// Returns `true` if the client offered the subprotocol `protocol`.
+    pub fn offers_protocol(&self, protocol: &str) -> bool {
+        self.sec_websocket_protocol
+            .iter()
+            .flatten()
+            .any(|offered| *offered == protocol)
+    }
+
+    /// Computes the `Sec-WebSocket-Accept` value answering this request.
+    #[cfg(feature = "sha1")]
+    pub fn accept(&self) -> crate::headers::WebSocketAccept {
+        crate::headers::WebSocketAccept::from_key(self.sec_websocket_key)
+    }
+}
+
+/// The handshake headers of a server's `101 Switching Protocols` response.
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SecWebSocketResponse<'a> {
+    pub sec_websocket_accept: &'a str,
+    pub sec_websocket_protocol: Option<Token<'a>>,
+    pub sec_websocket_extensions: Option<Vec<&'a str>>,
+}
+
+impl<'a> SecWebSocketResponse<'a> {
+    /// Returns `true` if the response answers the handshake started with
+    /// `key`.
+    #[cfg(feature = "sha1")]
+    pub fn verify(&self, key: &str) -> bool {
+        crate::headers::WebSocketAccept::verify(key, self.sec_websocket_accept)
+    }
+}
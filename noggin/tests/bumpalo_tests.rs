@@ -0,0 +1,72 @@
+#![cfg(feature = "bumpalo")]
+
+use noggin::bumpalo::Bump;
+use noggin::{BumpHeadParser, BumpVec, Noggin, ObsText};
+
+#[derive(Debug, Noggin)]
+pub struct BumpHeaders<'a> {
+    pub content_length: u32,
+    pub accept: BumpVec<'a, &'a str>,
+    pub pragma: Option<BumpVec<'a, &'a str>>,
+}
+
+#[test]
+fn test_bump_allocated_fields() {
+    let bump = Bump::new();
+    let input = b"Content-Length: 5\r\nAccept: text/html, text/plain\r\nAccept: */*\r\n\r\nhello";
+    let (headers, body) = BumpHeaders::parse_headers_in(input, &bump).unwrap();
+
+    assert_eq!(headers.content_length, 5);
+    assert_eq!(
+        headers.accept.as_slice(),
+        ["text/html", "text/plain", "*/*"]
+    );
+    assert!(headers.pragma.is_none());
+    assert_eq!(body, b"hello");
+}
+
+#[derive(Debug, Noggin)]
+#[noggin(obs_text)]
+pub struct BumpLegacyHeaders<'a> {
+    pub accept: BumpVec<'a, &'a str>,
+    pub content_disposition: ObsText<'a>,
+}
+
+#[test]
+fn test_bump_allocated_obs_text() {
+    let bump = Bump::new();
+    let input = b"Content-Disposition: inline; filename=\xa3\r\nAccept: */*\r\n\r\nhello";
+    let (headers, body) = BumpLegacyHeaders::parse_headers_in(input, &bump).unwrap();
+
+    assert_eq!(headers.accept.as_slice(), ["*/*"]);
+    assert_eq!(
+        headers.content_disposition.decode_latin1(),
+        "inline; filename=\u{a3}"
+    );
+    assert_eq!(body, b"hello");
+}
+
+#[test]
+fn test_bump_allocated_missing_header() {
+    let bump = Bump::new();
+    let result = BumpHeaders::parse_head_section_in("Content-Length: 5", &bump);
+
+    assert_eq!(
+        result.unwrap_err(),
+        noggin::Error::MissingHeader("accept".into())
+    );
+}
+
+#[test]
+fn test_bump_allocated_parse_into() {
+    let bump = Bump::new();
+    let mut headers =
+        BumpHeaders::parse_head_section_in("Content-Length: 5\r\nAccept: a", &bump).unwrap();
+    headers
+        .parse_into_in("Accept: b\r\nPragma: no-cache", &bump)
+        .unwrap();
+
+    assert_eq!(headers.content_length, 5);
+    assert_eq!(headers.accept.as_slice(), ["b"]);
+    assert_eq!(headers.pragma.unwrap().as_slice(), ["no-cache"]);
+}
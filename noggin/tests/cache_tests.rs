@@ -0,0 +1,107 @@
+use noggin::cache::{CachePolicy, CacheResponseHeaders};
+use noggin::{HeadParser, HttpDate};
+use rstest::rstest;
+use std::time::Duration;
+
+const PRIVATE: CachePolicy = CachePolicy { shared: false };
+const SHARED: CachePolicy = CachePolicy { shared: true };
+
+// Wed, 21 Oct 2015 07:28:00 GMT
+const NOW: HttpDate = HttpDate::from_unix_timestamp(1445412480);
+
+fn parse(head: &str) -> CacheResponseHeaders<'_> {
+    CacheResponseHeaders::parse_head_section(head).unwrap()
+}
+
+#[rstest]
+#[case(PRIVATE, 200, "Cache-Control: max-age=60", true)]
+#[case(PRIVATE, 200, "", true)]
+#[case(PRIVATE, 201, "", false)]
+#[case(PRIVATE, 201, "Cache-Control: public", true)]
+#[case(PRIVATE, 201, "Expires: Wed, 21 Oct 2015 07:30:00 GMT", true)]
+#[case(PRIVATE, 200, "Cache-Control: no-store, max-age=60", false)]
+#[case(PRIVATE, 200, "Cache-Control: private, max-age=60", true)]
+#[case(SHARED, 200, "Cache-Control: private, max-age=60", false)]
+#[case(PRIVATE, 200, "Vary: *", false)]
+#[case(PRIVATE, 100, "Cache-Control: max-age=60", false)]
+fn test_is_storable(
+    #[case] policy: CachePolicy,
+    #[case] status: u16,
+    #[case] head: &str,
+    #[case] expected: bool,
+) {
+    assert_eq!(expected, policy.is_storable(status, &parse(head)));
+}
+
+#[rstest]
+#[case(PRIVATE, "Cache-Control: max-age=60, s-maxage=600", 60)]
+#[case(SHARED, "Cache-Control: max-age=60, s-maxage=600", 600)]
+#[case(
+    PRIVATE,
+    "Date: Wed, 21 Oct 2015 07:28:00 GMT\r\nExpires: Wed, 21 Oct 2015 08:28:00 GMT",
+    3600
+)]
+#[case(PRIVATE, "Expires: Wed, 21 Oct 2015 08:28:00 GMT", 3600)]
+#[case(PRIVATE, "Cache-Control: max-age=60\r\nExpires: 0", 60)]
+#[case(PRIVATE, "Expires: 0", 0)]
+#[case(PRIVATE, "Cache-Control: no-cache, max-age=60", 0)]
+#[case(
+    PRIVATE,
+    "Date: Wed, 21 Oct 2015 07:28:00 GMT\r\nLast-Modified: Wed, 11 Oct 2015 07:28:00 GMT",
+    86400
+)]
+#[case(PRIVATE, "Last-Modified: Wed, 11 Oct 2015 07:28:00 GMT", 86400)]
+#[case(PRIVATE, "Cache-Control: max-age=9223372036854775807", 1 << 31)]
+#[case(PRIVATE, "", 0)]
+fn test_freshness_lifetime(#[case] policy: CachePolicy, #[case] head: &str, #[case] expected: u64) {
+    assert_eq!(
+        Duration::from_secs(expected),
+        policy.freshness_lifetime(200, &parse(head), NOW)
+    );
+}
+
+#[test]
+fn test_no_heuristic_for_uncacheable_status() {
+    let headers = parse(
+        "Date: Wed, 21 Oct 2015 07:28:00 GMT\r\n\
+         Last-Modified: Wed, 11 Oct 2015 07:28:00 GMT",
+    );
+    assert_eq!(
+        PRIVATE.freshness_lifetime(302, &headers, NOW),
+        Duration::ZERO
+    );
+}
+
+#[rstest]
+#[case("Cache-Control: max-age=600", Some(NOW.unix_timestamp() + 600))]
+#[case("Cache-Control: max-age=600\r\nAge: 100", Some(NOW.unix_timestamp() + 500))]
+#[case(
+    "Cache-Control: max-age=600\r\nDate: Wed, 21 Oct 2015 07:26:00 GMT\r\nAge: 30",
+    Some(NOW.unix_timestamp() + 480)
+)]
+#[case("Cache-Control: max-age=60\r\nAge: 100", Some(NOW.unix_timestamp()))]
+#[case("Cache-Control: no-cache", Some(NOW.unix_timestamp()))]
+#[case("Cache-Control: no-store", None)]
+#[case("Expires: Wed, 21 Oct 2015 08:28:00 GMT", Some(NOW.unix_timestamp() + 3600))]
+#[case(
+    "Cache-Control: max-age=9223372036854775807",
+    Some(NOW.unix_timestamp() + (1 << 31))
+)]
+#[case(
+    "Cache-Control: max-age=9223372036854775808",
+    Some(NOW.unix_timestamp() + (1 << 31))
+)]
+fn test_fresh_until(#[case] head: &str, #[case] expected: Option<i64>) {
+    let fresh_until = SHARED.fresh_until(200, &parse(head), NOW);
+    assert_eq!(expected, fresh_until.map(|date| date.unix_timestamp()));
+}
+
+#[test]
+fn test_fresh_until_system_time() {
+    let headers = parse("Cache-Control: max-age=60");
+    let now = std::time::SystemTime::from(NOW);
+    assert_eq!(
+        PRIVATE.fresh_until(200, &headers, now),
+        Some(HttpDate::from_unix_timestamp(NOW.unix_timestamp() + 60))
+    );
+}
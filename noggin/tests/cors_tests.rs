@@ -0,0 +1,120 @@
+use noggin::cors::{CorsPolicy, CorsRequestHeaders, CorsResponseHeaders};
+use noggin::headers::Vary;
+use noggin::{FromHeaderValue, HeadParser};
+use rstest::rstest;
+
+const POLICY: CorsPolicy = CorsPolicy {
+    allowed_origins: &["https://app.example"],
+    allowed_methods: &["PUT", "DELETE"],
+    allowed_headers: &["Content-Type", "X-Request-Id"],
+    exposed_headers: &["X-Total-Count"],
+    allow_credentials: true,
+    max_age: Some(600),
+};
+
+#[test]
+fn test_preflight_allowed() {
+    let request = CorsRequestHeaders::parse_head_section(
+        "Origin: https://app.example\r\n\
+         Access-Control-Request-Method: PUT\r\n\
+         Access-Control-Request-Headers: content-type, x-request-id",
+    )
+    .unwrap();
+    let response = POLICY.preflight(&request).unwrap();
+
+    assert_eq!(
+        response.access_control_allow_origin,
+        Some("https://app.example")
+    );
+    assert_eq!(response.access_control_allow_credentials, Some(true));
+    assert_eq!(response.access_control_allow_methods.unwrap(), vec!["PUT"]);
+    assert_eq!(
+        response.access_control_allow_headers.unwrap(),
+        vec!["Content-Type", "X-Request-Id"]
+    );
+    assert_eq!(response.access_control_expose_headers, None);
+    assert_eq!(response.access_control_max_age, Some(600));
+    assert_eq!(response.vary, Vary::parse_header_value("Origin"));
+}
+
+#[rstest]
+#[case("Origin: https://evil.example\r\nAccess-Control-Request-Method: PUT")]
+#[case("Origin: https://app.example\r\nAccess-Control-Request-Method: PATCH")]
+#[case("Origin: https://app.example\r\nAccess-Control-Request-Method: put")]
+#[case(
+    "Origin: https://app.example\r\nAccess-Control-Request-Method: PUT\r\n\
+     Access-Control-Request-Headers: Authorization"
+)]
+#[case("Origin: https://app.example")]
+#[case("Access-Control-Request-Method: PUT")]
+fn test_preflight_rejected(#[case] head: &str) {
+    let request = CorsRequestHeaders::parse_head_section(head).unwrap();
+    assert_eq!(POLICY.preflight(&request), None);
+}
+
+#[test]
+fn test_actual_response() {
+    let response = POLICY.response("https://app.example").unwrap();
+    assert_eq!(
+        response.access_control_expose_headers.unwrap(),
+        vec!["x-total-count"]
+    );
+    assert_eq!(POLICY.response("https://evil.example"), None);
+
+    let public = CorsPolicy {
+        allowed_origins: &["*"],
+        allowed_headers: &["*"],
+        ..CorsPolicy::default()
+    };
+    let response = public.response("https://any.example").unwrap();
+    assert_eq!(response.access_control_allow_origin, Some("*"));
+    assert_eq!(response.access_control_allow_credentials, None);
+    assert_eq!(response.vary, None);
+    let request = CorsRequestHeaders::parse_head_section(
+        "Origin: https://any.example\r\nAccess-Control-Request-Method: GET\r\n\
+         Access-Control-Request-Headers: X-Anything",
+    )
+    .unwrap();
+    assert!(public.preflight(&request).is_some());
+}
+
+#[test]
+fn test_credentials_disable_wildcards() {
+    let credentialed = CorsPolicy {
+        allowed_origins: &["*", "https://app.example"],
+        allowed_headers: &["*"],
+        allow_credentials: true,
+        ..CorsPolicy::default()
+    };
+    assert_eq!(credentialed.response("https://evil.example"), None);
+    let response = credentialed.response("https://app.example").unwrap();
+    assert_eq!(
+        response.access_control_allow_origin,
+        Some("https://app.example")
+    );
+    assert_eq!(response.vary, Vary::parse_header_value("Origin"));
+    assert!(!credentialed.allows_header("X-Anything"));
+
+    let public = CorsPolicy {
+        allowed_headers: &["*"],
+        ..CorsPolicy::default()
+    };
+    assert!(public.allows_header("X-Anything"));
+    assert!(!public.allows_header("Authorization"));
+}
+
+#[test]
+fn test_response_headers() {
+    let response = CorsResponseHeaders::parse_head_section(
+        "Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Methods: GET, PUT\r\n\
+         Access-Control-Max-Age: 86400",
+    )
+    .unwrap();
+    assert_eq!(response.access_control_allow_origin, Some("*"));
+    assert_eq!(
+        response.access_control_allow_methods.unwrap(),
+        vec!["GET", "PUT"]
+    );
+    assert_eq!(response.access_control_max_age, Some(86400));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "heapless")]
+
+use noggin::{HeadParser, Noggin};
+use rstest::rstest;
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct HeaplessHeaders<'a> {
+    pub user_agent: heapless::String<16>,
+    pub accept: heapless::Vec<&'a str, 2>,
+    pub pragma: Option<heapless::Vec<&'a str, 2>>,
+}
+
+fn headers<'a>(
+    user_agent: &str,
+    accept: &[&'a str],
+    pragma: Option<&[&'a str]>,
+) -> HeaplessHeaders<'a> {
+    HeaplessHeaders {
+        user_agent: user_agent.try_into().unwrap(),
+        accept: accept.try_into().unwrap(),
+        pragma: pragma.map(|p| p.try_into().unwrap()),
+    }
+}
+
+#[rstest]
+#[case(
+    "User-Agent: curl\r\nAccept: text/html\r\nAccept: text/plain",
+    Ok(headers("curl", &["text/html", "text/plain"], None))
+)]
+#[case(
+    "User-Agent: curl\r\nAccept: */*\r\nPragma: no-cache",
+    Ok(headers("curl", &["*/*"], Some(&["no-cache"])))
+)]
+#[case(
+    "User-Agent: curl\r\nAccept: a, b, c",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "accept".into(),
+        value: "a, b, c".into(),
+        line: 2,
+        offset: 18
+    })
+)]
+#[case(
+    "User-Agent: a-very-long-user-agent\r\nAccept: */*",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "user-agent".into(),
+        value: "a-very-long-user-agent".into(),
+        line: 1,
+        offset: 0
+    })
+)]
+fn test_heapless(
+    #[case] input_headers: &'static str,
+    #[case] expected: Result<HeaplessHeaders, noggin::Error>,
+) {
+    let parsed = HeaplessHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
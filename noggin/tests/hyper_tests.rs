@@ -0,0 +1,189 @@
+#![cfg(feature = "hyper")]
+
+use http::{request, response, HeaderMap, HeaderValue, Method, StatusCode, Version};
+use noggin::hyper::{parse_header_map, parse_request, parse_response};
+use noggin::{Error, HeaderName, Noggin, ObsText};
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct RequestHeaders<'a> {
+    pub host: &'a str,
+    pub accept: Vec<&'a str>,
+    pub content_length: Option<u64>,
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct ResponseHeaders<'a> {
+    pub content_type: &'a str,
+    pub set_cookie: Option<Vec<&'a str>>,
+}
+
+#[test]
+fn test_parse_request() {
+    let request = http::Request::builder()
+        .header("Host", "example.com")
+        .header("Accept", "text/html, application/json")
+        .header("Accept", "*/*")
+        .body(())
+        .unwrap();
+    let headers: RequestHeaders = parse_request(&request).unwrap();
+    assert_eq!(
+        headers,
+        RequestHeaders {
+            host: "example.com",
+            accept: vec!["text/html", "application/json", "*/*"],
+            content_length: None,
+        }
+    );
+    // the values are borrowed from the map, not copied
+    let host = request.headers()["host"].as_bytes();
+    assert_eq!(headers.host.as_ptr(), host.as_ptr());
+}
+
+#[test]
+fn test_parse_response_parts() {
+    let response = http::Response::builder()
+        .header("Content-Type", "text/plain")
+        .header("Set-Cookie", "a=1")
+        .body(())
+        .unwrap();
+    let (parts, ()) = response.into_parts();
+    let headers: ResponseHeaders = parse_header_map(&parts.headers).unwrap();
+    assert_eq!(headers.content_type, "text/plain");
+    assert_eq!(headers.set_cookie, Some(vec!["a=1"]));
+
+    let response = http::Response::from_parts(parts, ());
+    let headers: ResponseHeaders = parse_response(&response).unwrap();
+    assert_eq!(headers.content_type, "text/plain");
+}
+
+#[test]
+fn test_parse_errors() {
+    let request = http::Request::builder()
+        .header("Accept", "*/*")
+        .body(())
+        .unwrap();
+    let result: Result<RequestHeaders, _> = parse_request(&request);
+    assert_eq!(
+        result,
+        Err(Error::MissingHeader(HeaderName::from_static("host")))
+    );
+
+    let request = http::Request::builder()
+        .header("Host", "example.com")
+        .header("Accept", "*/*")
+        .header("Content-Length", "many")
+        .body(())
+        .unwrap();
+    let result: Result<RequestHeaders, _> = parse_request(&request);
+    assert_eq!(
+        result,
+        Err(Error::InvalidHeaderValue {
+            name: HeaderName::from_static("content-length"),
+            value: "many".into(),
+            line: 3,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn test_non_ascii_values() {
+    let request = http::Request::builder()
+        .header("Host", "example.com")
+        .header("Accept", "*/*")
+        .header("Referer", &b"https://example.com/caf\xc3\xa9"[..])
+        .body(())
+        .unwrap();
+    let headers: RequestHeaders = parse_request(&request).unwrap();
+    assert_eq!(headers.host, "example.com");
+
+    let request = http::Request::builder()
+        .header("Host", "example.com")
+        .header("Accept", "*/*")
+        .header("Content-Length", &b"4\xc3\xa92"[..])
+        .body(())
+        .unwrap();
+    let result: Result<RequestHeaders, _> = parse_request(&request);
+    assert_eq!(result, Err(Error::NonAscii));
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(obs_text)]
+pub struct DownloadHeaders<'a> {
+    pub content_disposition: ObsText<'a>,
+}
+
+#[test]
+fn test_obs_text_values() {
+    let response = http::Response::builder()
+        .header("Content-Disposition", &b"attachment; filename=caf\xe9"[..])
+        .body(())
+        .unwrap();
+    let headers: DownloadHeaders = parse_response(&response).unwrap();
+    assert_eq!(
+        headers.content_disposition.decode_latin1(),
+        "attachment; filename=caf\u{e9}"
+    );
+}
+
+#[derive(Clone, PartialEq, Debug, Noggin)]
+pub struct OwnedHeaders {
+    pub host: String,
+}
+
+impl From<OwnedHeaders> for HeaderMap {
+    fn from(headers: OwnedHeaders) -> Self {
+        let mut map = HeaderMap::new();
+        map.insert("host", HeaderValue::try_from(headers.host).unwrap());
+        map
+    }
+}
+
+#[test]
+fn test_request_head() {
+    let (parts, ()) = http::Request::builder()
+        .method(Method::POST)
+        .uri("/upload")
+        .header("Host", "example.com")
+        .header("Accept", "*/*")
+        .body(())
+        .unwrap()
+        .into_parts();
+    let head = noggin::hyper::Request::<RequestHeaders>::try_from(&parts).unwrap();
+    assert_eq!(head.method, Method::POST);
+    assert_eq!(head.uri, "/upload");
+    assert_eq!(head.version, Version::HTTP_11);
+    assert_eq!(head.headers.host, "example.com");
+
+    let head = noggin::hyper::Request::<OwnedHeaders>::try_from(&parts).unwrap();
+    let parts = request::Parts::from(head);
+    assert_eq!(parts.method, Method::POST);
+    assert_eq!(parts.uri, "/upload");
+    assert_eq!(parts.headers["host"], "example.com");
+    assert_eq!(parts.headers.len(), 1);
+}
+
+#[test]
+fn test_response_head() {
+    let (parts, ()) = http::Response::builder()
+        .status(StatusCode::CREATED)
+        .body(())
+        .unwrap()
+        .into_parts();
+    let result = noggin::hyper::Response::<ResponseHeaders>::try_from(&parts);
+    assert_eq!(
+        result,
+        Err(Error::MissingHeader(HeaderName::from_static(
+            "content-type"
+        )))
+    );
+
+    let head = noggin::hyper::Response {
+        status: StatusCode::CREATED,
+        version: Version::HTTP_2,
+        headers: HeaderMap::new(),
+    };
+    let parts = response::Parts::from(head);
+    assert_eq!(parts.status, StatusCode::CREATED);
+    assert_eq!(parts.version, Version::HTTP_2);
+}
@@ -0,0 +1,138 @@
+use noggin::headers::ContentCoding;
+use noggin::negotiation::{NegotiationHeaders, Negotiator, Representation};
+use noggin::HeadParser;
+use rstest::rstest;
+
+const REPRESENTATIONS: [Representation; 4] = [
+    Representation {
+        media_type: "text/html",
+        language: Some("en"),
+        charset: Some("utf-8"),
+    },
+    Representation {
+        media_type: "text/html",
+        language: Some("fr"),
+        charset: Some("utf-8"),
+    },
+    Representation {
+        media_type: "application/json",
+        language: Some("en"),
+        charset: Some("utf-8"),
+    },
+    Representation {
+        media_type: "text/html",
+        language: Some("fr"),
+        charset: Some("iso-8859-1"),
+    },
+];
+
+const ENCODINGS: [ContentCoding; 3] = [
+    ContentCoding::Br,
+    ContentCoding::Gzip,
+    ContentCoding::Identity,
+];
+
+const NEGOTIATOR: Negotiator = Negotiator {
+    representations: &REPRESENTATIONS,
+    encodings: &ENCODINGS,
+};
+
+fn parse(head: &str) -> NegotiationHeaders<'_> {
+    NegotiationHeaders::parse_head_section(head).unwrap()
+}
+
+#[rstest]
+#[case("", Some((0, ContentCoding::Identity)))]
+#[case("Accept: application/json", Some((2, ContentCoding::Identity)))]
+#[case(
+    "Accept: text/html, */*;q=0.1\r\nAccept-Language: fr, en;q=0.5\r\nAccept-Encoding: gzip, br;q=0.5",
+    Some((1, ContentCoding::Gzip))
+)]
+#[case(
+    "Accept-Language: fr\r\nAccept-Charset: iso-8859-1, utf-8;q=0.2",
+    Some((3, ContentCoding::Identity))
+)]
+#[case("Accept: text/*;q=0.5, application/json;q=0.4", Some((0, ContentCoding::Identity)))]
+#[case("Accept: image/png", None)]
+#[case("Accept-Language: de", None)]
+#[case("Accept-Encoding: zstd, identity;q=0", None)]
+#[case("Accept-Encoding: zstd", Some((0, ContentCoding::Identity)))]
+fn test_negotiate(#[case] head: &str, #[case] expected: Option<(usize, ContentCoding)>) {
+    let negotiated = NEGOTIATOR.negotiate(&parse(head));
+    assert_eq!(
+        expected.map(|(index, encoding)| (REPRESENTATIONS[index], encoding)),
+        negotiated.map(|negotiated| (negotiated.representation, negotiated.encoding))
+    );
+}
+
+#[rstest]
+#[case(&[], "gzip", Some(ContentCoding::Identity))]
+#[case(&[ContentCoding::Br], "gzip", Some(ContentCoding::Identity))]
+#[case(&[ContentCoding::Br], "gzip, br;q=0.5", Some(ContentCoding::Br))]
+#[case(&[ContentCoding::Br], "gzip, identity;q=0", None)]
+#[case(&[ContentCoding::Br], "gzip, *;q=0", None)]
+fn test_negotiate_identity_fallback(
+    #[case] encodings: &[ContentCoding<'static>],
+    #[case] accept_encoding: &str,
+    #[case] expected: Option<ContentCoding>,
+) {
+    let single = [Representation::new("text/html")];
+    let negotiator = Negotiator {
+        representations: &single,
+        encodings,
+    };
+    let head = format!("Accept-Encoding: {accept_encoding}");
+    let negotiated = negotiator.negotiate(&parse(&head));
+    assert_eq!(expected, negotiated.map(|negotiated| negotiated.encoding));
+}
+
+#[test]
+fn test_vary() {
+    let vary = NEGOTIATOR.vary();
+    let fields: Vec<_> = vary.fields().map(|field| field.as_str()).collect();
+    assert_eq!(
+        fields,
+        [
+            "Accept",
+            "Accept-Charset",
+            "Accept-Encoding",
+            "Accept-Language"
+        ]
+    );
+
+    let single = [Representation::new("text/html")];
+    let negotiator = Negotiator {
+        representations: &single,
+        encodings: &[ContentCoding::Identity],
+    };
+    assert_eq!(negotiator.vary().fields().count(), 0);
+
+    // identity is always available, so any other coding makes it vary
+    let negotiator = Negotiator {
+        representations: &single,
+        encodings: &[ContentCoding::Gzip],
+    };
+    let fields: Vec<_> = negotiator.vary().fields().map(|f| f.as_str()).collect();
+    assert_eq!(fields, ["Accept-Encoding"]);
+
+    let languages = [
+        Representation {
+            language: Some("en"),
+            ..Representation::new("text/html")
+        },
+        Representation {
+            language: Some("EN"),
+            ..Representation::new("TEXT/HTML")
+        },
+        Representation {
+            language: Some("de"),
+            ..Representation::new("text/html")
+        },
+    ];
+    let negotiator = Negotiator {
+        representations: &languages,
+        encodings: &[],
+    };
+    let fields: Vec<_> = negotiator.vary().fields().map(|f| f.as_str()).collect();
+    assert_eq!(fields, ["Accept-Language"]);
+}
@@ -1,5 +1,9 @@
-use noggin::{HeadParser, Noggin};
+use noggin::{FieldParser, HeadParser, Noggin, ObsText};
 use rstest::rstest;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
 
 #[derive(PartialEq, Debug, Noggin)]
 pub struct TestHeaders<'a> {
@@ -41,23 +45,655 @@ pub struct TestHeaders<'a> {
         pragma: None
     })
 )]
+#[case(
+    "Content-Type: application/json\r\nCONTENT-LENGTH: 42\r\nAccepT: text/plain\r\nX-Accp: ignored\r\nPragmas: ignored",
+    Ok(TestHeaders {
+        content_type: "application/json",
+        content_length: 42,
+        accept: vec!["text/plain"],
+        connection: None,
+        pragma: None
+    })
+)]
 #[case(
     "Content-Length: 42\r\nAccept: application/json,text/plain",
-    Err(noggin::Error::MissingHeader("content-type"))
+    Err(noggin::Error::MissingHeader("content-type".into()))
 )]
 #[case(
     "Content-Type: application/json\r\nContent-Length: 42",
-    Err(noggin::Error::MissingHeader("accept"))
+    Err(noggin::Error::MissingHeader("accept".into()))
 )]
 #[case(
     "Content-Type: application/json\r\nContent-Length: invalid\r\nAccept: application/json",
-    Err(noggin::Error::InvalidHeaderValue("content-length"))
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "content-length".into(),
+        value: "invalid".into(),
+        line: 2,
+        offset: 32
+    })
 )]
 #[case(
     "Content-Type: application/json\r\nContent-Length: 42\r\nAccept",
-    Err(noggin::Error::MalformedHeader)
+    Err(noggin::Error::MalformedHeader {
+        line: 3,
+        offset: 52
+    })
+)]
+#[case(
+    "Content-Type: application/json\r\nContent-Length: 42\r\nAccept: a\0b",
+    Err(noggin::Error::ControlCharacter {
+        line: 3,
+        offset: 61
+    })
 )]
 fn test_noggin(#[case] input_headers: &str, #[case] expected: Result<TestHeaders, noggin::Error>) {
     let parsed = TestHeaders::parse_head_section(input_headers);
     assert_eq!(parsed, expected);
 }
+
+#[rstest]
+#[case(b"Content-Type: text/html\r\nContent-Length: 1\r\nAccept: */*")]
+#[case(b"Content-Type: text/html\r\nContent-Length: 1\r\nAccept: */*\r\n")]
+#[case(b"Content-Type: text/html\r\nContent-Length: 1\r\nAccept: */*\r\n\r\n")]
+fn test_parse_head_bytes(#[case] head: &[u8]) {
+    let parsed = TestHeaders::parse_head_bytes(head);
+    assert_eq!(
+        parsed,
+        Ok(TestHeaders {
+            content_type: "text/html",
+            content_length: 1,
+            accept: vec!["*/*"],
+            connection: None,
+            pragma: None
+        })
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct ArrayHeaders<'a> {
+    pub signature_key_ids: [&'a str; 2],
+    pub range: Option<[u64; 2]>,
+}
+
+#[rstest]
+#[case(
+    "Signature-Key-Ids: a, b",
+    Ok(ArrayHeaders {
+        signature_key_ids: ["a", "b"],
+        range: None
+    })
+)]
+#[case(
+    "Signature-Key-Ids: a\r\nRange: 0, 499\r\nSignature-Key-Ids: b",
+    Ok(ArrayHeaders {
+        signature_key_ids: ["a", "b"],
+        range: Some([0, 499])
+    })
+)]
+#[case(
+    "Range: 0, 499",
+    Err(noggin::Error::MissingHeader("signature-key-ids".into()))
+)]
+#[case(
+    "Signature-Key-Ids: a",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "signature-key-ids".into(),
+        value: "a".into(),
+        line: 1,
+        offset: 0
+    })
+)]
+#[case(
+    "Signature-Key-Ids: a, b, c",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "signature-key-ids".into(),
+        value: "a, b, c".into(),
+        line: 1,
+        offset: 0
+    })
+)]
+#[case(
+    "Signature-Key-Ids: a, b\r\nRange: 0",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "range".into(),
+        value: "0".into(),
+        line: 2,
+        offset: 25
+    })
+)]
+fn test_array_fields(
+    #[case] input_headers: &str,
+    #[case] expected: Result<ArrayHeaders, noggin::Error>,
+) {
+    let parsed = ArrayHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SharedHeaders<'a> {
+    pub host: Arc<str>,
+    pub user_agent: Box<str>,
+    pub content_type: Cow<'a, str>,
+    pub accept: Arc<Vec<&'a str>>,
+    pub pragma: Option<Rc<Vec<&'a str>>>,
+}
+
+#[rstest]
+#[case(
+    "Host: example.com\r\nUser-Agent: curl\r\nContent-Type: text/html\r\nAccept: a, b\r\nPragma: no-cache",
+    Ok(SharedHeaders {
+        host: "example.com".into(),
+        user_agent: "curl".into(),
+        content_type: Cow::Borrowed("text/html"),
+        accept: Arc::new(vec!["a", "b"]),
+        pragma: Some(Rc::new(vec!["no-cache"]))
+    })
+)]
+#[case(
+    "Host: example.com\r\nUser-Agent: curl\r\nContent-Type: text/html",
+    Err(noggin::Error::MissingHeader("accept".into()))
+)]
+fn test_shared_fields(
+    #[case] input_headers: &str,
+    #[case] expected: Result<SharedHeaders, noggin::Error>,
+) {
+    let parsed = SharedHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SetHeaders<'a> {
+    pub vary: BTreeSet<&'a str>,
+    pub connection: Option<HashSet<&'a str>>,
+}
+
+#[rstest]
+#[case(
+    "Vary: Accept, Origin\r\nVary: Accept\r\nConnection: close, close",
+    Ok(SetHeaders {
+        vary: BTreeSet::from(["Accept", "Origin"]),
+        connection: Some(HashSet::from(["close"]))
+    })
+)]
+#[case("Vary: Accept", Ok(SetHeaders { vary: BTreeSet::from(["Accept"]), connection: None }))]
+#[case("Connection: close", Err(noggin::Error::MissingHeader("vary".into())))]
+fn test_set_fields(
+    #[case] input_headers: &str,
+    #[case] expected: Result<SetHeaders, noggin::Error>,
+) {
+    let parsed = SetHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct RequestIdHeaders<'a> {
+    pub x_request_id: noggin::NonEmpty<&'a str>,
+}
+
+#[rstest]
+#[case("X-Request-Id: abc", Ok(RequestIdHeaders { x_request_id: noggin::NonEmpty("abc") }))]
+#[case(
+    "X-Request-Id:   ",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "x-request-id".into(),
+        value: "".into(),
+        line: 1,
+        offset: 0
+    })
+)]
+fn test_non_empty(
+    #[case] input_headers: &str,
+    #[case] expected: Result<RequestIdHeaders, noggin::Error>,
+) {
+    let parsed = RequestIdHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct EntityTagHeaders<'a> {
+    pub etag: noggin::headers::EntityTag<'a>,
+    pub if_none_match: Option<Vec<noggin::headers::EntityTag<'a>>>,
+}
+
+#[test]
+fn test_entity_tags() {
+    let parsed = EntityTagHeaders::parse_head_section(
+        "ETag: \"v2\"\r\nIf-None-Match: W/\"v1\", \"a,b\"\r\nIf-None-Match: W/\"v2\"",
+    )
+    .unwrap();
+    let tags: Vec<_> = parsed.if_none_match.unwrap();
+    assert_eq!(tags.len(), 3);
+    assert_eq!(tags[1].tag(), "a,b");
+    assert!(tags.iter().any(|tag| tag.weak_eq(&parsed.etag)));
+    assert!(!tags.iter().any(|tag| tag.strong_eq(&parsed.etag)));
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(allow_control_characters)]
+pub struct TrustedHeaders<'a> {
+    pub x_trace: &'a str,
+}
+
+#[rstest]
+#[case("X-Trace: a\x01b", Ok(TrustedHeaders { x_trace: "a\x01b" }))]
+#[case("X-Trace: a\rb", Ok(TrustedHeaders { x_trace: "a\rb" }))]
+fn test_allow_control_characters(
+    #[case] input_headers: &str,
+    #[case] expected: Result<TrustedHeaders, noggin::Error>,
+) {
+    let parsed = TrustedHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(obs_text, partial)]
+pub struct LegacyHeaders<'a> {
+    pub host: &'a str,
+    pub content_disposition: Option<ObsText<'a>>,
+    pub range: Option<[u64; 2]>,
+}
+
+#[rstest]
+#[case(
+    b"Host: example.com\r\nContent-Disposition: attachment; filename=caf\xe9 \r\nX-Legacy: \xff\r\n\r\n",
+    Ok(LegacyHeaders {
+        host: "example.com",
+        content_disposition: Some(ObsText::new(b"attachment; filename=caf\xe9")),
+        range: None
+    })
+)]
+#[case(
+    b"Host: example.com\r\nRange: 0, 499\r\n\r\n",
+    Ok(LegacyHeaders {
+        host: "example.com",
+        content_disposition: None,
+        range: Some([0, 499])
+    })
+)]
+#[case(
+    b"X-Legacy: \xff\r\nHost: caf\xe9\r\n\r\n",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "host".into(),
+        value: "caf\u{e9}".into(),
+        line: 2,
+        offset: 13
+    })
+)]
+#[case(
+    b"Host: example.com\r\nRange: 0\r\n\r\n",
+    Err(noggin::Error::InvalidHeaderValue {
+        name: "range".into(),
+        value: "0".into(),
+        line: 2,
+        offset: 19
+    })
+)]
+#[case(b"Host\xe9: example.com\r\n\r\n", Err(noggin::Error::MalformedHeader { line: 1, offset: 0 }))]
+fn test_obs_text(#[case] input: &[u8], #[case] expected: Result<LegacyHeaders, noggin::Error>) {
+    let parsed = LegacyHeaders::parse_headers(input).map(|(headers, _)| headers);
+    assert_eq!(parsed, expected);
+    if !input.is_ascii() {
+        assert_eq!(
+            TestHeaders::parse_headers(input).map(|(headers, _)| headers),
+            Err(noggin::Error::NonAscii)
+        );
+    }
+}
+
+#[test]
+fn test_obs_text_head_only() {
+    let parsed =
+        LegacyHeaders::parse_head_bytes(b"Content-Disposition: inline\r\nHost: a\r\n").unwrap();
+    assert_eq!(parsed.host, "a");
+    assert_eq!(
+        parsed.content_disposition.unwrap().decode_latin1(),
+        "inline"
+    );
+
+    let partial = LegacyHeadersPartial::parse_head_section("Content-Disposition: inline").unwrap();
+    assert_eq!(partial.host, None);
+    assert_eq!(partial.content_disposition, Some(ObsText::new(b"inline")));
+}
+
+#[test]
+fn test_parse_into() {
+    let mut headers = TestHeaders::parse_head_section(
+        "Content-Type: text/html\r\nContent-Length: 1\r\nAccept: a, b\r\nConnection: close",
+    )
+    .unwrap();
+    headers
+        .parse_into("Content-Length: 2\r\nAccept: c\r\nPragma: no-cache")
+        .unwrap();
+
+    assert_eq!(
+        headers,
+        TestHeaders {
+            content_type: "text/html",
+            content_length: 2,
+            accept: vec!["c"],
+            connection: Some("close"),
+            pragma: Some(vec!["no-cache"])
+        }
+    );
+}
+
+#[test]
+fn test_parse_into_keeps_fields_on_error() {
+    let mut headers = ArrayHeaders {
+        signature_key_ids: ["a", "b"],
+        range: None,
+    };
+    let result = headers.parse_into("Signature-Key-Ids: c, d\r\nRange: 1");
+
+    assert_eq!(
+        result,
+        Err(noggin::Error::InvalidHeaderValue {
+            name: "range".into(),
+            value: "1".into(),
+            line: 2,
+            offset: 25
+        })
+    );
+    assert_eq!(
+        headers,
+        ArrayHeaders {
+            signature_key_ids: ["a", "b"],
+            range: None
+        }
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(partial)]
+pub struct RoutedHeaders<'a> {
+    pub host: &'a str,
+    pub accept: Vec<&'a str>,
+    pub range: Option<[u64; 2]>,
+}
+
+#[test]
+fn test_partial_headers() {
+    let partial = RoutedHeadersPartial::parse_head_section("Host: example.com").unwrap();
+    assert_eq!(partial.host, Some("example.com"));
+    assert_eq!(
+        partial.finish(),
+        Err(noggin::Error::MissingHeader("accept".into()))
+    );
+
+    let mut partial = RoutedHeadersPartial::default();
+    partial.parse_into("Host: example.com").unwrap();
+    partial.parse_into("Accept: */*\r\nRange: 1, 2").unwrap();
+    assert_eq!(
+        partial.finish(),
+        Ok(RoutedHeaders {
+            host: "example.com",
+            accept: vec!["*/*"],
+            range: Some([1, 2])
+        })
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(builder)]
+pub struct BuiltHeaders<'a> {
+    pub content_length: u32,
+    pub accept: Vec<&'a str>,
+    pub pragma: Option<Vec<&'a str>>,
+    pub connection: Option<&'a str>,
+}
+
+#[test]
+fn test_builder() {
+    let headers = BuiltHeaders::builder()
+        .content_length(42)
+        .accept(vec!["*/*"])
+        .pragma(vec!["no-cache"])
+        .build();
+
+    assert_eq!(
+        headers,
+        Ok(BuiltHeaders {
+            content_length: 42,
+            accept: vec!["*/*"],
+            pragma: Some(vec!["no-cache"]),
+            connection: None
+        })
+    );
+}
+
+#[rstest]
+#[case(BuiltHeaders::builder().accept(vec!["*/*"]), "content-length")]
+#[case(BuiltHeaders::builder().content_length(1), "accept")]
+#[case(BuiltHeaders::builder().content_length(1).accept(vec![]), "accept")]
+fn test_builder_missing_headers(#[case] builder: BuiltHeadersBuilder<'static>, #[case] name: &str) {
+    assert_eq!(
+        builder.build(),
+        Err(noggin::Error::MissingHeader(name.to_string().into()))
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(merge)]
+pub struct CacheHeaders<'a> {
+    pub etag: &'a str,
+    pub cache_control: Option<Vec<&'a str>>,
+    pub vary: Option<&'a str>,
+}
+
+#[test]
+fn test_merge_and_diff() {
+    let cached =
+        CacheHeaders::parse_head_section("ETag: a\r\nCache-Control: max-age=60\r\nVary: accept")
+            .unwrap();
+    let fresh = CacheHeaders::parse_head_section("ETag: b\r\nVary: accept").unwrap();
+
+    assert_eq!(cached.diff(&cached), Vec::<&str>::new());
+    assert_eq!(cached.diff(&fresh), vec!["etag", "cache-control"]);
+    assert_eq!(
+        cached.merge(fresh),
+        CacheHeaders {
+            etag: "b",
+            cache_control: Some(vec!["max-age=60"]),
+            vary: Some("accept")
+        }
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct OrderedHeaders<'a> {
+    pub host: &'a str,
+    pub accept: Option<Vec<&'a str>>,
+    #[noggin(order)]
+    pub order: Vec<(usize, &'a str)>,
+}
+
+#[test]
+fn test_header_order() {
+    let mut headers = OrderedHeaders::parse_head_section(
+        "accept: text/html\r\nX-Ignored: 1\r\nHOST: example.com\r\nAccept: */*",
+    )
+    .unwrap();
+
+    assert_eq!(headers.host, "example.com");
+    assert_eq!(headers.accept, Some(vec!["text/html", "*/*"]));
+    assert_eq!(
+        headers.order,
+        vec![(0, "accept"), (2, "HOST"), (3, "Accept")]
+    );
+
+    // positions are relative to the head passed to each call
+    headers.parse_into("Host: example.org").unwrap();
+    assert_eq!(
+        headers.order,
+        vec![(0, "accept"), (2, "HOST"), (3, "Accept"), (0, "Host")]
+    );
+}
+
+#[test]
+fn test_parse_fields() {
+    let fields: [(&str, &[u8]); 4] = [
+        ("accept", b"text/html"),
+        ("x-ignored", b"caf\xe9\x01"),
+        ("host", b"example.com"),
+        ("accept", b"*/*"),
+    ];
+    let headers = OrderedHeaders::parse_fields(fields).unwrap();
+    assert_eq!(headers.host, "example.com");
+    assert_eq!(headers.accept, Some(vec!["text/html", "*/*"]));
+    assert_eq!(
+        headers.order,
+        vec![(0, "accept"), (2, "host"), (3, "accept")]
+    );
+
+    let fields: [(&str, &[u8]); 2] = [("host", b"a"), ("accept", b"x\x01y")];
+    assert_eq!(
+        OrderedHeaders::parse_fields(fields),
+        Err(noggin::Error::ControlCharacter { line: 2, offset: 1 })
+    );
+    let fields: [(&str, &[u8]); 2] = [("host", b"caf\xe9"), ("accept", b"x")];
+    assert_eq!(
+        OrderedHeaders::parse_fields(fields),
+        Err(noggin::Error::NonAscii)
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+#[noggin(partial)]
+pub struct ProxiedHeaders<'a> {
+    /// The cookies to forward.
+    #[noggin(no_split)]
+    pub set_cookie: Vec<&'a str>,
+    pub host: &'a str,
+    #[noggin(order)]
+    pub order: Vec<(usize, &'a str)>,
+}
+
+#[test]
+fn test_partial_field_attributes() {
+    let mut partial =
+        ProxiedHeadersPartial::parse_head_section("Set-Cookie: a=1, b=2\r\nX-Other: 1").unwrap();
+    assert_eq!(partial.set_cookie, Some(vec!["a=1, b=2"]));
+    assert_eq!(partial.order, vec![(0, "Set-Cookie")]);
+
+    partial
+        .parse_into("X-Other: 2\r\nHost: example.com")
+        .unwrap();
+    assert_eq!(
+        partial.finish(),
+        Ok(ProxiedHeaders {
+            set_cookie: vec!["a=1, b=2"],
+            host: "example.com",
+            order: vec![(0, "Set-Cookie"), (1, "Host")]
+        })
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SetCookieHeaders<'a> {
+    #[noggin(no_split)]
+    pub set_cookie: Vec<noggin::headers::SetCookie<'a>>,
+    #[noggin(no_split)]
+    pub x_raw: Option<Vec<&'a str>>,
+}
+
+#[test]
+fn test_no_split() {
+    let headers = SetCookieHeaders::parse_head_section(
+        "Set-Cookie: id=1; Expires=Wed, 21 Oct 2015 07:28:00 GMT\r\n\
+         Set-Cookie: theme=dark; Path=/\r\n\
+         X-Raw: a, b",
+    )
+    .unwrap();
+
+    let cookies: Vec<_> = headers.set_cookie.iter().map(|c| c.name()).collect();
+    assert_eq!(cookies, vec!["id", "theme"]);
+    assert!(headers.set_cookie[0].expires().is_some());
+    assert_eq!(headers.x_raw, Some(vec!["a, b"]));
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct MethodHeaders<'a> {
+    pub allow: noggin::headers::Allow<'a>,
+    pub access_control_request_method: Option<noggin::Method<'a>>,
+    pub access_control_allow_methods: Option<Vec<noggin::Method<'a>>>,
+}
+
+#[test]
+fn test_methods() {
+    let headers = MethodHeaders::parse_head_section(
+        "Allow: GET, HEAD\r\n\
+         Access-Control-Request-Method: PATCH\r\n\
+         Access-Control-Allow-Methods: PUT, PROPFIND",
+    )
+    .unwrap();
+
+    assert!(headers.allow.contains(noggin::Method::Head));
+    assert_eq!(
+        headers.access_control_request_method,
+        Some(noggin::Method::Patch)
+    );
+    assert_eq!(
+        headers.access_control_allow_methods,
+        Some(vec![
+            noggin::Method::Put,
+            noggin::Method::Extension("PROPFIND")
+        ])
+    );
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct CacheMetadataHeaders<'a> {
+    pub cache_control: Option<noggin::headers::CacheControl<'a>>,
+    pub accept_ranges: Option<noggin::headers::AcceptRanges<'a>>,
+    pub age: Option<noggin::headers::Age>,
+    pub date: Option<noggin::HttpDate>,
+    pub expires: Option<noggin::headers::Expires>,
+    pub last_modified: Option<noggin::HttpDate>,
+    pub etag: Option<noggin::headers::EntityTag<'a>>,
+}
+
+#[test]
+fn test_cache_metadata() {
+    let headers = CacheMetadataHeaders::parse_head_section(
+        "Cache-Control: public, max-age=60\r\n\
+         Accept-Ranges: bytes\r\n\
+         Age: 30\r\n\
+         Date: Wed, 21 Oct 2015 07:28:00 GMT\r\n\
+         Expires: 0\r\n\
+         Last-Modified: Tue, 20 Oct 2015 07:28:00 GMT\r\n\
+         ETag: \"v1\"",
+    )
+    .unwrap();
+
+    let date = headers.date.unwrap();
+    assert!(headers.accept_ranges.unwrap().accepts_bytes());
+    assert_eq!(
+        headers.age,
+        Some(noggin::headers::Age(std::time::Duration::from_secs(30)))
+    );
+    assert_eq!(headers.expires, Some(noggin::headers::Expires::Past));
+    assert!(headers.last_modified.unwrap() < date);
+}
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct ForwardedHeaders<'a> {
+    #[noggin(no_split)]
+    pub x_forwarded_for: Vec<noggin::headers::XForwardedFor<'a>>,
+}
+
+#[test]
+fn test_x_forwarded_for_lines() {
+    let headers = ForwardedHeaders::parse_head_section(
+        "X-Forwarded-For: 6.6.6.6\r\n\
+         X-Forwarded-For: 203.0.113.9",
+    )
+    .unwrap();
+
+    let trusted = ["10.0.0.0/8".parse().unwrap()];
+    let client = noggin::headers::XForwardedFor::client_ip(
+        &headers.x_forwarded_for,
+        "10.0.0.1".parse().unwrap(),
+        &trusted,
+    );
+    assert_eq!(client, "203.0.113.9".parse::<std::net::IpAddr>().unwrap());
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "smallvec")]
+
+use noggin::{HeadParser, Noggin};
+use rstest::rstest;
+use smallvec::{smallvec, SmallVec};
+
+#[derive(PartialEq, Debug, Noggin)]
+pub struct SmallVecHeaders<'a> {
+    pub accept: SmallVec<[&'a str; 2]>,
+    pub pragma: Option<SmallVec<[&'a str; 2]>>,
+}
+
+#[rstest]
+#[case(
+    "Accept: text/html, text/plain\r\nAccept: application/json",
+    Ok(SmallVecHeaders {
+        accept: smallvec!["text/html", "text/plain", "application/json"],
+        pragma: None
+    })
+)]
+#[case(
+    "Accept: text/html\r\nPragma: no-cache",
+    Ok(SmallVecHeaders {
+        accept: smallvec!["text/html"],
+        pragma: Some(smallvec!["no-cache"])
+    })
+)]
+#[case("Pragma: no-cache", Err(noggin::Error::MissingHeader("accept".into())))]
+fn test_smallvec(
+    #[case] input_headers: &'static str,
+    #[case] expected: Result<SmallVecHeaders, noggin::Error>,
+) {
+    let parsed = SmallVecHeaders::parse_head_section(input_headers);
+    assert_eq!(parsed, expected);
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "tower")]
+
+use http::{Request, Response, StatusCode};
+use noggin::tower::{NogginLayer, NogginService};
+use noggin::Noggin;
+use std::convert::Infallible;
+use std::future::{ready, Future, Ready};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use tower_layer::Layer;
+use tower_service::Service;
+
+#[derive(Clone, PartialEq, Debug, Noggin)]
+pub struct ApiHeaders {
+    pub content_length: u64,
+    pub user_agent: Option<String>,
+}
+
+/// Answers with the parsed headers found in the request extensions.
+struct Echo;
+
+impl Service<Request<()>> for Echo {
+    type Response = Response<Option<ApiHeaders>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Infallible>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<()>) -> Self::Future {
+        let headers = request.extensions().get::<ApiHeaders>().cloned();
+        ready(Ok(Response::new(headers)))
+    }
+}
+
+fn call(request: Request<()>) -> Response<Option<ApiHeaders>> {
+    let mut service: NogginService<Echo, ApiHeaders> = NogginLayer::new().layer(Echo);
+    let future = pin!(service.call(request));
+    match future.poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(Ok(response)) => response,
+        Poll::Ready(Err(never)) => match never {},
+        Poll::Pending => panic!("the echo service is always ready"),
+    }
+}
+
+#[test]
+fn test_valid_headers_reach_the_service() {
+    let request = Request::builder()
+        .header("Content-Length", "42")
+        .header("User-Agent", "curl/8.0")
+        .body(())
+        .unwrap();
+    let response = call(request);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.into_body(),
+        Some(ApiHeaders {
+            content_length: 42,
+            user_agent: Some("curl/8.0".into()),
+        })
+    );
+}
+
+#[test]
+fn test_invalid_value_is_unprocessable() {
+    let request = Request::builder()
+        .header("Content-Length", "forty-two")
+        .body(())
+        .unwrap();
+    let response = call(request);
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.into_body(), None);
+}
+
+#[test]
+fn test_missing_header_is_bad_request() {
+    let request = Request::builder()
+        .header("User-Agent", "curl/8.0")
+        .body(())
+        .unwrap();
+    assert_eq!(call(request).status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_non_ascii_required_value_is_bad_request() {
+    let request = Request::builder()
+        .header("Content-Length", &b"4\xc3\xa92"[..])
+        .body(())
+        .unwrap();
+    assert_eq!(call(request).status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_non_ascii_optional_value_is_bad_request() {
+    let request = Request::builder()
+        .header("Content-Length", "42")
+        .header("User-Agent", &b"caf\xc3\xa9"[..])
+        .body(())
+        .unwrap();
+    let response = call(request);
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.into_body(), None);
+}
+
+#[test]
+fn test_non_ascii_undeclared_value_is_ignored() {
+    let request = Request::builder()
+        .header("Content-Length", "42")
+        .header("Referer", &b"https://example.com/caf\xc3\xa9"[..])
+        .header("Cookie", &b"name=\xe9"[..])
+        .body(())
+        .unwrap();
+    assert_eq!(call(request).status(), StatusCode::OK);
+}
@@ -0,0 +1,12 @@
+use noggin::Noggin;
+
+#[derive(Noggin)]
+pub struct Headers<'a> {
+    pub host: &'a str,
+    #[noggin(order)]
+    pub order: Vec<(usize, &'a str)>,
+    #[noggin(order)]
+    pub again: Vec<(usize, &'a str)>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use noggin::Noggin;
+
+#[derive(Noggin)]
+pub enum Headers {
+    Host,
+}
+
+fn main() {}
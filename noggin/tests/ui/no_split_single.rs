@@ -0,0 +1,9 @@
+use noggin::Noggin;
+
+#[derive(Noggin)]
+pub struct Headers<'a> {
+    #[noggin(no_split)]
+    pub host: &'a str,
+}
+
+fn main() {}
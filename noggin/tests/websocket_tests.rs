@@ -0,0 +1,81 @@
+use noggin::websocket::{SecWebSocketRequest, SecWebSocketResponse};
+use noggin::HeadParser;
+use rstest::rstest;
+
+const REQUEST: &str = "Host: server.example.com\r\n\
+    Upgrade: websocket\r\n\
+    Connection: Upgrade\r\n\
+    Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+    Sec-WebSocket-Protocol: chat, superchat\r\n\
+    Sec-WebSocket-Extensions: permessage-deflate; client_max_window_bits\r\n\
+    Sec-WebSocket-Version: 13";
+
+#[test]
+fn test_websocket_request() {
+    let request = SecWebSocketRequest::parse_head_section(REQUEST).unwrap();
+
+    assert!(request.is_valid());
+    assert_eq!(request.sec_websocket_key, "dGhlIHNhbXBsZSBub25jZQ==");
+    assert_eq!(request.sec_websocket_version, 13);
+    assert!(request.offers_protocol("superchat"));
+    assert!(!request.offers_protocol("mqtt"));
+    assert_eq!(
+        request.sec_websocket_extensions,
+        Some(vec!["permessage-deflate; client_max_window_bits"])
+    );
+}
+
+#[rstest]
+#[case(
+    "Upgrade: websocket\r\nConnection: keep-alive\r\n\
+     Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13"
+)]
+#[case(
+    "Upgrade: h2c\r\nConnection: Upgrade\r\n\
+     Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13"
+)]
+#[case(
+    "Upgrade: websocket\r\nConnection: Upgrade\r\n\
+     Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 8"
+)]
+#[case(
+    "Upgrade: websocket\r\nConnection: Upgrade\r\n\
+     Sec-WebSocket-Key: c2hvcnQ=\r\nSec-WebSocket-Version: 13"
+)]
+fn test_websocket_request_invalid(#[case] head: &str) {
+    let request = SecWebSocketRequest::parse_head_section(head).unwrap();
+    assert!(!request.is_valid());
+}
+
+#[test]
+fn test_websocket_response() {
+    let response = SecWebSocketResponse::parse_head_section(
+        "Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n\
+         Sec-WebSocket-Protocol: chat",
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.sec_websocket_accept,
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+    assert_eq!(response.sec_websocket_protocol.unwrap(), "chat");
+    assert_eq!(response.sec_websocket_extensions, None);
+}
+
+#[cfg(feature = "sha1")]
+#[test]
+fn test_websocket_accept() {
+    let request = SecWebSocketRequest::parse_head_section(REQUEST).unwrap();
+    let accept = request.accept();
+    assert_eq!(accept, "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+
+    let response = SecWebSocketResponse::parse_head_section(
+        "Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=",
+    )
+    .unwrap();
+    assert!(response.verify(request.sec_websocket_key));
+    assert!(!response.verify("c2hvcnQ="));
+}